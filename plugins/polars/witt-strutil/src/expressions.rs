@@ -18,13 +18,29 @@
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 #![allow(clippy::unused_unit)]
-use polars::chunked_array::builder::list::ListStringChunkedBuilder;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use md5::Md5;
+use polars::chunked_array::builder::list::{get_list_builder, ListStringChunkedBuilder};
+use polars::prelude::arity::{broadcast_binary_elementwise, unary_elementwise};
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
 use rand::Rng;
+use rayon::prelude::*;
+use regex::{Captures, NoExpand, Regex};
 use serde::Deserialize;
-use std::collections::HashSet;
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+use xxhash_rust::xxh64::xxh64;
 
 fn list_string_output(_: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
@@ -33,10 +49,42 @@ fn list_string_output(_: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+// Below this number of rows, spawning parallel tasks costs more than it saves
+const PARALLEL_ROWS_THRESHOLD: usize = 100_000;
+
+fn build_list_string_series<F>(ca: &StringChunked, split: F) -> PolarsResult<Series>
+where
+    F: Fn(&str) -> Vec<String> + Sync,
+{
+    // Compute the parts of each row first, possibly in parallel, then assemble
+    // the list column sequentially so that the row order is preserved.
+    let rows: Vec<Option<Vec<String>>> =
+    if ca.len() >= PARALLEL_ROWS_THRESHOLD {
+        let values: Vec<Option<&str>> = ca.into_iter().collect();
+        values.into_par_iter().map(|opt_s| opt_s.map(&split)).collect()
+    } else {
+        ca.into_iter().map(|opt_s| opt_s.map(&split)).collect()
+    };
+
+    let mut builder = ListStringChunkedBuilder::new("".into(), ca.len(), 0);
+    for row in rows {
+        match row {
+            None => builder.append_null(),
+            Some(parts) => builder.append_values_iter(parts.iter().map(String::as_str)),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
 fn is_vowel(c: char) -> bool {
     matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
 }
 
+// Whether an ASCII letter is a vowel, where "y" may be counted as one too
+fn is_vowel_letter(c: char, treat_y_as_vowel: bool) -> bool {
+    is_vowel(c) || treat_y_as_vowel && c.eq_ignore_ascii_case(&'y')
+}
+
 fn pig_latin_word(word: &str) -> String {
     if word.is_empty() {
         return String::new();
@@ -102,7 +150,7 @@ fn pig_latinnify(inputs: &[Series]) -> PolarsResult<Series> {
     let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
         let translated_words: Vec<String> = value
             .split_whitespace()
-            .map(|word| pig_latin_word(word))
+            .map(pig_latin_word)
             .collect();
         write!(output, "{}", translated_words.join(" ")).unwrap();
     });
@@ -128,52 +176,74 @@ fn split_by_character_transition(inputs: &[Series], kwargs: SplitByCharacterTran
         .iter()
         .filter_map(|s| s.chars().next())
         .collect();
-    let mut builder = ListStringChunkedBuilder::new("".into(), ca.len(), 0);
-    for opt_s in ca.into_iter() {
-        match opt_s {
-            None => builder.append_null(),
-            Some(s) => {
-                let mut parts: Vec<String> = Vec::new();
-                let mut buffer = String::new();
-                let mut chars = s.chars().peekable();
-                while let Some(c) = chars.next() {
-                    buffer.push(c);
-                    if before_set.contains(&c) {
-                        if let Some(&next) = chars.peek() {
-                            if after_set.contains(&next) {
-                                parts.push(std::mem::take(&mut buffer));
-                            }
-                        }
+    build_list_string_series(ca, |s| {
+        let mut parts: Vec<String> = Vec::new();
+        let mut buffer = String::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            buffer.push(c);
+            if before_set.contains(&c) {
+                if let Some(&next) = chars.peek() {
+                    if after_set.contains(&next) {
+                        parts.push(std::mem::take(&mut buffer));
                     }
                 }
-                if !buffer.is_empty() {
-                    parts.push(buffer);
-                }
-                builder.append_series(&Series::new("".into(), parts))?;
             }
         }
+        if !buffer.is_empty() {
+            parts.push(buffer);
+        }
+        parts
+    })
+}
+
+// Whether a camelCase word boundary lies between the previous and the current character. An
+// uppercase letter following a run of uppercase letters only starts a new word when a lowercase
+// letter comes next, so that acronyms stay together, e.g. "HTTPServer" is "HTTP" and "Server".
+fn is_camel_case_boundary(previous: Option<char>, current: char, next: Option<char>) -> bool {
+    let Some(previous) = previous else {
+        return false;
+    };
+    if !current.is_uppercase() {
+        return false;
     }
-    Ok(builder.finish().into_series())
+    previous.is_lowercase() || previous.is_uppercase() && next.is_some_and(char::is_lowercase)
+}
+
+#[derive(Deserialize)]
+pub struct SentenceCaseKwargs {
+    split_camel_case: bool,
 }
 
 #[polars_expr(output_type=String)]
-fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
+fn to_sentence_case(inputs: &[Series], kwargs: SentenceCaseKwargs) -> PolarsResult<Series> {
     let ca: &StringChunked = inputs[0].str()?;
     let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
         let mut capitalize_next = true;
-        let mut last_char_was_lowercase = false;
         let mut last_char_was_punctuation = false;
+        let mut previous: Option<char> = None;
+        let mut keep_word = false;
 
-        for c in value.chars() {
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
             if c.is_alphabetic() {
-                // Insert a space if the last character was lowercase and the current is uppercase.
-                let should_insert_space = last_char_was_lowercase && c.is_uppercase();
+                // Insert a space at camelCase boundaries, if requested.
+                let should_insert_space = kwargs.split_camel_case && is_camel_case_boundary(previous, c, chars.peek().copied());
                 if should_insert_space {
                     output.push(' ');
                 }
 
+                // Unless camelCase is split, deliberately mixed-case words like "iPhone", i.e. with a
+                // lowercase letter followed by an uppercase one, are left as they are
+                if previous.is_none() {
+                    let word: Vec<char> = std::iter::once(c).chain(chars.clone().take_while(|n| n.is_alphabetic())).collect();
+                    keep_word = !kwargs.split_camel_case && word.windows(2).any(|pair| pair[0].is_lowercase() && pair[1].is_uppercase());
+                }
+
                 // Apply capitalization rules
-                if capitalize_next {
+                if keep_word {
+                    output.push(c);
+                } else if capitalize_next {
                     output.extend(c.to_uppercase());
                 } else {
                     output.extend(c.to_lowercase());
@@ -181,8 +251,8 @@ fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
 
                 // Update state variables for the next iteration.
                 capitalize_next = false;
-                last_char_was_lowercase = c.is_lowercase();
                 last_char_was_punctuation = false;
+                previous = Some(c);
             }
             // It's a non-alphabetic character.
             else {
@@ -201,7 +271,7 @@ fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
                     capitalize_next = false;
                     last_char_was_punctuation = false;
                 }
-                last_char_was_lowercase = false;
+                previous = None;
             }
         }
     });
@@ -226,4 +296,7065 @@ fn to_sponge_case(inputs: &[Series]) -> PolarsResult<Series> {
         }
     });
     Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct MaskIpKwargs {
+    keep_segments: usize,
+    mask: String,
+}
+
+fn is_ip_char(c: char) -> bool {
+    c.is_ascii_hexdigit() || c == '.' || c == ':'
+}
+
+fn parse_ip_candidate(candidate: &str) -> Option<(&str, IpAddr)> {
+    // Sentence punctuation may stick to the end of an address, e.g. "from 10.0.0.1."
+    let trimmed = candidate.trim_end_matches('.');
+    [candidate, trimmed]
+        .into_iter()
+        .find_map(|c| c.parse::<IpAddr>().ok().map(|addr| (c, addr)))
+}
+
+// Byte ranges of the addresses within the run of address characters between start and end
+fn find_ip_addresses(value: &str, start: usize, end: usize) -> Vec<(usize, usize, IpAddr)> {
+    // Ignore candidates glued to other words, e.g. "v1.2.3.4" or "1.2.3.4x"
+    let glued = |s: usize, e: usize| {
+        value[..s].chars().next_back().is_some_and(|p| p.is_alphanumeric() || p == '_')
+            || value[e..].chars().next().is_some_and(|n| n.is_alphanumeric() || n == '_')
+    };
+    let parse = |s: usize, e: usize| {
+        if s >= e || glued(s, e) {
+            return None;
+        }
+        parse_ip_candidate(&value[s..e]).map(|(matched, addr)| (s, s + matched.len(), addr))
+    };
+
+    // A leading "key:" or a trailing colon sticks to the run, e.g. "ip:10.0.0.1" or "10.0.0.1: refused"
+    let run = &value[start..end];
+    let stripped = run.trim_start_matches(':');
+    let stripped_start = end - stripped.len();
+    let stripped_end = stripped_start + stripped.trim_end_matches(':').len();
+    if let Some(found) = parse(start, end).or_else(|| parse(stripped_start, stripped_end)) {
+        return vec![found];
+    }
+
+    // Otherwise look for IPv4 addresses between the colons, e.g. the one in "10.0.0.1:8080"
+    let mut offset = start;
+    run.split(':')
+        .filter_map(|segment| {
+            let segment_start = offset;
+            offset += segment.len() + 1;
+            parse(segment_start, segment_start + segment.len())
+        })
+        .collect()
+}
+
+fn mask_ip_address(addr: &IpAddr, keep_segments: usize, mask: &str) -> String {
+    match addr {
+        IpAddr::V4(v4) => v4
+            .octets()
+            .iter()
+            .enumerate()
+            .map(|(i, octet)| if i < keep_segments { octet.to_string() } else { mask.to_string() })
+            .collect::<Vec<String>>()
+            .join("."),
+        IpAddr::V6(v6) => v6
+            .segments()
+            .iter()
+            .enumerate()
+            .map(|(i, hextet)| if i < keep_segments { format!("{:x}", hextet) } else { mask.to_string() })
+            .collect::<Vec<String>>()
+            .join(":"),
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn mask_ip(inputs: &[Series], kwargs: MaskIpKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut last_end = 0;
+        let mut chars = value.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if !is_ip_char(c) {
+                continue;
+            }
+
+            // Collect the whole run of characters that may form an address
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, next)) = chars.peek() {
+                if !is_ip_char(next) {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+
+            for (ip_start, ip_end, addr) in find_ip_addresses(value, start, end) {
+                output.push_str(&value[last_end..ip_start]);
+                output.push_str(&mask_ip_address(&addr, kwargs.keep_segments, &kwargs.mask));
+                last_end = ip_end;
+            }
+        }
+        output.push_str(&value[last_end..]);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct PalindromeKwargs {
+    ignore_case: bool,
+    ignore_non_alphanumeric: bool,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_palindrome(inputs: &[Series], kwargs: PalindromeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| {
+            // Compare grapheme clusters so that combining marks stay attached to their base
+            let graphemes: Vec<String> = value
+                .graphemes(true)
+                .filter(|g| !kwargs.ignore_non_alphanumeric || g.chars().next().is_some_and(char::is_alphanumeric))
+                .map(|g| if kwargs.ignore_case { g.to_lowercase() } else { g.to_string() })
+                .collect();
+            graphemes.iter().eq(graphemes.iter().rev())
+        })
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_valid_ip(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| value.parse::<IpAddr>().is_ok())
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Int8)]
+fn ip_version(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Int8Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(|value| match value.parse::<IpAddr>() {
+            Ok(IpAddr::V4(_)) => Some(4),
+            Ok(IpAddr::V6(_)) => Some(6),
+            Err(_) => None,
+        })
+    });
+    Ok(out.into_series())
+}
+
+fn lcs_length<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+    for x in a {
+        for (j, y) in b.iter().enumerate() {
+            current[j + 1] = if x == y {
+                previous[j] + 1
+            } else {
+                current[j].max(previous[j + 1])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+// Normalized indel similarity in the range of 0-100, as popularized by fuzzy-matching libraries
+fn similarity_ratio(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let total = a.len() + b.len();
+    if total == 0 {
+        return 100.0;
+    }
+    200.0 * lcs_length(&a, &b) as f64 / total as f64
+}
+
+fn sort_tokens(value: &str, case_insensitive: bool) -> String {
+    let mut tokens: Vec<String> = value
+        .split_whitespace()
+        .map(|token| if case_insensitive { token.to_lowercase() } else { token.to_string() })
+        .collect();
+    tokens.sort();
+    tokens.join(" ")
+}
+
+#[derive(Deserialize)]
+pub struct TokenSortKwargs {
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=Float64)]
+fn token_sort_ratio(inputs: &[Series], kwargs: TokenSortKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: Float64Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(similarity_ratio(
+                &sort_tokens(a, kwargs.case_insensitive),
+                &sort_tokens(b, kwargs.case_insensitive),
+            )),
+            _ => None,
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=UInt32)]
+fn ipv4_to_int(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: UInt32Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(|value| value.parse::<Ipv4Addr>().ok().map(u32::from))
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn int_to_ipv4(inputs: &[Series]) -> PolarsResult<Series> {
+    // Values which do not fit into 32 bits become nulls
+    let series = inputs[0].cast(&DataType::UInt32)?;
+    let ca: &UInt32Chunked = series.u32()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_v: Option<u32>| {
+        opt_v.map(|value| Ipv4Addr::from(value).to_string())
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NgramMode {
+    Word,
+    Char,
+}
+
+#[derive(Deserialize)]
+pub struct NgramKwargs {
+    n: usize,
+    mode: NgramMode,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn ngrams(inputs: &[Series], kwargs: NgramKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.n > 0, InvalidOperation: "n must be greater than zero");
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        let (units, separator): (Vec<&str>, &str) = match kwargs.mode {
+            NgramMode::Word => (s.unicode_words().collect(), " "),
+            NgramMode::Char => (s.graphemes(true).collect(), ""),
+        };
+        units
+            .windows(kwargs.n)
+            .map(|window| window.join(separator))
+            .collect()
+    })
+}
+
+fn parse_cidr(value: &str) -> Option<(IpAddr, u32)> {
+    let (network, prefix) = value.trim().split_once('/')?;
+    let network: IpAddr = network.parse().ok()?;
+    let prefix: u32 = prefix.parse().ok()?;
+    let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+    (prefix <= max_prefix).then_some((network, prefix))
+}
+
+fn ip_in_network(addr: &IpAddr, network: &IpAddr, prefix: u32) -> bool {
+    match (addr, network) {
+        (IpAddr::V4(addr), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(*addr) & mask == u32::from(*network) & mask
+        },
+        (IpAddr::V6(addr), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(*addr) & mask == u128::from(*network) & mask
+        },
+        _ => false,
+    }
+}
+
+#[polars_expr(output_type=Boolean)]
+fn cidr_contains(inputs: &[Series]) -> PolarsResult<Series> {
+    let addresses: &StringChunked = inputs[0].str()?;
+    let networks: &StringChunked = inputs[1].str()?;
+    let out: BooleanChunked = broadcast_binary_elementwise(addresses, networks, |a: Option<&str>, n: Option<&str>| {
+        let addr: IpAddr = a?.trim().parse().ok()?;
+        let (network, prefix) = parse_cidr(n?)?;
+        Some(ip_in_network(&addr, &network, prefix))
+    });
+    Ok(out.into_series())
+}
+
+fn parse_mac_address(value: &str) -> Option<String> {
+    let value = value.trim();
+    let groups: Vec<&str> =
+    if value.contains(':') {
+        value.split(':').collect()
+    } else if value.contains('-') {
+        value.split('-').collect()
+    } else if value.contains('.') {
+        value.split('.').collect()
+    } else {
+        vec![value]
+    };
+
+    // Accept six pairs, three quads (Cisco dotted notation), or a bare run of digits
+    let group_len = match groups.len() {
+        6 => 2,
+        3 => 4,
+        1 => 12,
+        _ => return None,
+    };
+    if groups.iter().any(|g| g.len() != group_len || !g.chars().all(|c| c.is_ascii_hexdigit())) {
+        return None;
+    }
+
+    let digits = groups.concat().to_ascii_lowercase();
+    let pairs: Vec<&str> = (0..12).step_by(2).map(|i| &digits[i..i + 2]).collect();
+    Some(pairs.join(":"))
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_mac(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(parse_mac_address)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct StripHtmlKwargs {
+    decode_entities: bool,
+    collapse_whitespace: bool,
+}
+
+// Returns the byte length of the tag starting at the beginning of the text,
+// or none when the text does not start with a well-formed tag.
+fn html_tag_length(text: &str) -> Option<usize> {
+    if let Some(comment) = text.strip_prefix("<!--") {
+        return comment.find("-->").map(|end| 4 + end + 3);
+    }
+
+    let mut chars = text.char_indices().skip(1);
+    let (_, first) = chars.next()?;
+    if !(first.is_ascii_alphabetic() || matches!(first, '/' | '!' | '?')) {
+        return None;
+    }
+
+    // Skip over quoted attribute values since they may contain '>'
+    let mut quote: Option<char> = None;
+    for (i, c) in chars {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {},
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return Some(i + 1),
+            None => {},
+        }
+    }
+    None
+}
+
+fn decode_html_entity(entity: &str) -> Option<char> {
+    if let Some(code) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+        return u32::from_str_radix(code, 16).ok().and_then(char::from_u32);
+    }
+    if let Some(code) = entity.strip_prefix('#') {
+        return code.parse::<u32>().ok().and_then(char::from_u32);
+    }
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => None,
+    }
+}
+
+fn decode_html_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_html_entity(&rest[1..end + 1]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, length)) => {
+                result.push(c);
+                rest = &rest[length..];
+            },
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[polars_expr(output_type=String)]
+fn strip_html_tags(inputs: &[Series], kwargs: StripHtmlKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut text = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find('<') {
+            text.push_str(&rest[..start]);
+            rest = &rest[start..];
+            match html_tag_length(rest) {
+                Some(length) => rest = &rest[length..],
+                // Not a tag, e.g. "a < b" or an unterminated tag, so keep it as is
+                None => {
+                    text.push('<');
+                    rest = &rest[1..];
+                },
+            }
+        }
+        text.push_str(rest);
+
+        if kwargs.decode_entities {
+            text = decode_html_entities(&text);
+        }
+
+        if kwargs.collapse_whitespace {
+            write!(output, "{}", text.split_whitespace().collect::<Vec<&str>>().join(" ")).unwrap();
+        } else {
+            output.push_str(&text);
+        }
+    });
+    Ok(out.into_series())
+}
+
+// Calls the function on every whitespace-separated token, copying the whitespace in between as is
+fn for_each_token<F>(value: &str, output: &mut String, mut f: F)
+where
+    F: FnMut(&str, &mut String),
+{
+    let mut token_start: Option<usize> = None;
+    for (i, c) in value.char_indices() {
+        if c.is_whitespace() {
+            if let Some(start) = token_start.take() {
+                f(&value[start..i], output);
+            }
+            output.push(c);
+        } else if token_start.is_none() {
+            token_start = Some(i);
+        }
+    }
+    if let Some(start) = token_start {
+        f(&value[start..], output);
+    }
+}
+
+fn is_acronym(token: &str, min_length: usize) -> bool {
+    let letters: Vec<char> = token.chars().filter(|c| c.is_alphabetic()).collect();
+    letters.len() >= min_length && letters.iter().all(|c| c.is_uppercase())
+}
+
+fn push_capitalized(token: &str, output: &mut String) {
+    let mut capitalized = false;
+    for c in token.chars() {
+        if !capitalized && c.is_alphabetic() {
+            output.extend(c.to_uppercase());
+            capitalized = true;
+        } else {
+            output.extend(c.to_lowercase());
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SmartTitleKwargs {
+    min_acronym_length: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn smart_title(inputs: &[Series], kwargs: SmartTitleKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for_each_token(value, output, |token, output| {
+            if is_acronym(token, kwargs.min_acronym_length) {
+                output.push_str(token);
+            } else {
+                push_capitalized(token, output);
+            }
+        });
+    });
+    Ok(out.into_series())
+}
+
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?i)\b[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*",
+        r"@(?:[a-z0-9](?:[a-z0-9-]*[a-z0-9])?\.)+[a-z]{2,}\b",
+    ))
+    .unwrap()
+});
+
+#[derive(Deserialize)]
+pub struct ExtractEmailsKwargs {
+    lowercase: bool,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_emails(inputs: &[Series], kwargs: ExtractEmailsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        EMAIL_REGEX
+            .find_iter(s)
+            .map(|m| if kwargs.lowercase { m.as_str().to_lowercase() } else { m.as_str().to_string() })
+            .collect()
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceStrategy {
+    Close,
+    Remove,
+}
+
+#[derive(Deserialize)]
+pub struct BalanceQuotesKwargs {
+    strategy: BalanceStrategy,
+}
+
+fn closing_pair(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '"' => Some('"'),
+        '\u{201c}' => Some('\u{201d}'),
+        _ => None,
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn balance_quotes(inputs: &[Series], kwargs: BalanceQuotesKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut stack: Vec<(usize, char)> = Vec::new();
+        let mut removals: HashSet<usize> = HashSet::new();
+        let mut insertions: Vec<(usize, char)> = Vec::new();
+
+        for (i, c) in value.char_indices() {
+            let is_closer = matches!(c, ')' | ']' | '}' | '\u{201d}')
+                || (c == '"' && stack.iter().any(|&(_, o)| o == '"'));
+            if !is_closer {
+                if closing_pair(c).is_some() {
+                    stack.push((i, c));
+                }
+                continue;
+            }
+
+            match stack.iter().rposition(|&(_, o)| closing_pair(o) == Some(c)) {
+                Some(position) => {
+                    // Any opener above the matching one was left unclosed
+                    for (j, opener) in stack.drain(position..).skip(1).rev() {
+                        match kwargs.strategy {
+                            BalanceStrategy::Close => insertions.push((i, closing_pair(opener).unwrap())),
+                            BalanceStrategy::Remove => { removals.insert(j); },
+                        }
+                    }
+                },
+                None => { removals.insert(i); },
+            }
+        }
+
+        // Whatever is still open at the end is either dropped or closed after the text
+        if let BalanceStrategy::Remove = kwargs.strategy {
+            removals.extend(stack.iter().map(|&(j, _)| j));
+        }
+
+        for (i, c) in value.char_indices() {
+            for &(_, closer) in insertions.iter().filter(|&&(at, _)| at == i) {
+                output.push(closer);
+            }
+            if !removals.contains(&i) {
+                output.push(c);
+            }
+        }
+
+        if let BalanceStrategy::Close = kwargs.strategy {
+            output.extend(stack.iter().rev().map(|&(_, opener)| closing_pair(opener).unwrap()));
+        }
+    });
+    Ok(out.into_series())
+}
+
+static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\b([a-z][a-z0-9+.-]*)://[^\s<>"'`]+"#).unwrap()
+});
+
+// Drops punctuation that most likely belongs to the surrounding sentence
+fn trim_url_end(url: &str) -> &str {
+    let mut url = url;
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        let trimmed = match trimmed.chars().next_back() {
+            Some(')') if trimmed.matches('(').count() < trimmed.matches(')').count() => &trimmed[..trimmed.len() - 1],
+            Some(']') if trimmed.matches('[').count() < trimmed.matches(']').count() => &trimmed[..trimmed.len() - 1],
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            return url;
+        }
+        url = trimmed;
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ExtractUrlsKwargs {
+    schemes: Vec<String>,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_urls(inputs: &[Series], kwargs: ExtractUrlsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        URL_REGEX
+            .captures_iter(s)
+            .filter(|c| kwargs.schemes.iter().any(|scheme| scheme.eq_ignore_ascii_case(&c[1])))
+            .map(|c| trim_url_end(c.get(0).unwrap().as_str()))
+            // A lone scheme such as "http://." has nothing left after trimming
+            .filter(|url| !url.ends_with("://"))
+            .map(String::from)
+            .collect()
+    })
+}
+
+fn char_ratio<F>(value: &str, predicate: F) -> f64
+where
+    F: Fn(char) -> bool,
+{
+    let (matched, total) = value
+        .chars()
+        .fold((0usize, 0usize), |(matched, total), c| (matched + predicate(c) as usize, total + 1));
+    if total == 0 {
+        return 1.0;
+    }
+    matched as f64 / total as f64
+}
+
+#[polars_expr(output_type=Float64)]
+fn ascii_ratio(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| char_ratio(value, |c| c.is_ascii()))
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_ascii(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| value.is_ascii())
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn non_ascii_ratio(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| 1.0 - char_ratio(value, |c| c.is_ascii()))
+    });
+    Ok(out.into_series())
+}
+
+// Whitespace such as tabs and newlines is considered printable, while other control
+// characters and the replacement character left behind by failed decoding are not.
+fn is_printable(c: char) -> bool {
+    (c.is_whitespace() || !c.is_control()) && c != '\u{fffd}'
+}
+
+#[polars_expr(output_type=Float64)]
+fn printable_ratio(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| char_ratio(value, is_printable))
+    });
+    Ok(out.into_series())
+}
+
+// Digit groups joined by a single space, period or hyphen, after an optional country code and area
+// code in parentheses, so that the match neither spans lines nor swallows unrelated numbers
+static PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{1,4}\)[ .-]?)?\b\d{2,}(?:[ .-]\d{2,})*\b").unwrap()
+});
+
+static PHONE_DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:\d{4}[-./]\d{2}[-./]\d{2}|\d{2}[-./]\d{2}[-./]\d{4})$").unwrap()
+});
+
+#[derive(Deserialize)]
+pub struct MaskPiiKwargs {
+    mask_char: char,
+    mask_emails: bool,
+    mask_phones: bool,
+}
+
+// Keeps the first character of the local part and the whole domain, e.g. "j***@example.com"
+fn mask_email(email: &str, mask_char: char) -> String {
+    let (local, domain) = email.split_once('@').unwrap();
+    let first = local.chars().next().unwrap();
+    format!("{}{}@{}", first, mask_char.to_string().repeat(3), domain)
+}
+
+// Keeps the separators and the last four digits, e.g. "(***) ***-2671"
+fn mask_phone(phone: &str, mask_char: char) -> String {
+    let total_digits = phone.chars().filter(char::is_ascii_digit).count();
+    let mut seen_digits = 0;
+    phone
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            seen_digits += 1;
+            if seen_digits > total_digits.saturating_sub(4) { c } else { mask_char }
+        })
+        .collect()
+}
+
+#[polars_expr(output_type=String)]
+fn mask_pii(inputs: &[Series], kwargs: MaskPiiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut text = value.to_string();
+        if kwargs.mask_emails {
+            text = EMAIL_REGEX
+                .replace_all(&text, |c: &regex::Captures| mask_email(&c[0], kwargs.mask_char))
+                .into_owned();
+        }
+        if kwargs.mask_phones {
+            text = PHONE_REGEX
+                .replace_all(&text, |c: &regex::Captures| {
+                    // Too few or too many digits to be a phone number, or rather a date
+                    let digits = c[0].chars().filter(char::is_ascii_digit).count();
+                    if (7..=15).contains(&digits) && !PHONE_DATE_REGEX.is_match(&c[0]) {
+                        mask_phone(&c[0], kwargs.mask_char)
+                    } else {
+                        c[0].to_string()
+                    }
+                })
+                .into_owned();
+        }
+        output.push_str(&text);
+    });
+    Ok(out.into_series())
+}
+
+fn bracket_depth(value: &str) -> Option<u32> {
+    let mut stack: Vec<char> = Vec::new();
+    let mut max_depth = 0;
+    for c in value.chars() {
+        match c {
+            '(' | '[' | '{' => {
+                stack.push(c);
+                max_depth = max_depth.max(stack.len() as u32);
+            },
+            ')' | ']' | '}' => {
+                let opener = stack.pop()?;
+                if closing_pair(opener) != Some(c) {
+                    return None;
+                }
+            },
+            _ => {},
+        }
+    }
+    stack.is_empty().then_some(max_depth)
+}
+
+#[polars_expr(output_type=UInt32)]
+fn max_bracket_depth(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: UInt32Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(bracket_depth)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct RedactKwargs {
+    pattern: String,
+    replacement: String,
+}
+
+#[polars_expr(output_type=String)]
+fn redact_by_regex(inputs: &[Series], kwargs: RedactKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let regex = Regex::new(&kwargs.pattern)
+        .map_err(|e| polars_err!(ComputeError: "invalid pattern {:?}: {}", kwargs.pattern, e))?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.push_str(&regex.replace_all(value, NoExpand(&kwargs.replacement)));
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct StripOuterQuotesKwargs {
+    unescape_doubled: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_outer_quotes(inputs: &[Series], kwargs: StripOuterQuotesKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let quote = value.chars().next().filter(|&c| c == '"' || c == '\'');
+        match quote {
+            Some(q) if value.len() >= 2 && value.ends_with(q) => {
+                let inner = &value[1..value.len() - 1];
+                if kwargs.unescape_doubled {
+                    let doubled: String = [q, q].iter().collect();
+                    output.push_str(&inner.replace(&doubled, &q.to_string()));
+                } else {
+                    output.push_str(inner);
+                }
+            },
+            _ => output.push_str(value),
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShingleMode {
+    Word,
+    Char,
+}
+
+fn shingle_set(value: &str, mode: &ShingleMode, size: usize) -> HashSet<String> {
+    let (units, separator): (Vec<&str>, &str) = match mode {
+        ShingleMode::Word => (value.unicode_words().collect(), " "),
+        ShingleMode::Char => (value.graphemes(true).collect(), ""),
+    };
+    // Strings shorter than a single shingle are compared as a whole
+    if !units.is_empty() && units.len() < size {
+        return HashSet::from([units.join(separator)]);
+    }
+    units.windows(size).map(|window| window.join(separator)).collect()
+}
+
+#[derive(Deserialize)]
+pub struct JaccardKwargs {
+    mode: ShingleMode,
+    shingle_size: usize,
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=Float64)]
+fn jaccard_similarity(inputs: &[Series], kwargs: JaccardKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.shingle_size > 0, InvalidOperation: "shingle_size must be greater than zero");
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let shingles = |value: &str| {
+        if kwargs.case_insensitive {
+            shingle_set(&value.to_lowercase(), &kwargs.mode, kwargs.shingle_size)
+        } else {
+            shingle_set(value, &kwargs.mode, kwargs.shingle_size)
+        }
+    };
+    let out: Float64Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (shingles(a?), shingles(b?));
+        let union = a.union(&b).count();
+        if union == 0 {
+            return Some(1.0);
+        }
+        Some(a.intersection(&b).count() as f64 / union as f64)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct RepeatToKwargs {
+    length: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn repeat_to_length(inputs: &[Series], kwargs: RepeatToKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.extend(value.chars().cycle().take(kwargs.length));
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct RepeatKwargs {
+    times: usize,
+    separator: String,
+}
+
+#[polars_expr(output_type=String)]
+fn repeat(inputs: &[Series], kwargs: RepeatKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for i in 0..kwargs.times {
+            if i > 0 {
+                output.push_str(&kwargs.separator);
+            }
+            output.push_str(value);
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct HammingKwargs {
+    pad_mismatch: bool,
+}
+
+#[polars_expr(output_type=UInt32)]
+fn hamming_distance(inputs: &[Series], kwargs: HammingKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: UInt32Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (a?, b?);
+        let (a_len, b_len) = (a.chars().count(), b.chars().count());
+        if a_len != b_len && !kwargs.pad_mismatch {
+            return None;
+        }
+        // The extra characters of the longer string all count as mismatches
+        let mismatches = a.chars().zip(b.chars()).filter(|(x, y)| x != y).count();
+        Some((mismatches + a_len.abs_diff(b_len)) as u32)
+    });
+    Ok(out.into_series())
+}
+
+fn list_int64_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Int64)),
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct ExpandRangesKwargs {
+    range_separator: String,
+    list_separator: String,
+    skip_invalid: bool,
+    max_items: usize,
+}
+
+fn parse_range(token: &str, range_separator: &str) -> Option<(i64, i64)> {
+    // Skip the first character so that a leading minus sign is not mistaken for a separator
+    let split_at = token
+        .get(1..)
+        .and_then(|rest| rest.find(range_separator))
+        .map(|i| i + 1);
+    match split_at {
+        Some(i) => {
+            let start = token[..i].trim().parse().ok()?;
+            let end = token[i + range_separator.len()..].trim().parse().ok()?;
+            Some((start, end))
+        },
+        None => token.parse().ok().map(|value| (value, value)),
+    }
+}
+
+#[polars_expr(output_type_func=list_int64_output)]
+fn expand_ranges(inputs: &[Series], kwargs: ExpandRangesKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.range_separator.is_empty() && !kwargs.list_separator.is_empty(),
+                   InvalidOperation: "separators must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new("".into(), ca.len(), 0, DataType::Int64);
+    for opt_s in ca.into_iter() {
+        match opt_s {
+            None => builder.append_null(),
+            Some(s) => {
+                let mut values: Vec<i64> = Vec::new();
+                for token in s.split(kwargs.list_separator.as_str()).map(str::trim).filter(|t| !t.is_empty()) {
+                    match parse_range(token, &kwargs.range_separator) {
+                        Some((start, end)) if start <= end => {
+                            // Huge ranges like "1-1000000000000" are bounded so as not to run out of memory
+                            let count = end.abs_diff(start) as u128 + 1;
+                            if values.len() as u128 + count <= kwargs.max_items as u128 {
+                                values.extend(start..=end);
+                            } else {
+                                polars_ensure!(kwargs.skip_invalid,
+                                               ComputeError: "range {:?} in {:?} expands to more than {} items", token, s, kwargs.max_items);
+                            }
+                        },
+                        _ if kwargs.skip_invalid => {},
+                        _ => polars_bail!(ComputeError: "invalid range {:?} in {:?}", token, s),
+                    }
+                }
+                builder.append_slice(&values);
+            }
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+// Short samples of common words and phrases from which the character trigram
+// frequencies of each language are counted on first use.
+const LANGUAGE_SAMPLES: &[(&str, &str)] = &[
+    ("en", "the of and to in is you that it he was for on are as with his they at be this have from \
+            or one had by word but not what all were we when your can said there use an each which she \
+            do how their if will up other about out many then them these so some her would make like him \
+            into time has look two more write go see number no way could people my than first water been \
+            call who oil its now find long down day did get come made may part the quick brown fox jumps \
+            over the lazy dog this is a simple sentence written in english and it should be easy to read \
+            where are you going today i think that we should have gone there together with them yesterday \
+            what is the weather like this morning because they said that it would be nice and warm \
+            the children are playing in the garden while their parents are cooking dinner in the kitchen \
+            she has been working at the hospital for three years and she likes her job very much"),
+    ("fr", "le de un être et à il avoir ne je son que se qui ce dans en du elle au pour pas que vous par \
+            sur faire plus dire me on mon lui nous comme mais pouvoir avec tout y aller voir en bien où \
+            sans tu ou leur homme si deux mari moi vouloir te femme venir quand grand celui notre devoir \
+            là jour prendre même votre rien petit encore aussi quelque dont tout mer trouver donner temps \
+            ça peu même falloir sous parler alors premier reste le renard brun rapide saute par dessus le \
+            chien paresseux ceci est une phrase simple écrite en français et elle devrait être facile à lire \
+            où allez vous aujourd'hui je pense que nous devrions y aller ensemble avec eux demain matin \
+            quel temps fait il ce matin parce qu'ils ont dit qu'il ferait beau et chaud \
+            les enfants jouent dans le jardin pendant que leurs parents préparent le dîner dans la cuisine \
+            elle travaille à l'hôpital depuis trois ans et elle aime beaucoup son travail c'est la vie \
+            est ce que tu veux venir avec nous au cinéma ce soir il y a un nouveau film"),
+    ("de", "der die und in den von zu das mit sich des auf für ist im dem nicht ein eine als auch es an \
+            werden aus er hat dass sie nach wird bei einer um am sind noch wie einem über einen so zum war \
+            haben nur oder aber vor zur bis mehr durch man sein wurde sei ihr ich wir kann wenn schon \
+            können gegen sehr diese heute jahr zeit jetzt immer zwischen einmal wieder dann der schnelle \
+            braune fuchs springt über den faulen hund dies ist ein einfacher satz der auf deutsch \
+            geschrieben wurde und er sollte leicht zu lesen sein wohin gehst du heute ich glaube dass wir \
+            gestern zusammen mit ihnen dorthin hätten gehen sollen wie ist das wetter heute morgen weil \
+            sie gesagt haben dass es schön und warm sein würde \
+            die kinder spielen im garten während ihre eltern in der küche das abendessen kochen \
+            sie arbeitet seit drei jahren im krankenhaus und ihre arbeit gefällt ihr sehr gut \
+            möchtest du heute abend mit uns ins kino gehen es gibt einen neuen film"),
+    ("es", "de la que el en y a los se del las un por con no una su para es al lo como más o pero sus le \
+            ha me si sin sobre este ya entre cuando todo esta ser son dos también fue había era muy años \
+            hasta desde está mi porque qué sólo han yo hay vez puede todos así nos ni parte tiene él uno \
+            donde bien tiempo mismo ese ahora cada e vida otro después te otros aunque esa eso hace otra \
+            el rápido zorro marrón salta sobre el perro perezoso esta es una frase sencilla escrita en \
+            español y debería ser fácil de leer a dónde vas hoy creo que deberíamos haber ido allí juntos \
+            con ellos ayer qué tiempo hace esta mañana porque dijeron que haría bueno y calor \
+            los niños juegan en el jardín mientras sus padres preparan la cena en la cocina \
+            ella trabaja en el hospital desde hace tres años y le gusta mucho su trabajo \
+            quieres venir con nosotros al cine esta noche hay una película nueva"),
+    ("it", "di e il la che a per un in è del non sono una le si da con i dei al della gli ha come più \
+            anche ma questo essere se ci nel alla tra ho mi lo delle io fare quando molto tutto ancora \
+            suo dove sua cosa poi stato solo così già era due perché hanno questa dopo ne sempre fatto \
+            anni ora casa bene tempo lavoro vita parte giorno oggi il veloce volpe marrone salta sopra il \
+            cane pigro questa è una frase semplice scritta in italiano e dovrebbe essere facile da leggere \
+            dove stai andando oggi penso che saremmo dovuti andare lì insieme a loro ieri che tempo fa \
+            questa mattina perché hanno detto che sarebbe stato bello e caldo \
+            i bambini giocano nel giardino mentre i loro genitori preparano la cena in cucina \
+            lei lavora in ospedale da tre anni e il suo lavoro le piace molto \
+            vuoi venire con noi al cinema stasera c'è un film nuovo"),
+    ("pt", "de a o que e do da em um para é com não uma os no se na por mais as dos como mas foi ao ele \
+            das tem à seu sua ou ser quando muito há nos já está eu também só pelo pela até isso ela entre \
+            era depois sem mesmo aos ter seus quem nas me esse eles estão você tinha foram essa num nem \
+            suas meu às minha têm numa pelos elas havia seja qual será nós tenho lhe deles essas esses a \
+            rápida raposa marrom pula sobre o cão preguiçoso esta é uma frase simples escrita em português \
+            e deveria ser fácil de ler para onde você vai hoje acho que deveríamos ter ido lá juntos com \
+            eles ontem como está o tempo esta manhã porque disseram que seria bom e quente \
+            as crianças brincam no jardim enquanto os pais preparam o jantar na cozinha \
+            ela trabalha no hospital há três anos e gosta muito do seu trabalho \
+            você quer ir ao cinema conosco hoje à noite tem um filme novo"),
+    ("nl", "de van een het en in is dat op te zijn voor met die niet aan er om ook als dan maar bij of \
+            uit nog wat tot door over ik naar je kan zo wel al was hij we worden heeft geen hebben dit \
+            zou werd deze na moet ze nu hun wordt veel meer moeten onder tegen ons zonder tussen jaar de \
+            snelle bruine vos springt over de luie hond dit is een eenvoudige zin geschreven in het \
+            nederlands en het zou makkelijk te lezen moeten zijn waar ga je vandaag naartoe ik denk dat \
+            we er gisteren samen met hen heen hadden moeten gaan hoe is het weer vanochtend omdat ze zeiden \
+            dat het mooi en warm zou worden \
+            de kinderen spelen in de tuin terwijl hun ouders in de keuken het avondeten koken \
+            zij werkt al drie jaar in het ziekenhuis en ze vindt haar werk erg leuk \
+            wil je vanavond met ons naar de bioscoop gaan er draait een nieuwe film"),
+];
+
+fn trigram_counts(text: &str) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in text.unicode_words() {
+        let padded: Vec<char> = format!(" {} ", word.to_lowercase()).chars().collect();
+        for window in padded.windows(3) {
+            *counts.entry(window.iter().collect()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+struct LanguageProfile {
+    code: &'static str,
+    counts: HashMap<String, usize>,
+    total: usize,
+}
+
+impl LanguageProfile {
+    // Log-likelihood of the trigrams under this profile, with add-one smoothing
+    fn score(&self, trigrams: &HashMap<String, usize>) -> f64 {
+        let denominator = (self.total + self.counts.len()) as f64;
+        trigrams
+            .iter()
+            .map(|(trigram, &n)| {
+                let count = self.counts.get(trigram).copied().unwrap_or(0);
+                n as f64 * ((count + 1) as f64 / denominator).ln()
+            })
+            .sum()
+    }
+}
+
+static LANGUAGE_PROFILES: LazyLock<Vec<LanguageProfile>> = LazyLock::new(|| {
+    LANGUAGE_SAMPLES
+        .iter()
+        .map(|&(code, sample)| {
+            let counts = trigram_counts(sample);
+            let total = counts.values().sum();
+            LanguageProfile { code, counts, total }
+        })
+        .collect()
+});
+
+#[derive(Deserialize)]
+pub struct DetectLanguageKwargs {
+    candidates: Vec<String>,
+    min_length: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn detect_language(inputs: &[Series], kwargs: DetectLanguageKwargs) -> PolarsResult<Series> {
+    if let Some(unknown) = kwargs.candidates.iter().find(|c| !LANGUAGE_SAMPLES.iter().any(|(code, _)| code == c)) {
+        polars_bail!(InvalidOperation: "unsupported language {:?}", unknown);
+    }
+    let profiles: Vec<&LanguageProfile> = LANGUAGE_PROFILES
+        .iter()
+        .filter(|profile| kwargs.candidates.is_empty() || kwargs.candidates.iter().any(|c| c == profile.code))
+        .collect();
+
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let value = opt_s?;
+        if value.chars().filter(|c| c.is_alphabetic()).count() < kwargs.min_length {
+            return None;
+        }
+        let trigrams = trigram_counts(value);
+        if trigrams.is_empty() {
+            return None;
+        }
+        profiles
+            .iter()
+            .map(|profile| (profile.code, profile.score(&trigrams)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(code, _)| code)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct CompressRangesKwargs {
+    range_separator: String,
+    list_separator: String,
+}
+
+fn compress_range_values(values: &mut Vec<i64>, range_separator: &str, list_separator: &str) -> String {
+    values.sort_unstable();
+    values.dedup();
+    let mut parts: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < values.len() {
+        // Extend the run for as long as the values are consecutive
+        let start = values[i];
+        let mut end = start;
+        while i + 1 < values.len() && values[i + 1] == end + 1 {
+            end += 1;
+            i += 1;
+        }
+        if start == end {
+            parts.push(start.to_string());
+        } else {
+            parts.push(format!("{}{}{}", start, range_separator, end));
+        }
+        i += 1;
+    }
+    parts.join(list_separator)
+}
+
+#[polars_expr(output_type=String)]
+fn compress_ranges(inputs: &[Series], kwargs: CompressRangesKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::List(Box::new(DataType::Int64)))?;
+    let ca: &ListChunked = series.list()?;
+    let mut values: Vec<Option<String>> = Vec::with_capacity(ca.len());
+    for opt_list in ca.into_iter() {
+        match opt_list {
+            None => values.push(None),
+            Some(list) => {
+                let mut numbers: Vec<i64> = list.i64()?.into_iter().flatten().collect();
+                values.push(Some(compress_range_values(&mut numbers, &kwargs.range_separator, &kwargs.list_separator)));
+            },
+        }
+    }
+    Ok(StringChunked::from_iter(values).into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslitScheme {
+    // GOST 7.79-2000, system B
+    Gost,
+    // BGN/PCGN 1947
+    Bgn,
+}
+
+#[derive(Deserialize)]
+pub struct TransliterateCyrillicKwargs {
+    scheme: TranslitScheme,
+}
+
+fn is_cyrillic_vowel(c: char) -> bool {
+    matches!(c, 'а' | 'е' | 'ё' | 'и' | 'о' | 'у' | 'ы' | 'э' | 'ю' | 'я' | 'і' | 'ї' | 'є')
+}
+
+// Maps a lowercase Cyrillic letter given its lowercase neighbours
+fn romanize_cyrillic(c: char, previous: Option<char>, next: Option<char>, scheme: &TranslitScheme) -> Option<&'static str> {
+    // In BGN/PCGN, "е" and "ё" are iotated at the start of a word and after vowels or signs
+    let iotated = previous.is_none_or(|p| is_cyrillic_vowel(p) || matches!(p, 'й' | 'ъ' | 'ь') || !p.is_alphabetic());
+    let mapped = match (c, scheme) {
+        ('а', _) => "a",
+        ('б', _) => "b",
+        ('в', _) => "v",
+        ('г', _) => "g",
+        ('д', _) => "d",
+        ('е', TranslitScheme::Gost) => "e",
+        ('е', TranslitScheme::Bgn) => if iotated { "ye" } else { "e" },
+        ('ё', TranslitScheme::Gost) => "yo",
+        ('ё', TranslitScheme::Bgn) => if iotated { "yë" } else { "ë" },
+        ('ж', _) => "zh",
+        ('з', _) => "z",
+        ('и', _) => "i",
+        ('й', TranslitScheme::Gost) => "j",
+        ('й', TranslitScheme::Bgn) => "y",
+        ('к', _) => "k",
+        ('л', _) => "l",
+        ('м', _) => "m",
+        ('н', _) => "n",
+        ('о', _) => "o",
+        ('п', _) => "p",
+        ('р', _) => "r",
+        ('с', _) => "s",
+        ('т', _) => "t",
+        ('у', _) => "u",
+        ('ф', _) => "f",
+        ('х', TranslitScheme::Gost) => "x",
+        ('х', TranslitScheme::Bgn) => "kh",
+        // GOST writes "c" before the letters which would be read as soft anyway
+        ('ц', TranslitScheme::Gost) => if matches!(next, Some('е' | 'и' | 'ы' | 'й' | 'і')) { "c" } else { "cz" },
+        ('ц', TranslitScheme::Bgn) => "ts",
+        ('ч', _) => "ch",
+        ('ш', _) => "sh",
+        ('щ', TranslitScheme::Gost) => "shh",
+        ('щ', TranslitScheme::Bgn) => "shch",
+        ('ъ', TranslitScheme::Gost) => "``",
+        ('ъ', TranslitScheme::Bgn) => "\u{2ba}",
+        ('ы', TranslitScheme::Gost) => "y'",
+        ('ы', TranslitScheme::Bgn) => "y",
+        ('ь', TranslitScheme::Gost) => "`",
+        ('ь', TranslitScheme::Bgn) => "\u{2b9}",
+        ('э', TranslitScheme::Gost) => "e'",
+        ('э', TranslitScheme::Bgn) => "e",
+        ('ю', _) => "yu",
+        ('я', _) => "ya",
+        ('і', TranslitScheme::Gost) => "i'",
+        ('і', TranslitScheme::Bgn) => "i",
+        ('ї', _) => "yi",
+        ('є', _) => "ye",
+        ('ґ', TranslitScheme::Gost) => "g`",
+        ('ґ', TranslitScheme::Bgn) => "g",
+        ('ў', TranslitScheme::Gost) => "u`",
+        ('ў', TranslitScheme::Bgn) => "w",
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+// Romanizes letter by letter, given a mapping of a lowercase letter and its lowercase neighbours
+fn push_romanized<F>(value: &str, romanize: F, output: &mut String)
+where
+    F: Fn(char, Option<char>, Option<char>) -> Option<&'static str>,
+{
+    let chars: Vec<char> = value.chars().collect();
+    let lower = |i: usize| chars.get(i).and_then(|c| c.to_lowercase().next());
+    for (i, &c) in chars.iter().enumerate() {
+        let previous = i.checked_sub(1).and_then(lower);
+        let Some(mapped) = romanize(lower(i).unwrap(), previous, lower(i + 1)) else {
+            output.push(c);
+            continue;
+        };
+        if !c.is_uppercase() {
+            output.push_str(mapped);
+            continue;
+        }
+
+        // Keep fully uppercased words uppercased, otherwise capitalize only the first letter
+        let neighbour_is_upper = chars.get(i + 1).or(i.checked_sub(1).and_then(|j| chars.get(j)))
+            .is_some_and(|n| n.is_uppercase());
+        if neighbour_is_upper {
+            output.push_str(&mapped.to_uppercase());
+        } else {
+            let mut letters = mapped.chars();
+            if let Some(first) = letters.next() {
+                output.extend(first.to_uppercase());
+                output.extend(letters);
+            }
+        }
+    }
+}
+
+fn push_transliterated_cyrillic(value: &str, scheme: &TranslitScheme, output: &mut String) {
+    push_romanized(value, |c, previous, next| romanize_cyrillic(c, previous, next, scheme), output);
+}
+
+#[polars_expr(output_type=String)]
+fn transliterate_cyrillic(inputs: &[Series], kwargs: TransliterateCyrillicKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        push_transliterated_cyrillic(value, &kwargs.scheme, output);
+    });
+    Ok(out.into_series())
+}
+
+fn kwic_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("left".into(), DataType::String),
+            Field::new("keyword".into(), DataType::String),
+            Field::new("right".into(), DataType::String),
+        ]))),
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct KwicKwargs {
+    keyword: String,
+    window: usize,
+}
+
+#[polars_expr(output_type_func=kwic_output)]
+fn kwic(inputs: &[Series], kwargs: KwicKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let keyword = kwargs.keyword.to_lowercase();
+    let dtype = kwic_output(&[])?.dtype().inner_dtype().unwrap().clone();
+    let mut builder = get_list_builder(&dtype, ca.len(), ca.len(), "".into());
+    for opt_s in ca.into_iter() {
+        match opt_s {
+            None => builder.append_null(),
+            Some(s) => {
+                let words: Vec<&str> = s.split_whitespace().collect();
+                let (mut left, mut matched, mut right) = (Vec::new(), Vec::new(), Vec::new());
+                for (i, word) in words.iter().enumerate() {
+                    // Ignore surrounding punctuation when looking for the keyword
+                    let bare = word.trim_matches(|c: char| !c.is_alphanumeric());
+                    if bare.to_lowercase() != keyword {
+                        continue;
+                    }
+                    let start = i.saturating_sub(kwargs.window);
+                    let end = (i + 1 + kwargs.window).min(words.len());
+                    left.push(words[start..i].join(" "));
+                    matched.push(word.to_string());
+                    right.push(words[i + 1..end].join(" "));
+                }
+                let fields = [
+                    Series::new("left".into(), left),
+                    Series::new("keyword".into(), matched),
+                    Series::new("right".into(), right),
+                ];
+                let rows = StructChunked::from_series("".into(), fields[0].len(), fields.iter())?;
+                builder.append_series(&rows.into_series())?;
+            }
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+// Letters which do not decompose into an ASCII base letter plus combining marks
+fn ascii_fallback(c: char) -> Option<&'static str> {
+    let mapped = match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'ł' => "l",
+        'Ł' => "L",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "TH",
+        'ı' => "i",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '−' => "-",
+        '…' => "...",
+        '\u{a0}' | '\u{2007}' | '\u{202f}' => " ",
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+#[derive(Deserialize)]
+pub struct ToAsciiKwargs {
+    replacement: String,
+}
+
+fn push_ascii(value: &str, replacement: &str, output: &mut String) {
+    // GOST romanizes Cyrillic to pure ASCII, unlike BGN/PCGN
+    let mut romanized = String::with_capacity(value.len());
+    push_transliterated_cyrillic(value, &TranslitScheme::Gost, &mut romanized);
+    for c in romanized.chars() {
+        if c.is_ascii() {
+            output.push(c);
+            continue;
+        }
+        if let Some(mapped) = ascii_fallback(c) {
+            output.push_str(mapped);
+            continue;
+        }
+        if is_combining_mark(c) {
+            continue;
+        }
+
+        // Strip accents by dropping the combining marks left after decomposition,
+        // the compatibility forms also split ligatures like "ﬁ" into plain letters
+        let base: String = c.nfkd().filter(|d| !is_combining_mark(*d)).collect();
+        if !base.is_empty() && base.is_ascii() {
+            output.push_str(&base);
+        } else {
+            output.push_str(replacement);
+        }
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn to_ascii(inputs: &[Series], kwargs: ToAsciiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        push_ascii(value, &kwargs.replacement, output);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PadScope {
+    Row,
+    Column,
+}
+
+#[derive(Deserialize)]
+pub struct PadListKwargs {
+    scope: PadScope,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn pad_list_elements(inputs: &[Series], kwargs: PadListKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::List(Box::new(DataType::String)))?;
+    let ca: &ListChunked = series.list()?;
+    let width_of = |list: &Series| -> PolarsResult<usize> {
+        Ok(list.str()?.into_iter().flatten().map(|s| s.chars().count()).max().unwrap_or(0))
+    };
+    let column_width = match kwargs.scope {
+        PadScope::Row => 0,
+        PadScope::Column => ca.into_iter().flatten().map(|list| width_of(&list)).try_fold(0, |a, w| w.map(|w| a.max(w)))?,
+    };
+
+    let mut builder = ListStringChunkedBuilder::new("".into(), ca.len(), 0);
+    for opt_list in ca.into_iter() {
+        let Some(list) = opt_list else {
+            builder.append_null();
+            continue;
+        };
+        let width = match kwargs.scope {
+            PadScope::Row => width_of(&list)?,
+            PadScope::Column => column_width,
+        };
+        let padded: StringChunked = list.str()?.into_iter()
+            .map(|opt_s| opt_s.map(|s| format!("{s:<width$}")))
+            .collect();
+        builder.append_series(&padded.into_series())?;
+    }
+    Ok(builder.finish().into_series())
+}
+
+// Characters commonly mistaken for (or substituted in place of) Latin letters and ASCII punctuation
+fn confusable_char(c: char) -> Option<char> {
+    let mapped = match c {
+        // Cyrillic
+        'а' => 'a', 'е' => 'e', 'о' => 'o', 'р' => 'p', 'с' => 'c', 'у' => 'y', 'х' => 'x',
+        'і' => 'i', 'ј' => 'j', 'ѕ' => 's', 'ԁ' => 'd', 'һ' => 'h', 'ԛ' => 'q', 'ԝ' => 'w',
+        'А' => 'A', 'В' => 'B', 'Е' => 'E', 'К' => 'K', 'М' => 'M', 'Н' => 'H', 'О' => 'O',
+        'Р' => 'P', 'С' => 'C', 'Т' => 'T', 'Х' => 'X', 'У' => 'Y', 'І' => 'I', 'Ј' => 'J',
+        'Ѕ' => 'S', 'Ԛ' => 'Q', 'Ԝ' => 'W',
+        // Greek
+        'ο' => 'o', 'ν' => 'v', 'ι' => 'i', 'κ' => 'k',
+        'Α' => 'A', 'Β' => 'B', 'Ε' => 'E', 'Ζ' => 'Z', 'Η' => 'H', 'Ι' => 'I', 'Κ' => 'K',
+        'Μ' => 'M', 'Ν' => 'N', 'Ο' => 'O', 'Ρ' => 'P', 'Τ' => 'T', 'Υ' => 'Y', 'Χ' => 'X',
+        // Punctuation
+        '‘' | '’' | '‚' | '‛' | '′' | '＇' => '\'',
+        '“' | '”' | '„' | '‟' | '″' | '＂' => '"',
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => '-',
+        '⁄' | '∕' => '/',
+        'ǀ' | '∣' => '|',
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+// Zero-width spaces, (non-)joiners, word joiners and zero-width no-break spaces
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}')
+}
+
+#[derive(Deserialize)]
+pub struct ConfusablesKwargs {
+    fold_whitespace: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_confusables(inputs: &[Series], kwargs: ConfusablesKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for c in value.chars() {
+            if kwargs.fold_whitespace {
+                // Zero-width characters are dropped entirely
+                if is_zero_width(c) {
+                    continue;
+                }
+                if c.is_whitespace() && !matches!(c, ' ' | '\t' | '\n' | '\r') {
+                    output.push(' ');
+                    continue;
+                }
+            }
+            output.push(confusable_char(c).unwrap_or(c));
+        }
+    });
+    Ok(out.into_series())
+}
+
+// Finds the first word immediately repeated by the next word, punctuation between them breaks the pair
+fn find_repeated_word(value: &str, case_insensitive: bool) -> Option<&str> {
+    let mut previous: Option<&str> = None;
+    for token in value.split_whitespace() {
+        let word = token.trim_matches(|c: char| !c.is_alphanumeric());
+        if let Some(prev) = previous {
+            let leading = token.starts_with(|c: char| c.is_alphanumeric());
+            let same =
+                if case_insensitive {
+                    prev.to_lowercase() == word.to_lowercase()
+                } else {
+                    prev == word
+                };
+            if leading && !word.is_empty() && same {
+                return Some(prev);
+            }
+        }
+        // Trailing punctuation ends the run, e.g. "that. That" is not a repetition
+        previous = token.ends_with(|c: char| c.is_alphanumeric()).then_some(word);
+    }
+    None
+}
+
+#[derive(Deserialize)]
+pub struct RepeatedWordKwargs {
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn has_repeated_word(inputs: &[Series], kwargs: RepeatedWordKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|s| find_repeated_word(s, kwargs.case_insensitive).is_some())
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn first_repeated_word(inputs: &[Series], kwargs: RepeatedWordKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(|s| find_repeated_word(s, kwargs.case_insensitive))
+    });
+    Ok(out.into_series())
+}
+
+// Ambiguous contractions take their most common reading, e.g. "he's" is "he is" and "I'd" is "I would"
+const CONTRACTIONS: &[(&str, &str)] = &[
+    ("ain't", "is not"),
+    ("aren't", "are not"),
+    ("can't", "cannot"),
+    ("could've", "could have"),
+    ("couldn't", "could not"),
+    ("didn't", "did not"),
+    ("doesn't", "does not"),
+    ("don't", "do not"),
+    ("hadn't", "had not"),
+    ("hasn't", "has not"),
+    ("haven't", "have not"),
+    ("he'd", "he would"),
+    ("he'll", "he will"),
+    ("he's", "he is"),
+    ("how's", "how is"),
+    ("i'd", "I would"),
+    ("i'll", "I will"),
+    ("i'm", "I am"),
+    ("i've", "I have"),
+    ("isn't", "is not"),
+    ("it'd", "it would"),
+    ("it'll", "it will"),
+    ("it's", "it is"),
+    ("let's", "let us"),
+    ("ma'am", "madam"),
+    ("mightn't", "might not"),
+    ("might've", "might have"),
+    ("mustn't", "must not"),
+    ("must've", "must have"),
+    ("needn't", "need not"),
+    ("o'clock", "of the clock"),
+    ("shan't", "shall not"),
+    ("she'd", "she would"),
+    ("she'll", "she will"),
+    ("she's", "she is"),
+    ("should've", "should have"),
+    ("shouldn't", "should not"),
+    ("that'd", "that would"),
+    ("that's", "that is"),
+    ("there'd", "there would"),
+    ("there's", "there is"),
+    ("they'd", "they would"),
+    ("they'll", "they will"),
+    ("they're", "they are"),
+    ("they've", "they have"),
+    ("wasn't", "was not"),
+    ("we'd", "we would"),
+    ("we'll", "we will"),
+    ("we're", "we are"),
+    ("we've", "we have"),
+    ("weren't", "were not"),
+    ("what'll", "what will"),
+    ("what're", "what are"),
+    ("what's", "what is"),
+    ("what've", "what have"),
+    ("where's", "where is"),
+    ("who'd", "who would"),
+    ("who'll", "who will"),
+    ("who's", "who is"),
+    ("who've", "who have"),
+    ("why's", "why is"),
+    ("won't", "will not"),
+    ("would've", "would have"),
+    ("wouldn't", "would not"),
+    ("y'all", "you all"),
+    ("you'd", "you would"),
+    ("you'll", "you will"),
+    ("you're", "you are"),
+    ("you've", "you have"),
+];
+
+static CONTRACTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z]+(?:['’][A-Za-z]+)+").unwrap()
+});
+
+#[derive(Deserialize)]
+pub struct ExpandKwargs {
+    extra: Vec<(String, String)>,
+}
+
+#[polars_expr(output_type=String)]
+fn expand_contractions(inputs: &[Series], kwargs: ExpandKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    // User-provided expansions take precedence over the bundled ones
+    let mut mapping: HashMap<String, String> = CONTRACTIONS
+        .iter()
+        .map(|&(from, to)| (from.to_string(), to.to_string()))
+        .collect();
+    for (from, to) in kwargs.extra.iter() {
+        mapping.insert(from.replace('’', "'").to_lowercase(), to.clone());
+    }
+
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut last_end = 0;
+        for found in CONTRACTION_REGEX.find_iter(value) {
+            let token = found.as_str();
+            let Some(expansion) = mapping.get(&token.replace('’', "'").to_lowercase()) else {
+                continue;
+            };
+            output.push_str(&value[last_end..found.start()]);
+            last_end = found.end();
+
+            let is_all_caps = token.chars().filter(|c| c.is_alphabetic()).count() > 1
+                && token.chars().all(|c| !c.is_lowercase());
+            if is_all_caps {
+                output.push_str(&expansion.to_uppercase());
+            } else if token.starts_with(char::is_uppercase) {
+                let mut letters = expansion.chars();
+                if let Some(first) = letters.next() {
+                    output.extend(first.to_uppercase());
+                    output.extend(letters);
+                }
+            } else {
+                output.push_str(expansion);
+            }
+        }
+        output.push_str(&value[last_end..]);
+    });
+    Ok(out.into_series())
+}
+
+// Splits a bare or dotted acronym token like "U", "U." or "U.S." into its uppercase letter groups
+fn acronym_pieces(token: &str, max_token_length: usize) -> Option<Vec<&str>> {
+    let pieces: Vec<&str> = token.strip_suffix('.').unwrap_or(token).split('.').collect();
+    let is_acronym = pieces.iter().all(|piece| {
+        (1..=max_token_length).contains(&piece.chars().count()) && piece.chars().all(char::is_uppercase)
+    });
+    is_acronym.then_some(pieces)
+}
+
+#[derive(Deserialize)]
+pub struct AcronymSpacingKwargs {
+    max_token_length: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_acronym_spacing(inputs: &[Series], kwargs: AcronymSpacingKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let is_title_case = |token: &str| {
+        let mut chars = token.chars();
+        chars.next().is_some_and(char::is_uppercase) && chars.next().is_some_and(char::is_lowercase)
+    };
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut offset = 0;
+        let tokens: Vec<(usize, &str)> = value
+            .split_whitespace()
+            .map(|token| {
+                let start = offset + value[offset..].find(token).unwrap();
+                offset = start + token.len();
+                (start, token)
+            })
+            .collect();
+
+        let mut last_end = 0;
+        let mut i = 0;
+        while i < tokens.len() {
+            let Some(first_pieces) = acronym_pieces(tokens[i].1, kwargs.max_token_length) else {
+                i += 1;
+                continue;
+            };
+
+            // Only single letters are joined across spaces, longer pieces like "UK." or "IT" only
+            // lose the periods inside their own token
+            let mut j = i + 1;
+            if first_pieces.iter().all(|piece| piece.chars().count() == 1) {
+                while j < tokens.len() && acronym_pieces(tokens[j].1, 1).is_some() {
+                    j += 1;
+                }
+            }
+
+            // The pronoun "I" next to a letter is not part of it, e.g. "Grade A I guess"
+            let mut run = &tokens[i..j];
+            while run.first().is_some_and(|(_, token)| *token == "I") {
+                run = &run[1..];
+            }
+            while run.last().is_some_and(|(_, token)| *token == "I") {
+                run = &run[..run.len() - 1];
+            }
+            let pieces: Vec<&str> = run
+                .iter()
+                .flat_map(|(_, token)| acronym_pieces(token, kwargs.max_token_length).unwrap())
+                .collect();
+
+            // A lone single-letter token is a regular word, not an acronym, and letters before a
+            // name are initials, e.g. "J. K. Rowling"
+            let is_initials = tokens.get(j).is_some_and(|(_, token)| is_title_case(token));
+            if pieces.len() > 1 && !is_initials {
+                let (run_start, _) = run[0];
+                let (last_start, last_token) = run[run.len() - 1];
+                output.push_str(&value[last_end..run_start]);
+                pieces.iter().for_each(|piece| output.push_str(piece));
+                last_end = last_start + last_token.len();
+            }
+            i = j;
+        }
+        output.push_str(&value[last_end..]);
+    });
+    Ok(out.into_series())
+}
+
+// Splits on Unicode word boundaries (UAX #29), optionally keeping punctuation as separate tokens
+fn tokenize_text(value: &str, lowercase: bool, keep_punctuation: bool, min_length: usize) -> Vec<String> {
+    value
+        .split_word_bounds()
+        .filter(|token| !token.chars().all(char::is_whitespace))
+        .filter(|token| keep_punctuation || token.chars().any(char::is_alphanumeric))
+        .filter(|token| token.chars().count() >= min_length)
+        .map(|token| if lowercase { token.to_lowercase() } else { token.to_string() })
+        .collect()
+}
+
+#[derive(Deserialize)]
+pub struct TokenizeKwargs {
+    lowercase: bool,
+    keep_punctuation: bool,
+    min_length: usize,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn tokenize_words(inputs: &[Series], kwargs: TokenizeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        tokenize_text(s, kwargs.lowercase, kwargs.keep_punctuation, kwargs.min_length)
+    })
+}
+
+// Latin digits along with the separators of decimals, times and dates written in between
+static DIGIT_RUN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[0-9]+(?:[.,:/\-][0-9]+)*").unwrap()
+});
+
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08ff}'     // Hebrew, Arabic, Syriac, Thaana, NKo, etc.
+        | '\u{fb1d}'..='\u{fdff}'   // Hebrew and Arabic presentation forms A
+        | '\u{fe70}'..='\u{feff}')  // Arabic presentation forms B
+}
+
+#[polars_expr(output_type=String)]
+fn fix_rtl_number_order(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        if !value.chars().any(is_rtl_char) {
+            output.push_str(value);
+            return;
+        }
+        let mut last_end = 0;
+        for found in DIGIT_RUN_REGEX.find_iter(value) {
+            output.push_str(&value[last_end..found.start()]);
+            last_end = found.end();
+            // Leave runs which are already isolated untouched
+            if value[..found.start()].ends_with('\u{2066}') && value[found.end()..].starts_with('\u{2069}') {
+                output.push_str(found.as_str());
+                continue;
+            }
+            output.push('\u{2066}');
+            output.push_str(found.as_str());
+            output.push('\u{2069}');
+        }
+        output.push_str(&value[last_end..]);
+    });
+    Ok(out.into_series())
+}
+
+// Based on the English stopword list of NLTK
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "i", "me", "my", "myself", "we", "our", "ours", "ourselves", "you", "you're", "you've",
+    "you'll", "you'd", "your", "yours", "yourself", "yourselves", "he", "him", "his", "himself",
+    "she", "she's", "her", "hers", "herself", "it", "it's", "its", "itself", "they", "them",
+    "their", "theirs", "themselves", "what", "which", "who", "whom", "this", "that", "that'll",
+    "these", "those", "am", "is", "are", "was", "were", "be", "been", "being", "have", "has", "had",
+    "having", "do", "does", "did", "doing", "a", "an", "the", "and", "but", "if", "or", "because",
+    "as", "until", "while", "of", "at", "by", "for", "with", "about", "against", "between", "into",
+    "through", "during", "before", "after", "above", "below", "to", "from", "up", "down", "in",
+    "out", "on", "off", "over", "under", "again", "further", "then", "once", "here", "there",
+    "when", "where", "why", "how", "all", "any", "both", "each", "few", "more", "most", "other",
+    "some", "such", "no", "nor", "not", "only", "own", "same", "so", "than", "too", "very", "s",
+    "t", "can", "will", "just", "don", "don't", "should", "should've", "now", "d", "ll", "m", "o",
+    "re", "ve", "y", "ain", "aren", "aren't", "couldn", "couldn't", "didn", "didn't", "doesn",
+    "doesn't", "hadn", "hadn't", "hasn", "hasn't", "haven", "haven't", "isn", "isn't", "ma",
+    "mightn", "mightn't", "mustn", "mustn't", "needn", "needn't", "shan", "shan't", "shouldn",
+    "shouldn't", "wasn", "wasn't", "weren", "weren't", "won", "won't", "wouldn", "wouldn't",
+];
+
+fn stopwords_output(_: &[Field], kwargs: StopwordsKwargs) -> PolarsResult<Field> {
+    let dtype =
+        if kwargs.output_list {
+            DataType::List(Box::new(DataType::String))
+        } else {
+            DataType::String
+        };
+    Ok(Field::new("".into(), dtype))
+}
+
+#[derive(Deserialize)]
+pub struct StopwordsKwargs {
+    language: String,
+    custom: Vec<String>,
+    output_list: bool,
+}
+
+#[polars_expr(output_type_func_with_kwargs=stopwords_output)]
+fn remove_stopwords(inputs: &[Series], kwargs: StopwordsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let builtin = match kwargs.language.as_str() {
+        "en" => ENGLISH_STOPWORDS,
+        unknown => polars_bail!(InvalidOperation: "unsupported language {:?}", unknown),
+    };
+    let stopwords: HashSet<String> = builtin
+        .iter()
+        .map(|word| word.to_string())
+        .chain(kwargs.custom.iter().map(|word| word.replace('’', "'").to_lowercase()))
+        .collect();
+    let content_words = |value: &str| -> Vec<String> {
+        tokenize_text(value, false, false, 0)
+            .into_iter()
+            .filter(|token| !stopwords.contains(&token.replace('’', "'").to_lowercase()))
+            .collect()
+    };
+
+    if kwargs.output_list {
+        return build_list_string_series(ca, content_words);
+    }
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.push_str(&content_words(value).join(" "));
+    });
+    Ok(out.into_series())
+}
+
+// The first reading of each substitution is preferred when no dictionary settles the ambiguity
+fn unleet_char(c: char) -> Option<&'static [char]> {
+    let readings: &[char] = match c {
+        '4' | '@' => &['a'],
+        '8' => &['b'],
+        '3' => &['e'],
+        '6' | '9' => &['g'],
+        '1' => &['i', 'l'],
+        '|' => &['l', 'i'],
+        '0' => &['o'],
+        '5' | '$' => &['s'],
+        '7' | '+' => &['t'],
+        '2' => &['z'],
+        _ => return None,
+    };
+    Some(readings)
+}
+
+// Bounds the number of spellings tried against the dictionary for a single word
+const MAX_SPELLING_CANDIDATES: usize = 1024;
+
+// Picks the first spelling of the word found in the dictionary, reading each character in every way
+// given, or falls back to the first reading of every character
+fn dictionary_reading<F>(word: &str, dictionary: &HashSet<String>, readings_of: F) -> String
+where
+    F: Fn(char) -> Option<&'static [char]>,
+{
+    let mut candidates = vec![String::new()];
+    for c in word.chars() {
+        let readings = match readings_of(c) {
+            Some(readings) if !dictionary.is_empty() && candidates.len() * readings.len() <= MAX_SPELLING_CANDIDATES => readings,
+            Some(readings) => &readings[..1],
+            None => &[c][..],
+        };
+        candidates = candidates
+            .iter()
+            .flat_map(|prefix| readings.iter().map(move |r| format!("{prefix}{r}")))
+            .collect();
+    }
+    let fallback = candidates[0].clone();
+    candidates
+        .into_iter()
+        .find(|candidate| dictionary.contains(&candidate.to_lowercase()))
+        .unwrap_or(fallback)
+}
+
+#[derive(Deserialize)]
+pub struct UnleetKwargs {
+    dictionary: Vec<String>,
+}
+
+#[polars_expr(output_type=String)]
+fn unleet(inputs: &[Series], kwargs: UnleetKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let dictionary: HashSet<String> = kwargs.dictionary.iter().map(|word| word.to_lowercase()).collect();
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for_each_token(value, output, |token, output| {
+            let is_core = |c: char| c.is_alphanumeric() || unleet_char(c).is_some();
+            let (Some(start), Some(end)) = (token.find(is_core), token.rfind(is_core)) else {
+                output.push_str(token);
+                return;
+            };
+            let end = end + token[end..].chars().next().unwrap().len_utf8();
+            let word = &token[start..end];
+
+            // Plain numbers are left alone, a word needs at least one actual letter
+            if !word.chars().any(char::is_alphabetic) || !word.chars().any(|c| unleet_char(c).is_some()) {
+                output.push_str(token);
+                return;
+            }
+            output.push_str(&token[..start]);
+            output.push_str(&dictionary_reading(word, &dictionary, unleet_char));
+            output.push_str(&token[end..]);
+        });
+    });
+    Ok(out.into_series())
+}
+
+// Porter (1980) stemmer, following the reference implementation by Martin Porter
+struct PorterStemmer {
+    b: Vec<u8>,
+    // Index of the last letter of the word being stemmed
+    k: isize,
+    // Index of the last letter of the stem once a suffix has been matched, -1 if the stem is empty
+    j: isize,
+}
+
+impl PorterStemmer {
+    fn at(&self, i: isize) -> u8 {
+        self.b[i as usize]
+    }
+
+    fn is_consonant(&self, i: isize) -> bool {
+        match self.at(i) {
+            b'a' | b'e' | b'i' | b'o' | b'u' => false,
+            b'y' => i == 0 || !self.is_consonant(i - 1),
+            _ => true,
+        }
+    }
+
+    // Number of vowel-consonant sequences in the stem, the "m" in [C](VC){m}[V]
+    fn measure(&self) -> usize {
+        let mut n = 0;
+        let mut i = 0;
+        while i <= self.j && self.is_consonant(i) {
+            i += 1;
+        }
+        loop {
+            while i <= self.j && !self.is_consonant(i) {
+                i += 1;
+            }
+            if i > self.j {
+                return n;
+            }
+            n += 1;
+            while i <= self.j && self.is_consonant(i) {
+                i += 1;
+            }
+            if i > self.j {
+                return n;
+            }
+        }
+    }
+
+    fn vowel_in_stem(&self) -> bool {
+        (0..=self.j).any(|i| !self.is_consonant(i))
+    }
+
+    fn double_consonant(&self, j: isize) -> bool {
+        j >= 1 && self.at(j) == self.at(j - 1) && self.is_consonant(j)
+    }
+
+    // Consonant-vowel-consonant ending at i, where the last consonant is not w, x or y
+    fn cvc(&self, i: isize) -> bool {
+        if i < 2 || !self.is_consonant(i) || self.is_consonant(i - 1) || !self.is_consonant(i - 2) {
+            return false;
+        }
+        !matches!(self.at(i), b'w' | b'x' | b'y')
+    }
+
+    fn ends(&mut self, suffix: &str) -> bool {
+        let length = suffix.len() as isize;
+        if length > self.k + 1 || &self.b[(self.k + 1 - length) as usize..=self.k as usize] != suffix.as_bytes() {
+            return false;
+        }
+        self.j = self.k - length;
+        true
+    }
+
+    fn set_to(&mut self, replacement: &str) {
+        self.b.truncate((self.j + 1) as usize);
+        self.b.extend_from_slice(replacement.as_bytes());
+        self.k = self.b.len() as isize - 1;
+    }
+
+    // Only the end marker moves, the buffer is cut once stemming is done since later steps may still
+    // read the letters past it
+    fn truncate_to(&mut self, k: isize) {
+        self.k = k;
+    }
+
+    fn step1ab(&mut self) {
+        if self.at(self.k) == b's' {
+            if self.ends("sses") {
+                self.truncate_to(self.k - 2);
+            } else if self.ends("ies") {
+                self.set_to("i");
+            } else if self.at(self.k - 1) != b's' {
+                self.truncate_to(self.k - 1);
+            }
+        }
+        if self.ends("eed") {
+            if self.measure() > 0 {
+                self.truncate_to(self.k - 1);
+            }
+        } else if (self.ends("ed") || self.ends("ing")) && self.vowel_in_stem() {
+            self.truncate_to(self.j);
+            if self.ends("at") {
+                self.set_to("ate");
+            } else if self.ends("bl") {
+                self.set_to("ble");
+            } else if self.ends("iz") {
+                self.set_to("ize");
+            } else if self.double_consonant(self.k) {
+                if !matches!(self.at(self.k), b'l' | b's' | b'z') {
+                    self.truncate_to(self.k - 1);
+                }
+            } else if self.measure() == 1 && self.cvc(self.k) {
+                self.j = self.k;
+                self.set_to("e");
+            }
+        }
+    }
+
+    fn step1c(&mut self) {
+        if self.ends("y") && self.vowel_in_stem() {
+            self.b[self.k as usize] = b'i';
+        }
+    }
+
+    fn replace_first(&mut self, rules: &[(&str, &str)]) {
+        // Only the first matching suffix is considered, even if its stem is too short
+        if let Some(&(_, replacement)) = rules.iter().find(|(suffix, _)| self.ends(suffix)) {
+            if self.measure() > 0 {
+                self.set_to(replacement);
+            }
+        }
+    }
+
+    fn step2(&mut self) {
+        let rules: &[(&str, &str)] = match self.at(self.k - 1) {
+            b'a' => &[("ational", "ate"), ("tional", "tion")],
+            b'c' => &[("enci", "ence"), ("anci", "ance")],
+            b'e' => &[("izer", "ize")],
+            b'l' => &[("bli", "ble"), ("alli", "al"), ("entli", "ent"), ("eli", "e"), ("ousli", "ous")],
+            b'o' => &[("ization", "ize"), ("ation", "ate"), ("ator", "ate")],
+            b's' => &[("alism", "al"), ("iveness", "ive"), ("fulness", "ful"), ("ousness", "ous")],
+            b't' => &[("aliti", "al"), ("iviti", "ive"), ("biliti", "ble")],
+            b'g' => &[("logi", "log")],
+            _ => return,
+        };
+        self.replace_first(rules);
+    }
+
+    fn step3(&mut self) {
+        let rules: &[(&str, &str)] = match self.at(self.k) {
+            b'e' => &[("icate", "ic"), ("ative", ""), ("alize", "al")],
+            b'i' => &[("iciti", "ic")],
+            b'l' => &[("ical", "ic"), ("ful", "")],
+            b's' => &[("ness", "")],
+            _ => return,
+        };
+        self.replace_first(rules);
+    }
+
+    fn step4(&mut self) {
+        let suffixes: &[&str] = match self.at(self.k - 1) {
+            b'a' => &["al"],
+            b'c' => &["ance", "ence"],
+            b'e' => &["er"],
+            b'i' => &["ic"],
+            b'l' => &["able", "ible"],
+            b'n' => &["ant", "ement", "ment", "ent"],
+            b'o' => &["ion", "ou"],
+            b's' => &["ism"],
+            b't' => &["ate", "iti"],
+            b'u' => &["ous"],
+            b'v' => &["ive"],
+            b'z' => &["ize"],
+            _ => return,
+        };
+        let Some(&suffix) = suffixes.iter().find(|suffix| self.ends(suffix)) else {
+            return;
+        };
+        // "-ion" is only removed after an "s" or a "t"
+        if suffix == "ion" && !(self.j >= 0 && matches!(self.at(self.j), b's' | b't')) {
+            return;
+        }
+        if self.measure() > 1 {
+            self.truncate_to(self.j);
+        }
+    }
+
+    fn step5(&mut self) {
+        self.j = self.k;
+        if self.at(self.k) == b'e' {
+            let m = self.measure();
+            if m > 1 || m == 1 && !self.cvc(self.k - 1) {
+                self.truncate_to(self.k - 1);
+            }
+        }
+        if self.at(self.k) == b'l' && self.double_consonant(self.k) && self.measure() > 1 {
+            self.truncate_to(self.k - 1);
+        }
+    }
+
+    fn stem(word: &str) -> String {
+        if word.len() <= 2 {
+            return word.to_string();
+        }
+        let mut stemmer = PorterStemmer { b: word.as_bytes().to_vec(), k: word.len() as isize - 1, j: 0 };
+        stemmer.step1ab();
+        if stemmer.k > 0 {
+            stemmer.step1c();
+            stemmer.step2();
+            stemmer.step3();
+            stemmer.step4();
+            stemmer.step5();
+        }
+        stemmer.b.truncate((stemmer.k + 1) as usize);
+        String::from_utf8(stemmer.b).unwrap()
+    }
+}
+
+// Only plain English words are stemmed, surrounding punctuation is kept and anything else is left as is
+fn stem_token(token: &str) -> String {
+    let word = token.trim_matches(|c: char| c.is_ascii_punctuation());
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return token.to_string();
+    }
+    let start = token.find(word).unwrap();
+    let stem = PorterStemmer::stem(&word.to_ascii_lowercase());
+    format!("{}{}{}", &token[..start], stem, &token[start + word.len()..])
+}
+
+#[derive(Deserialize)]
+pub struct StemKwargs {
+    output_list: bool,
+}
+
+fn stem_porter_output(_: &[Field], kwargs: StemKwargs) -> PolarsResult<Field> {
+    let dtype =
+        if kwargs.output_list {
+            DataType::List(Box::new(DataType::String))
+        } else {
+            DataType::String
+        };
+    Ok(Field::new("".into(), dtype))
+}
+
+#[polars_expr(output_type_func_with_kwargs=stem_porter_output)]
+fn stem_porter(inputs: &[Series], kwargs: StemKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    if kwargs.output_list {
+        return build_list_string_series(ca, |s| s.split_whitespace().map(stem_token).collect());
+    }
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for_each_token(value, output, |token, output| output.push_str(&stem_token(token)));
+    });
+    Ok(out.into_series())
+}
+
+// Abbreviations whose trailing period does not end a sentence
+const SENTENCE_ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "vs", "etc", "e.g", "i.e", "inc", "ltd",
+    "co", "corp", "no", "fig", "approx", "dept", "est", "jan", "feb", "mar", "apr", "jun", "jul",
+    "aug", "sep", "sept", "oct", "nov", "dec", "a.m", "p.m", "u.s",
+];
+
+fn is_sentence_closer(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | ']' | '}' | '”' | '’' | '»')
+}
+
+// Returns the char offsets of each sentence, as [start, end) spans without surrounding whitespace.
+// The extra abbreviations are expected in lowercase and without the trailing period.
+fn sentence_spans(chars: &[char], extra_abbreviations: &[String]) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if start.is_none() && !c.is_whitespace() {
+            start = Some(i);
+        }
+        if !matches!(c, '.' | '!' | '?' | '…') {
+            i += 1;
+            continue;
+        }
+
+        // Consume runs like "?!" or "..." along with closing quotes and brackets
+        let mut end = i + 1;
+        while end < chars.len() && matches!(chars[end], '.' | '!' | '?' | '…') {
+            end += 1;
+        }
+        while end < chars.len() && is_sentence_closer(chars[end]) {
+            end += 1;
+        }
+        // Decimals like "3.14" and domains like "example.com" are not followed by a space
+        if end < chars.len() && !chars[end].is_whitespace() {
+            i = end;
+            continue;
+        }
+        // A trailing off which the sentence carries on from, as in "wait... then"
+        let is_ellipsis = chars[i..end].iter().all(|&c| c == '.' || c == '…') && (c == '…' || end - i >= 3);
+        if is_ellipsis && chars[end..].iter().find(|c| !c.is_whitespace()).is_some_and(|c| c.is_lowercase()) {
+            i = end;
+            continue;
+        }
+        if c == '.' && end == i + 1 {
+            let word_start = chars[..i].iter().rposition(|c| !(c.is_alphabetic() || *c == '.')).map_or(0, |p| p + 1);
+            let word: String = chars[word_start..i].iter().collect::<String>().to_lowercase();
+            let is_initial = i - word_start == 1 && chars[word_start].is_uppercase();
+            let is_abbreviation = SENTENCE_ABBREVIATIONS.contains(&word.as_str()) || extra_abbreviations.contains(&word);
+            if is_initial || is_abbreviation {
+                i = end;
+                continue;
+            }
+        }
+        if let Some(s) = start.take() {
+            spans.push((s, end));
+        }
+        i = end;
+    }
+    if let Some(s) = start {
+        let end = chars.iter().rposition(|c| !c.is_whitespace()).unwrap() + 1;
+        spans.push((s, end));
+    }
+    spans
+}
+
+fn sentences_with_offsets_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("text".into(), DataType::String),
+            Field::new("start".into(), DataType::UInt32),
+            Field::new("end".into(), DataType::UInt32),
+        ]))),
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct SentenceSplitKwargs {
+    abbreviations: Vec<String>,
+}
+
+// Abbreviations are accepted as written, e.g. "Fig." or "fig"
+fn sentence_abbreviations(abbreviations: &[String]) -> Vec<String> {
+    abbreviations
+        .iter()
+        .map(|abbreviation| abbreviation.trim().trim_end_matches('.').to_lowercase())
+        .collect()
+}
+
+#[polars_expr(output_type_func=sentences_with_offsets_output)]
+fn sentences_with_offsets(inputs: &[Series], kwargs: SentenceSplitKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let abbreviations = sentence_abbreviations(&kwargs.abbreviations);
+    let dtype = sentences_with_offsets_output(&[])?.dtype().inner_dtype().unwrap().clone();
+    let mut builder = get_list_builder(&dtype, ca.len(), ca.len(), "".into());
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        let chars: Vec<char> = s.chars().collect();
+        let spans = sentence_spans(&chars, &abbreviations);
+        let texts: Vec<String> = spans.iter().map(|&(start, end)| chars[start..end].iter().collect()).collect();
+        let fields = [
+            Series::new("text".into(), texts),
+            Series::new("start".into(), spans.iter().map(|&(start, _)| start as u32).collect::<Vec<u32>>()),
+            Series::new("end".into(), spans.iter().map(|&(_, end)| end as u32).collect::<Vec<u32>>()),
+        ];
+        let rows = StructChunked::from_series("".into(), spans.len(), fields.iter())?;
+        builder.append_series(&rows.into_series())?;
+    }
+    Ok(builder.finish().into_series())
+}
+
+struct PhoneRegion {
+    region: &'static str,
+    calling_code: &'static str,
+    // Prefix dialled before national numbers within the region, dropped in the international form
+    trunk_prefix: Option<&'static str>,
+    // Whether national numbers are always written with the trunk prefix
+    trunk_required: bool,
+    // Digits of the national significant number
+    lengths: std::ops::RangeInclusive<usize>,
+}
+
+const PHONE_REGIONS: &[PhoneRegion] = &[
+    PhoneRegion { region: "US", calling_code: "1", trunk_prefix: Some("1"), trunk_required: false, lengths: 10..=10 },
+    PhoneRegion { region: "CA", calling_code: "1", trunk_prefix: Some("1"), trunk_required: false, lengths: 10..=10 },
+    PhoneRegion { region: "GB", calling_code: "44", trunk_prefix: Some("0"), trunk_required: true, lengths: 9..=10 },
+    PhoneRegion { region: "DE", calling_code: "49", trunk_prefix: Some("0"), trunk_required: true, lengths: 6..=13 },
+    PhoneRegion { region: "FR", calling_code: "33", trunk_prefix: Some("0"), trunk_required: true, lengths: 9..=9 },
+    PhoneRegion { region: "NL", calling_code: "31", trunk_prefix: Some("0"), trunk_required: true, lengths: 9..=9 },
+    PhoneRegion { region: "IT", calling_code: "39", trunk_prefix: None, trunk_required: false, lengths: 6..=11 },
+    PhoneRegion { region: "ES", calling_code: "34", trunk_prefix: None, trunk_required: false, lengths: 9..=9 },
+    PhoneRegion { region: "AU", calling_code: "61", trunk_prefix: Some("0"), trunk_required: true, lengths: 9..=9 },
+    PhoneRegion { region: "IN", calling_code: "91", trunk_prefix: Some("0"), trunk_required: false, lengths: 10..=10 },
+    PhoneRegion { region: "JP", calling_code: "81", trunk_prefix: Some("0"), trunk_required: true, lengths: 9..=10 },
+    PhoneRegion { region: "BR", calling_code: "55", trunk_prefix: Some("0"), trunk_required: false, lengths: 10..=11 },
+    PhoneRegion { region: "ID", calling_code: "62", trunk_prefix: Some("0"), trunk_required: true, lengths: 8..=12 },
+    PhoneRegion { region: "SG", calling_code: "65", trunk_prefix: None, trunk_required: false, lengths: 8..=8 },
+];
+
+// Checks the national significant number against the region, dropping the trunk prefix when it is present
+fn national_number<'a>(digits: &'a str, region: &PhoneRegion, international: bool) -> Option<&'a str> {
+    let stripped = region.trunk_prefix
+        .and_then(|prefix| digits.strip_prefix(prefix))
+        .filter(|stripped| region.lengths.contains(&stripped.len()));
+    let national = match stripped {
+        Some(stripped) => stripped,
+        None if region.trunk_required && !international => return None,
+        None => digits,
+    };
+    if !region.lengths.contains(&national.len()) {
+        return None;
+    }
+    // North American area codes never start with 0 or 1
+    if region.calling_code == "1" && national.starts_with(['0', '1']) {
+        return None;
+    }
+    Some(national)
+}
+
+fn normalize_phone_number(value: &str, region: &PhoneRegion) -> Option<String> {
+    let value = value.trim();
+    let is_separator = |c: char| c.is_whitespace() || matches!(c, '-' | '.' | '(' | ')' | '/');
+    if !value.chars().all(|c| c.is_ascii_digit() || is_separator(c) || c == '+') || value.rfind('+').is_some_and(|i| i > 0) {
+        return None;
+    }
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+
+    // Numbers written with an international dialling prefix already carry their country code
+    let international =
+        if value.starts_with('+') {
+            Some(digits.as_str())
+        } else if region.calling_code == "1" {
+            digits.strip_prefix("011")
+        } else {
+            digits.strip_prefix("00")
+        };
+    let Some(international) = international else {
+        let national = national_number(&digits, region, false)?;
+        return Some(format!("+{}{}", region.calling_code, national));
+    };
+
+    let known = PHONE_REGIONS.iter().find(|r| international.starts_with(r.calling_code));
+    match known {
+        Some(known) => {
+            // Some write the trunk prefix in parentheses after the country code, e.g. "+44 (0)20"
+            let national = national_number(&international[known.calling_code.len()..], known, true)?;
+            Some(format!("+{}{}", known.calling_code, national))
+        },
+        None if (8..=15).contains(&international.len()) && !international.starts_with('0') => Some(format!("+{international}")),
+        None => None,
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PhoneKwargs {
+    default_region: String,
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_phone(inputs: &[Series], kwargs: PhoneKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let Some(region) = PHONE_REGIONS.iter().find(|r| r.region.eq_ignore_ascii_case(&kwargs.default_region)) else {
+        polars_bail!(InvalidOperation: "unsupported region {:?}", kwargs.default_region);
+    };
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(|s| normalize_phone_number(s, region))
+    });
+    Ok(out.into_series())
+}
+
+fn escape_markdown(value: &str, special: &[char], output: &mut String) {
+    for c in value.chars() {
+        if c == '\\' || special.contains(&c) {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn make_markdown_link(inputs: &[Series]) -> PolarsResult<Series> {
+    let texts: &StringChunked = inputs[0].str()?;
+    let urls: &StringChunked = inputs[1].str()?;
+    let out: StringChunked = broadcast_binary_elementwise(texts, urls, |t: Option<&str>, u: Option<&str>| {
+        let (text, url) = (t?, u?.trim());
+        let mut link = String::with_capacity(text.len() + url.len() + 4);
+        link.push('[');
+        escape_markdown(text, &['[', ']'], &mut link);
+        link.push_str("](");
+        // Destinations with spaces are only allowed between angle brackets
+        if url.contains(char::is_whitespace) {
+            link.push('<');
+            escape_markdown(url, &['<', '>'], &mut link);
+            link.push('>');
+        } else {
+            escape_markdown(url, &['(', ')'], &mut link);
+        }
+        link.push(')');
+        Some(link)
+    });
+    Ok(out.into_series())
+}
+
+fn unescape_markdown(chars: &[char]) -> String {
+    let mut output = String::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '\\' && chars.get(i + 1).is_some_and(|n| n.is_ascii_punctuation()) {
+            i += 1;
+        }
+        output.push(chars[i]);
+        i += 1;
+    }
+    output
+}
+
+// Reads up to the unescaped closing character, returning the unescaped content and the index after it
+fn read_markdown_until(chars: &[char], mut i: usize, close: char, nestable: Option<char>) -> Option<(String, usize)> {
+    let mut content = String::new();
+    let mut depth = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && chars.get(i + 1).is_some_and(|n| n.is_ascii_punctuation()) {
+            content.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+        if Some(c) == nestable {
+            depth += 1;
+        } else if c == close && depth == 0 {
+            return Some((content, i + 1));
+        } else if c == close {
+            depth -= 1;
+        }
+        content.push(c);
+        i += 1;
+    }
+    None
+}
+
+// Parses an inline link starting at the opening bracket, returning its text, destination, and end index
+fn parse_markdown_link_at(chars: &[char], i: usize) -> Option<(String, String, usize)> {
+    let (text, i) = read_markdown_until(chars, i + 1, ']', Some('['))?;
+    if chars.get(i) != Some(&'(') {
+        return None;
+    }
+    let mut i = i + 1;
+    while chars.get(i).is_some_and(|c| c.is_whitespace()) {
+        i += 1;
+    }
+    let (url, mut i) =
+        if chars.get(i) == Some(&'<') {
+            read_markdown_until(chars, i + 1, '>', None)?
+        } else {
+            let start = i;
+            let mut depth = 0;
+            while i < chars.len() && !chars[i].is_whitespace() && !(chars[i] == ')' && depth == 0) {
+                match chars[i] {
+                    '\\' => i += 1,
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {},
+                }
+                i += 1;
+            }
+            i = i.min(chars.len());
+            (unescape_markdown(&chars[start..i]), i)
+        };
+    // Skip an optional title, e.g. [text](url "title")
+    while i < chars.len() && chars[i] != ')' {
+        i += 1;
+    }
+    (i < chars.len()).then_some((text, url, i + 1))
+}
+
+fn markdown_links_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("text".into(), DataType::String),
+            Field::new("url".into(), DataType::String),
+        ]))),
+    ))
+}
+
+#[polars_expr(output_type_func=markdown_links_output)]
+fn parse_markdown_link(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let dtype = markdown_links_output(&[])?.dtype().inner_dtype().unwrap().clone();
+    let mut builder = get_list_builder(&dtype, ca.len(), ca.len(), "".into());
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        let chars: Vec<char> = s.chars().collect();
+        let (mut texts, mut urls) = (Vec::new(), Vec::new());
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i += 2,
+                // Images share the syntax of links but are not links
+                '[' if i == 0 || chars[i - 1] != '!' => match parse_markdown_link_at(&chars, i) {
+                    Some((text, url, end)) => {
+                        texts.push(text);
+                        urls.push(url);
+                        i = end;
+                    },
+                    None => i += 1,
+                },
+                _ => i += 1,
+            }
+        }
+        let fields = [
+            Series::new("text".into(), texts),
+            Series::new("url".into(), urls),
+        ];
+        let rows = StructChunked::from_series("".into(), fields[0].len(), fields.iter())?;
+        builder.append_series(&rows.into_series())?;
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ParseNumberKwargs {
+    thousands: char,
+    decimal: char,
+    scale_suffixes: bool,
+}
+
+fn parse_first_number(value: &str, kwargs: &ParseNumberKwargs) -> Option<f64> {
+    let chars: Vec<char> = value.chars().collect();
+    let is_digit_at = |i: usize| chars.get(i).is_some_and(char::is_ascii_digit);
+    let start = (0..chars.len()).find(|&i| is_digit_at(i) || chars[i] == kwargs.decimal && is_digit_at(i + 1))?;
+
+    let mut number = String::new();
+    if start > 0 && chars[start - 1] == '-' {
+        number.push('-');
+    }
+    let mut i = start;
+    let mut seen_decimal = false;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            number.push(c);
+        } else if c == kwargs.decimal && !seen_decimal && is_digit_at(i + 1) {
+            number.push('.');
+            seen_decimal = true;
+        } else if c != kwargs.thousands || seen_decimal || !is_digit_at(i + 1) {
+            break;
+        }
+        i += 1;
+    }
+    let mut parsed: f64 = number.parse().ok()?;
+
+    // A suffix only counts when it is not the start of a word like "km" or "million"
+    if kwargs.scale_suffixes && !chars.get(i + 1).is_some_and(|c| c.is_alphabetic()) {
+        match chars.get(i).map(|c| c.to_ascii_lowercase()) {
+            Some('k') => parsed *= 1e3,
+            Some('m') => parsed *= 1e6,
+            Some('b') => parsed *= 1e9,
+            _ => {},
+        }
+    }
+    Some(parsed)
+}
+
+#[polars_expr(output_type=Float64)]
+fn parse_number(inputs: &[Series], kwargs: ParseNumberKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    polars_ensure!(kwargs.thousands != kwargs.decimal, InvalidOperation: "thousands and decimal separators must differ");
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(|s| parse_first_number(s, &kwargs))
+    });
+    Ok(out.into_series())
+}
+
+fn is_emoji_component(c: char) -> bool {
+    matches!(c,
+        '\u{1f000}'..='\u{1faff}'     // Pictographs, emoticons, skin tone modifiers, etc.
+        | '\u{2600}'..='\u{27bf}'     // Miscellaneous symbols and dingbats
+        | '\u{2b00}'..='\u{2bff}'     // Miscellaneous symbols and arrows
+        | '\u{fe0f}'                  // Emoji presentation selector
+        | '\u{e0020}'..='\u{e007f}')  // Tags
+}
+
+#[derive(Deserialize)]
+pub struct StripZeroWidthKwargs {
+    keep_emoji_joiners: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_zero_width(inputs: &[Series], kwargs: StripZeroWidthKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut previous: Option<char> = None;
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            // Joiners between two emoji build up a single glyph, e.g. a family or a profession
+            let is_emoji_joiner = c == '\u{200d}'
+                && previous.is_some_and(is_emoji_component)
+                && chars.peek().is_some_and(|&n| is_emoji_component(n));
+            if !is_zero_width(c) || kwargs.keep_emoji_joiners && is_emoji_joiner {
+                output.push(c);
+                previous = Some(c);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+const NUMBER_ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const NUMBER_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const NUMBER_SCALES: [&str; 7] = [
+    "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+];
+
+// Spells out a number between 1 and 999
+fn push_hundreds_words(n: u64, and_connector: bool, words: &mut Vec<String>) {
+    if n >= 100 {
+        words.push(NUMBER_ONES[(n / 100) as usize].to_string());
+        words.push("hundred".to_string());
+        if and_connector && !n.is_multiple_of(100) {
+            words.push("and".to_string());
+        }
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = NUMBER_TENS[(rest / 10) as usize];
+        words.push(match rest % 10 {
+            0 => tens.to_string(),
+            ones => format!("{}-{}", tens, NUMBER_ONES[ones as usize]),
+        });
+    } else if rest > 0 {
+        words.push(NUMBER_ONES[rest as usize].to_string());
+    }
+}
+
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ => match word.strip_suffix('y') {
+            Some(stem) => format!("{stem}ieth"),
+            None => format!("{word}th"),
+        },
+    }
+}
+
+fn number_words(number: i64, kwargs: &NumberWordsKwargs) -> String {
+    let mut words: Vec<String> = Vec::new();
+    if number < 0 {
+        words.push("negative".to_string());
+    }
+    let mut n = number.unsigned_abs();
+    if n == 0 {
+        words.push("zero".to_string());
+    }
+
+    let mut groups: Vec<u64> = Vec::new();
+    while n > 0 {
+        groups.push(n % 1000);
+        n /= 1000;
+    }
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        // British style also joins a trailing number below one hundred, e.g. "one thousand and one"
+        if kwargs.and_connector && scale == 0 && group < 100 && groups.len() > 1 {
+            words.push("and".to_string());
+        }
+        push_hundreds_words(group, kwargs.and_connector, &mut words);
+        if scale > 0 {
+            words.push(NUMBER_SCALES[scale].to_string());
+        }
+    }
+
+    if kwargs.ordinal {
+        let last = words.pop().unwrap();
+        let ordinal = match last.rsplit_once('-') {
+            Some((tens, ones)) => format!("{}-{}", tens, ordinal_word(ones)),
+            None => ordinal_word(&last),
+        };
+        words.push(ordinal);
+    }
+    words.join(" ")
+}
+
+#[derive(Deserialize)]
+pub struct NumberWordsKwargs {
+    ordinal: bool,
+    and_connector: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn number_to_words(inputs: &[Series], kwargs: NumberWordsKwargs) -> PolarsResult<Series> {
+    // Numeric strings are accepted too, anything which is not an integer becomes null
+    let series =
+        if inputs[0].dtype().is_string() {
+            inputs[0].str()?.apply_values(|s| s.trim().into()).cast(&DataType::Int64)?
+        } else {
+            inputs[0].cast(&DataType::Int64)?
+        };
+    let ca: &Int64Chunked = series.i64()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_n: Option<i64>| {
+        opt_n.map(|n| number_words(n, &kwargs))
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct JoinKeyKwargs {
+    trim: bool,
+    casefold: bool,
+    nfkc: bool,
+    collapse_whitespace: bool,
+    strip_zero_width: bool,
+}
+
+// Full case folding differs from lowercasing for a handful of letters
+fn push_casefolded(c: char, output: &mut String) {
+    match c {
+        'ß' | 'ẞ' => output.push_str("ss"),
+        'ς' => output.push('σ'),
+        _ => output.extend(c.to_lowercase()),
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_join_key(inputs: &[Series], kwargs: JoinKeyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let chars: Box<dyn Iterator<Item = char>> =
+            if kwargs.nfkc {
+                Box::new(value.nfkc())
+            } else {
+                Box::new(value.chars())
+            };
+        // Whitespace is held back until the next visible character to know whether it is trailing
+        let mut pending_whitespace = String::new();
+        for c in chars {
+            if kwargs.strip_zero_width && is_zero_width(c) {
+                continue;
+            }
+            if c.is_whitespace() {
+                pending_whitespace.push(c);
+                continue;
+            }
+            if !(pending_whitespace.is_empty() || kwargs.trim && output.is_empty()) {
+                if kwargs.collapse_whitespace {
+                    output.push(' ');
+                } else {
+                    output.push_str(&pending_whitespace);
+                }
+            }
+            pending_whitespace.clear();
+            if kwargs.casefold {
+                push_casefolded(c, output);
+            } else {
+                output.push(c);
+            }
+        }
+        if !pending_whitespace.is_empty() && !kwargs.trim {
+            if kwargs.collapse_whitespace {
+                output.push(' ');
+            } else {
+                output.push_str(&pending_whitespace);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(PartialEq)]
+enum NumberWordKind {
+    Start,
+    Ones,
+    Teens,
+    Tens,
+    Hundred,
+    Scale,
+}
+
+fn parse_number_words(value: &str) -> Option<i64> {
+    let lowered = value.to_lowercase().replace(['-', ','], " ");
+    let mut tokens = lowered.split_whitespace().filter(|&token| token != "and").peekable();
+    let negative = tokens.next_if(|&token| token == "negative" || token == "minus").is_some();
+
+    let mut total: i64 = 0;
+    let mut group: i64 = 0;
+    let mut last = NumberWordKind::Start;
+    let mut last_scale = i64::MAX;
+    let mut is_zero = false;
+    for token in tokens {
+        if is_zero {
+            return None;
+        }
+        if let Some(n) = NUMBER_ONES.iter().position(|&word| word == token) {
+            let n = n as i64;
+            if n == 0 {
+                // Zero is only ever spelled on its own
+                is_zero = last == NumberWordKind::Start;
+                if !is_zero {
+                    return None;
+                }
+            } else if n < 10 && matches!(last, NumberWordKind::Start | NumberWordKind::Tens | NumberWordKind::Hundred | NumberWordKind::Scale) {
+                last = NumberWordKind::Ones;
+            } else if n >= 10 && matches!(last, NumberWordKind::Start | NumberWordKind::Hundred | NumberWordKind::Scale) {
+                last = NumberWordKind::Teens;
+            } else {
+                return None;
+            }
+            group += n;
+        } else if let Some(n) = NUMBER_TENS.iter().position(|&word| !word.is_empty() && word == token) {
+            if !matches!(last, NumberWordKind::Start | NumberWordKind::Hundred | NumberWordKind::Scale) {
+                return None;
+            }
+            group += n as i64 * 10;
+            last = NumberWordKind::Tens;
+        } else if token == "hundred" {
+            // Also accepts the informal "fifteen hundred"
+            if !matches!(last, NumberWordKind::Ones | NumberWordKind::Teens) || group >= 100 {
+                return None;
+            }
+            group *= 100;
+            last = NumberWordKind::Hundred;
+        } else if let Some(scale) = NUMBER_SCALES.iter().skip(1).position(|&word| word == token) {
+            let multiplier = 1000_i64.pow(scale as u32 + 1);
+            if group == 0 || multiplier >= last_scale {
+                return None;
+            }
+            total = total.checked_add(group.checked_mul(multiplier)?)?;
+            group = 0;
+            last_scale = multiplier;
+            last = NumberWordKind::Scale;
+        } else {
+            return None;
+        }
+    }
+    if last == NumberWordKind::Start && !is_zero {
+        return None;
+    }
+    let number = total.checked_add(group)?;
+    Some(if negative { -number } else { number })
+}
+
+#[polars_expr(output_type=Int64)]
+fn words_to_number(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Int64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.and_then(parse_number_words)
+    });
+    Ok(out.into_series())
+}
+
+fn slugify(value: &str, separator: &str) -> String {
+    let mut ascii = String::with_capacity(value.len());
+    push_ascii(value, "", &mut ascii);
+    let mut slug = String::with_capacity(ascii.len());
+    for word in ascii.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()) {
+        if !slug.is_empty() {
+            slug.push_str(separator);
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug
+}
+
+#[derive(Deserialize)]
+pub struct UniqueSlugKwargs {
+    separator: String,
+}
+
+#[polars_expr(output_type=String)]
+fn unique_slug(inputs: &[Series], kwargs: UniqueSlugKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut taken: HashSet<String> = HashSet::with_capacity(ca.len());
+    // Continue numbering from the last suffix given to each base slug
+    let mut last_suffix: HashMap<String, usize> = HashMap::new();
+    let out: StringChunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            let slug = slugify(opt_s?, &kwargs.separator);
+            if taken.insert(slug.clone()) {
+                return Some(slug);
+            }
+            let suffix = last_suffix.entry(slug.clone()).or_insert(0);
+            loop {
+                *suffix += 1;
+                let candidate = format!("{}{}{}", slug, kwargs.separator, suffix);
+                if taken.insert(candidate.clone()) {
+                    return Some(candidate);
+                }
+            }
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct WrapKwargs {
+    width: usize,
+    break_long_words: bool,
+    preserve_newlines: bool,
+}
+
+// Splits a word into graphemes, each taking up a single column
+fn grapheme_units(word: &str) -> Vec<(&str, usize)> {
+    word.graphemes(true).map(|grapheme| (grapheme, 1)).collect()
+}
+
+// Greedily fills lines with whole words, a line only exceeds the width for a long word left unbroken.
+// Words are measured by the widths of the units they are split into.
+fn wrap_paragraph(paragraph: &str, kwargs: &WrapKwargs, units: fn(&str) -> Vec<(&str, usize)>, lines: &mut Vec<String>) {
+    let width_of = |units: &[(&str, usize)]| units.iter().map(|&(_, width)| width).sum::<usize>();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in paragraph.split_whitespace() {
+        let word_units = units(word);
+        let word_width = width_of(&word_units);
+        if line_width > 0 && line_width + 1 + word_width <= kwargs.width {
+            line.push(' ');
+            line.push_str(word);
+            line_width += 1 + word_width;
+            continue;
+        }
+        if line_width > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        let mut rest = word_units.as_slice();
+        if kwargs.break_long_words {
+            while width_of(rest) > kwargs.width {
+                // Zero-width units right after a full piece stay with it
+                let mut taken = 0;
+                let mut taken_width = 0;
+                while taken < rest.len() && taken_width + rest[taken].1 <= kwargs.width {
+                    taken_width += rest[taken].1;
+                    taken += 1;
+                }
+                lines.push(rest[..taken].iter().map(|&(unit, _)| unit).collect());
+                rest = &rest[taken..];
+            }
+        }
+        line = rest.iter().map(|&(unit, _)| unit).collect();
+        line_width = width_of(rest);
+    }
+    if line_width > 0 || lines.is_empty() {
+        lines.push(line);
+    }
+}
+
+fn wrap_lines(value: &str, kwargs: &WrapKwargs, units: fn(&str) -> Vec<(&str, usize)>, output: &mut String) {
+    let mut lines: Vec<String> = Vec::new();
+    if kwargs.preserve_newlines {
+        for paragraph in value.split('\n') {
+            let mut paragraph_lines = Vec::new();
+            wrap_paragraph(paragraph, kwargs, units, &mut paragraph_lines);
+            lines.extend(paragraph_lines);
+        }
+    } else {
+        wrap_paragraph(value, kwargs, units, &mut lines);
+    }
+    output.push_str(&lines.join("\n"));
+}
+
+#[polars_expr(output_type=String)]
+fn wrap_text(inputs: &[Series], kwargs: WrapKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    polars_ensure!(kwargs.width > 0, InvalidOperation: "width must be greater than zero");
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        wrap_lines(value, &kwargs, grapheme_units, output);
+    });
+    Ok(out.into_series())
+}
+
+// Consonant pairs which are pronounced together and should not be split
+fn is_consonant_digraph(a: char, b: char) -> bool {
+    matches!((a.to_ascii_lowercase(), b.to_ascii_lowercase()),
+        ('c', 'h') | ('s', 'h') | ('t', 'h') | ('p', 'h') | ('w', 'h') | ('c', 'k') | ('n', 'g') | ('q', 'u'))
+}
+
+// Breaks before a single consonant between vowels (ba-con) or between two consonants (win-ter),
+// keeping at least two letters on each side of every break
+fn push_soft_hyphenated(word: &[char], output: &mut String) {
+    let mut last_break = 0;
+    for (i, &c) in word.iter().enumerate() {
+        let can_break = i >= 2 && word.len() - i >= 2 && i - last_break >= 2 && !is_vowel(c) && is_vowel(word[i + 1]);
+        let before_consonant = can_break && !is_vowel(word[i - 1]) && is_vowel(word[i - 2]) && !is_consonant_digraph(word[i - 1], c);
+        let before_vowel = can_break && is_vowel(word[i - 1]);
+        if before_consonant || before_vowel {
+            output.push('\u{ad}');
+            last_break = i;
+        }
+        output.push(c);
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SoftHyphenKwargs {
+    min_word_length: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn insert_soft_hyphens(inputs: &[Series], kwargs: SoftHyphenKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut word: Vec<char> = Vec::new();
+        let flush = |word: &mut Vec<char>, output: &mut String| {
+            if word.len() >= kwargs.min_word_length {
+                push_soft_hyphenated(word, output);
+            } else {
+                output.extend(word.iter());
+            }
+            word.clear();
+        };
+        for c in value.chars() {
+            if c.is_alphabetic() {
+                word.push(c);
+            } else {
+                flush(&mut word, output);
+                output.push(c);
+            }
+        }
+        flush(&mut word, output);
+    });
+    Ok(out.into_series())
+}
+
+// Same as Python's textwrap.dedent, lines with only whitespace are emptied and ignored for the margin
+#[polars_expr(output_type=String)]
+fn dedent(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut margin: Option<&str> = None;
+        for line in value.split('\n').filter(|line| !line.trim().is_empty()) {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            margin = Some(match margin {
+                None => indent,
+                Some(margin) => {
+                    let common = margin.chars().zip(indent.chars()).take_while(|(a, b)| a == b).map(|(a, _)| a.len_utf8()).sum();
+                    &margin[..common]
+                },
+            });
+        }
+        let margin = margin.unwrap_or("");
+        for (i, line) in value.split('\n').enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            if !line.trim().is_empty() {
+                output.push_str(&line[margin.len()..]);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct IndentKwargs {
+    prefix: String,
+    skip_empty_lines: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn indent(inputs: &[Series], kwargs: IndentKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for line in value.split_inclusive('\n') {
+            if !(kwargs.skip_empty_lines && line.trim().is_empty()) {
+                output.push_str(&kwargs.prefix);
+            }
+            output.push_str(line);
+        }
+    });
+    Ok(out.into_series())
+}
+
+struct CodeFence {
+    marker: char,
+    length: usize,
+    indent: usize,
+}
+
+// Parses a fence of at least three backticks or tildes indented by up to three spaces
+fn parse_code_fence(line: &str) -> Option<(CodeFence, &str)> {
+    let rest = line.trim_start_matches(' ');
+    let indent = line.len() - rest.len();
+    let marker = rest.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let length = rest.chars().take_while(|&c| c == marker).count();
+    if indent > 3 || length < 3 {
+        return None;
+    }
+    let info = rest[length..].trim();
+    // Backticks in the info string would make it an inline code span instead
+    if marker == '`' && info.contains('`') {
+        return None;
+    }
+    Some((CodeFence { marker, length, indent }, info))
+}
+
+fn code_blocks_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("language".into(), DataType::String),
+            Field::new("code".into(), DataType::String),
+        ]))),
+    ))
+}
+
+#[polars_expr(output_type_func=code_blocks_output)]
+fn extract_code_blocks(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let dtype = code_blocks_output(&[])?.dtype().inner_dtype().unwrap().clone();
+    let mut builder = get_list_builder(&dtype, ca.len(), ca.len(), "".into());
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        let mut languages: Vec<Option<String>> = Vec::new();
+        let mut codes: Vec<String> = Vec::new();
+        let mut open: Option<(CodeFence, Vec<&str>)> = None;
+        for line in s.lines() {
+            match open.take() {
+                None => {
+                    if let Some((fence, info)) = parse_code_fence(line) {
+                        let language = info.split_whitespace().next().map(str::to_string);
+                        languages.push(language);
+                        open = Some((fence, Vec::new()));
+                    }
+                },
+                Some((fence, mut content)) => {
+                    // A closing fence uses the same marker, at least as long, with nothing after it
+                    let is_closing = parse_code_fence(line).is_some_and(|(closing, info)| {
+                        closing.marker == fence.marker && closing.length >= fence.length && info.is_empty()
+                    });
+                    if is_closing {
+                        codes.push(content.join("\n"));
+                        continue;
+                    }
+                    // Content lines lose as much of their indentation as the opening fence had
+                    let trimmed = line.len() - line.trim_start_matches(' ').len();
+                    content.push(&line[trimmed.min(fence.indent)..]);
+                    open = Some((fence, content));
+                },
+            }
+        }
+        // An unclosed block runs until the end of the cell
+        if let Some((_, content)) = open {
+            codes.push(content.join("\n"));
+        }
+
+        let fields = [
+            Series::new("language".into(), languages),
+            Series::new("code".into(), codes),
+        ];
+        let rows = StructChunked::from_series("".into(), fields[0].len(), fields.iter())?;
+        builder.append_series(&rows.into_series())?;
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct SqueezeKwargs {
+    preserve_newlines: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn squeeze_whitespace(inputs: &[Series], kwargs: SqueezeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut run_has_newline = false;
+        let mut in_run = false;
+        for c in value.trim().chars() {
+            if c.is_whitespace() {
+                in_run = true;
+                run_has_newline |= c == '\n';
+                continue;
+            }
+            if in_run {
+                // A run of whitespace with line breaks becomes a single line break
+                output.push(if kwargs.preserve_newlines && run_has_newline { '\n' } else { ' ' });
+                in_run = false;
+                run_has_newline = false;
+            }
+            output.push(c);
+        }
+    });
+    Ok(out.into_series())
+}
+
+// Reference definitions like `[label]: https://example.com "Title"` on their own line
+static MARKDOWN_DEFINITION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^ {0,3}\[([^\]]+)\]:[ \t]*<?([^\s>]+)>?(?:[ \t]+(?:"[^"]*"|'[^']*'|\([^)]*\)))?[ \t]*(?:\r?\n|$)"#).unwrap()
+});
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkPart {
+    Url,
+    Text,
+}
+
+#[derive(Deserialize)]
+pub struct MarkdownLinksKwargs {
+    keep: LinkPart,
+}
+
+#[polars_expr(output_type=String)]
+fn markdown_links_to_urls(inputs: &[Series], kwargs: MarkdownLinksKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        // Reference definitions are consumed, labels are matched case-insensitively
+        let definitions: HashMap<String, String> = MARKDOWN_DEFINITION_REGEX
+            .captures_iter(value)
+            .map(|caps| (caps[1].trim().to_lowercase(), caps[2].to_string()))
+            .collect();
+        let value = MARKDOWN_DEFINITION_REGEX.replace_all(value, "");
+
+        let chars: Vec<char> = value.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            // Images share the syntax of links but are not links
+            if c != '[' || i > 0 && chars[i - 1] == '!' {
+                if c == '\\' && i + 1 < chars.len() {
+                    output.push(c);
+                    i += 1;
+                }
+                output.push(chars[i]);
+                i += 1;
+                continue;
+            }
+
+            let link = parse_markdown_link_at(&chars, i).or_else(|| {
+                let (text, end) = read_markdown_until(&chars, i + 1, ']', Some('['))?;
+                // Full [text][label], collapsed [text][] or shortcut [text] references
+                let (label, end) = match chars.get(end) {
+                    Some('[') => {
+                        let (label, end) = read_markdown_until(&chars, end + 1, ']', None)?;
+                        (if label.is_empty() { text.clone() } else { label }, end)
+                    },
+                    _ => (text.clone(), end),
+                };
+                let url = definitions.get(&label.trim().to_lowercase())?;
+                Some((text, url.clone(), end))
+            });
+            match link {
+                Some((text, url, end)) => {
+                    output.push_str(match kwargs.keep {
+                        LinkPart::Url => &url,
+                        LinkPart::Text => &text,
+                    });
+                    i = end;
+                },
+                None => {
+                    output.push(c);
+                    i += 1;
+                },
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct FenceKwargs {
+    language: Option<String>,
+}
+
+#[polars_expr(output_type=String)]
+fn code_fence(inputs: &[Series], kwargs: FenceKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let language = kwargs.language.as_deref().unwrap_or("").trim();
+    polars_ensure!(!language.contains('`'), InvalidOperation: "language must not contain backticks");
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        // The fence must be longer than any run of backticks in the content to not be closed early
+        let longest_run = value.split(|c: char| c != '`').map(str::len).max().unwrap_or(0);
+        let fence = "`".repeat((longest_run + 1).max(3));
+        output.push_str(&fence);
+        output.push_str(language);
+        output.push('\n');
+        output.push_str(value);
+        if !value.is_empty() && !value.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push_str(&fence);
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn common_prefix(inputs: &[Series]) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: StringChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (a?, b?);
+        let length: usize = a
+            .graphemes(true)
+            .zip(b.graphemes(true))
+            .take_while(|(x, y)| x == y)
+            .map(|(x, _)| x.len())
+            .sum();
+        Some(a[..length].to_string())
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn common_suffix(inputs: &[Series]) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: StringChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (a?, b?);
+        let length: usize = a
+            .graphemes(true)
+            .rev()
+            .zip(b.graphemes(true).rev())
+            .take_while(|(x, y)| x == y)
+            .map(|(x, _)| x.len())
+            .sum();
+        Some(a[a.len() - length..].to_string())
+    });
+    Ok(out.into_series())
+}
+
+fn list_uint32_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::UInt32)),
+    ))
+}
+
+#[polars_expr(output_type_func=list_uint32_output)]
+fn infer_columns(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new("".into(), ca.len(), 0, DataType::UInt32);
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        // Mark every character offset that holds a non-space character on at least one line
+        let mut occupied: Vec<bool> = Vec::new();
+        for line in s.lines() {
+            for (offset, c) in line.chars().enumerate() {
+                if offset >= occupied.len() {
+                    occupied.resize(offset + 1, false);
+                }
+                occupied[offset] |= !c.is_whitespace();
+            }
+        }
+        // A column starts wherever an occupied offset follows one that is blank on every line
+        let starts: Vec<u32> = occupied
+            .iter()
+            .enumerate()
+            .filter(|&(offset, &filled)| filled && (offset == 0 || !occupied[offset - 1]))
+            .map(|(offset, _)| offset as u32)
+            .collect();
+        builder.append_slice(&starts);
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct LcsKwargs {
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn longest_common_substring(inputs: &[Series], kwargs: LcsKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let fold = |c: char| -> char {
+        if kwargs.case_insensitive {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+    let out: StringChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let a: Vec<char> = a?.chars().collect();
+        let b: Vec<char> = b?.chars().map(fold).collect();
+        // Lengths of the common suffixes ending at each character of the second input, one row
+        // of the first input at a time
+        let mut lengths = vec![0usize; b.len() + 1];
+        let (mut best_length, mut best_end) = (0, 0);
+        for (i, &x) in a.iter().enumerate() {
+            let x = fold(x);
+            for j in (0..b.len()).rev() {
+                lengths[j + 1] =
+                    if x == b[j] {
+                        lengths[j] + 1
+                    } else {
+                        0
+                    };
+                // Only a strictly longer match wins, so ties go to the earliest one in the first input
+                if lengths[j + 1] > best_length {
+                    best_length = lengths[j + 1];
+                    best_end = i + 1;
+                }
+            }
+        }
+        Some(a[best_end - best_length..best_end].iter().collect::<String>())
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct FixedWidthKwargs {
+    widths: Vec<usize>,
+}
+
+// Slices off consecutive fields of the given widths, counted in graphemes or in chars, returning them
+// along with whatever is left. Fields past the end of the string are empty.
+fn slice_fixed_width<'a>(s: &'a str, widths: &[usize], by_grapheme: bool) -> (Vec<&'a str>, &'a str) {
+    let mut fields: Vec<&str> = Vec::with_capacity(widths.len() + 1);
+    let mut rest = s;
+    for &width in widths {
+        let end =
+            if by_grapheme {
+                rest.grapheme_indices(true).nth(width).map_or(rest.len(), |(end, _)| end)
+            } else {
+                rest.char_indices().nth(width).map_or(rest.len(), |(end, _)| end)
+            };
+        fields.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    (fields, rest)
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn parse_fixed_width(inputs: &[Series], kwargs: FixedWidthKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.widths.is_empty(), InvalidOperation: "widths must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        let s = s.trim_end_matches(['\r', '\n']);
+        // The final field captures whatever is left after the preceding ones
+        let (mut fields, rest) = slice_fixed_width(s, &kwargs.widths[..kwargs.widths.len() - 1], false);
+        fields.push(rest);
+        fields.into_iter().map(|field| field.trim().to_string()).collect()
+    })
+}
+
+#[derive(Deserialize)]
+pub struct SplitFixedWidthKwargs {
+    widths: Vec<usize>,
+    trim: bool,
+    keep_remainder: bool,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_fixed_width(inputs: &[Series], kwargs: SplitFixedWidthKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.widths.is_empty(), InvalidOperation: "widths must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        let s = s.trim_end_matches(['\r', '\n']);
+        let (mut fields, rest) = slice_fixed_width(s, &kwargs.widths, true);
+        // Anything beyond the given widths makes up a trailing field, if requested
+        if kwargs.keep_remainder && !rest.is_empty() {
+            fields.push(rest);
+        }
+        fields.into_iter().map(|field| if kwargs.trim { field.trim() } else { field }.to_string()).collect()
+    })
+}
+
+// Whether the needle occurs anywhere in the haystack within the given number of edits. This is the
+// Levenshtein distance with the start and the end of the match left free in the haystack.
+fn fuzzy_find(haystack: &[char], needle: &[char], max_edits: usize) -> bool {
+    if needle.len() <= max_edits {
+        return true;
+    }
+    // Edits needed to match each prefix of the needle ending at the current haystack character
+    let mut previous: Vec<usize> = (0..=needle.len()).collect();
+    let mut current = vec![0usize; needle.len() + 1];
+    for &h in haystack {
+        for (j, &n) in needle.iter().enumerate() {
+            let substitution = previous[j] + usize::from(h != n);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current[needle.len()] <= max_edits {
+            return true;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    false
+}
+
+#[derive(Deserialize)]
+pub struct FuzzyKwargs {
+    max_edits: u32,
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn fuzzy_contains(inputs: &[Series], kwargs: FuzzyKwargs) -> PolarsResult<Series> {
+    let haystacks: &StringChunked = inputs[0].str()?;
+    let needles: &StringChunked = inputs[1].str()?;
+    let chars = |value: &str| -> Vec<char> {
+        if kwargs.case_insensitive {
+            value.to_lowercase().chars().collect()
+        } else {
+            value.chars().collect()
+        }
+    };
+    let out: BooleanChunked = broadcast_binary_elementwise(haystacks, needles, |h: Option<&str>, n: Option<&str>| {
+        Some(fuzzy_find(&chars(h?), &chars(n?), kwargs.max_edits as usize))
+    });
+    Ok(out.into_series())
+}
+
+// Characters that Windows-1252 places in the 0x80-0x9F range, where Latin-1 has control characters
+const CP1252_SPECIALS: [(char, u8); 27] = [
+    ('€', 0x80), ('‚', 0x82), ('ƒ', 0x83), ('„', 0x84), ('…', 0x85), ('†', 0x86), ('‡', 0x87),
+    ('ˆ', 0x88), ('‰', 0x89), ('Š', 0x8A), ('‹', 0x8B), ('Œ', 0x8C), ('Ž', 0x8E), ('‘', 0x91),
+    ('’', 0x92), ('“', 0x93), ('”', 0x94), ('•', 0x95), ('–', 0x96), ('—', 0x97), ('˜', 0x98),
+    ('™', 0x99), ('š', 0x9A), ('›', 0x9B), ('œ', 0x9C), ('ž', 0x9E), ('Ÿ', 0x9F),
+];
+
+// The byte a character would have been decoded from when UTF-8 is misread as Windows-1252 or Latin-1
+fn cp1252_byte(c: char) -> Option<u8> {
+    match c as u32 {
+        0x80..=0xFF => Some(c as u8),
+        _ => CP1252_SPECIALS.iter().find(|&&(special, _)| special == c).map(|&(_, byte)| byte),
+    }
+}
+
+// Number of characters, starting at the given offset, that form a UTF-8 multibyte sequence when
+// mapped back to their Windows-1252 bytes, e.g. "Ã©" for "é" or "â€™" for "’"
+fn misdecoded_sequence_length(chars: &[char], start: usize) -> usize {
+    let continuations = match cp1252_byte(chars[start]) {
+        Some(0xC2..=0xDF) => 1,
+        Some(0xE0..=0xEF) => 2,
+        Some(0xF0..=0xF4) => 3,
+        _ => return 0,
+    };
+    let is_continuation = |c: &char| matches!(cp1252_byte(*c), Some(0x80..=0xBF));
+    match chars.get(start + 1..=start + continuations) {
+        Some(tail) if tail.iter().all(is_continuation) => continuations + 1,
+        _ => 0,
+    }
+}
+
+#[polars_expr(output_type=Float64)]
+fn mojibake_score(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let chars: Vec<char> = opt_s?.chars().collect();
+        let non_ascii = chars.iter().filter(|c| !c.is_ascii()).count();
+        if non_ascii == 0 {
+            return Some(0.0);
+        }
+        // The share of non-ASCII characters that look like the debris of a wrong decoding
+        let mut suspicious = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            let length = misdecoded_sequence_length(&chars, i);
+            if length > 0 {
+                suspicious += length;
+                i += length;
+                continue;
+            }
+            if chars[i] == '\u{fffd}' || matches!(chars[i] as u32, 0x80..=0x9F) {
+                suspicious += 1;
+            }
+            i += 1;
+        }
+        Some(suspicious as f64 / non_ascii as f64)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Deserialize)]
+pub struct AlignKwargs {
+    width: usize,
+    alignment: Alignment,
+    fill: char,
+    truncate: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn align(inputs: &[Series], kwargs: AlignKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let length = value.graphemes(true).count();
+        if length >= kwargs.width {
+            if kwargs.truncate {
+                output.extend(value.graphemes(true).take(kwargs.width));
+            } else {
+                output.push_str(value);
+            }
+            return;
+        }
+        // Centered values lean to the left when the padding can't be split evenly
+        let padding = kwargs.width - length;
+        let left = match kwargs.alignment {
+            Alignment::Left => 0,
+            Alignment::Right => padding,
+            Alignment::Center => padding / 2,
+        };
+        output.extend(std::iter::repeat_n(kwargs.fill, left));
+        output.push_str(value);
+        output.extend(std::iter::repeat_n(kwargs.fill, padding - left));
+    });
+    Ok(out.into_series())
+}
+
+// Emoji with the emoticons that stand for them, the first of which is used when converting back.
+// Variants with and without a nose are both recognized.
+const EMOTICONS: &[(&str, &[&str])] = &[
+    ("\u{1f642}", &[":)", ":-)", "=)", ":]"]),
+    ("\u{1f603}", &[":D", ":-D", "=D"]),
+    ("\u{1f641}", &[":(", ":-(", "=(", ":["]),
+    ("\u{1f609}", &[";)", ";-)"]),
+    ("\u{1f61b}", &[":P", ":-P", ":p", ":-p"]),
+    ("\u{1f62e}", &[":O", ":-O", ":o", ":-o"]),
+    ("\u{1f622}", &[":'(", ":'-("]),
+    ("\u{1f610}", &[":|", ":-|"]),
+    ("\u{1f615}", &[":/", ":-/"]),
+    ("\u{1f618}", &[":*", ":-*"]),
+    ("\u{1f60e}", &["B)", "B-)"]),
+    ("\u{1f606}", &["XD", "xD"]),
+    ("\u{2764}\u{fe0f}", &["<3"]),
+    ("\u{2764}", &["<3"]),
+    ("\u{1f494}", &["</3"]),
+];
+
+#[polars_expr(output_type=String)]
+fn emoticon_to_emoji(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut at_word_start = true;
+        let mut i = 0;
+        while let Some(c) = value[i..].chars().next() {
+            // Only standalone emoticons are converted, so that e.g. "http://" or "f(x)" stay intact
+            let emoticon = EMOTICONS
+                .iter()
+                .flat_map(|&(emoji, emoticons)| emoticons.iter().map(move |&emoticon| (emoji, emoticon)))
+                .filter(|&(_, emoticon)| at_word_start && value[i..].starts_with(emoticon))
+                .filter(|&(_, emoticon)| {
+                    value[i + emoticon.len()..]
+                        .chars()
+                        .next()
+                        .is_none_or(|next| next.is_whitespace() || matches!(next, '.' | ',' | '!' | '?'))
+                })
+                .min_by_key(|&(_, emoticon)| Reverse(emoticon.len()));
+            match emoticon {
+                Some((emoji, emoticon)) => {
+                    output.push_str(emoji);
+                    i += emoticon.len();
+                },
+                None => {
+                    output.push(c);
+                    i += c.len_utf8();
+                },
+            }
+            at_word_start = c.is_whitespace() && emoticon.is_none();
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn emoji_to_emoticon(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut i = 0;
+        while let Some(c) = value[i..].chars().next() {
+            let emoji = EMOTICONS
+                .iter()
+                .filter(|&&(emoji, _)| value[i..].starts_with(emoji))
+                .min_by_key(|&&(emoji, _)| Reverse(emoji.len()));
+            match emoji {
+                Some(&(emoji, emoticons)) => {
+                    output.push_str(emoticons[0]);
+                    i += emoji.len();
+                },
+                None => {
+                    output.push(c);
+                    i += c.len_utf8();
+                },
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ShowWhitespaceKwargs {
+    space: String,
+    tab: String,
+    newline: String,
+}
+
+#[polars_expr(output_type=String)]
+fn show_whitespace(inputs: &[Series], kwargs: ShowWhitespaceKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for c in value.chars() {
+            match c {
+                ' ' => output.push_str(&kwargs.space),
+                '\t' => output.push_str(&kwargs.tab),
+                '\n' => output.push_str(&kwargs.newline),
+                _ => output.push(c),
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn ordinal(inputs: &[Series]) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_n: Option<i64>| {
+        let n = opt_n?;
+        let magnitude = n.unsigned_abs();
+        // Numbers ending in 11, 12 and 13 take "th" despite their last digit
+        let suffix = match (magnitude % 10, magnitude % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        let sign = if n < 0 { "-" } else { "" };
+        Some(format!("{sign}{magnitude}{suffix}"))
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    // Han ideographs together with the Japanese kana, which are written intermixed
+    Cjk,
+    Other,
+}
+
+// The script of a character, or none for characters shared between scripts such as whitespace,
+// punctuation, digits, symbols and combining marks
+fn char_script(c: char) -> Option<Script> {
+    if !c.is_alphabetic() || is_combining_mark(c) {
+        return None;
+    }
+    let script = match c {
+        'A'..='Z' | 'a'..='z' | '\u{aa}' | '\u{ba}' | '\u{c0}'..='\u{24f}' | '\u{1e00}'..='\u{1eff}' => Script::Latin,
+        '\u{370}'..='\u{3ff}' | '\u{1f00}'..='\u{1fff}' => Script::Greek,
+        '\u{400}'..='\u{52f}' => Script::Cyrillic,
+        '\u{530}'..='\u{58f}' => Script::Armenian,
+        '\u{590}'..='\u{5ff}' => Script::Hebrew,
+        '\u{600}'..='\u{6ff}' | '\u{750}'..='\u{77f}' => Script::Arabic,
+        '\u{900}'..='\u{97f}' => Script::Devanagari,
+        '\u{e00}'..='\u{e7f}' => Script::Thai,
+        '\u{1100}'..='\u{11ff}' | '\u{3130}'..='\u{318f}' | '\u{ac00}'..='\u{d7af}' => Script::Hangul,
+        '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' | '\u{f900}'..='\u{faff}'
+        | '\u{20000}'..='\u{3134f}' => Script::Cjk,
+        _ => Script::Other,
+    };
+    Some(script)
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_by_script_change(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        // Shared characters stay with the preceding segment, a segment only ends right before a
+        // character of another script
+        let mut segments: Vec<&str> = Vec::new();
+        let mut start = 0;
+        let mut current: Option<Script> = None;
+        for (i, c) in s.char_indices() {
+            let Some(script) = char_script(c) else {
+                continue;
+            };
+            if current.is_some_and(|current| current != script) {
+                segments.push(&s[start..i]);
+                start = i;
+            }
+            current = Some(script);
+        }
+        segments.push(&s[start..]);
+        segments.into_iter().map(str::trim).filter(|segment| !segment.is_empty()).map(String::from).collect()
+    })
+}
+
+const ROMAN_NUMERALS: [(i64, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+    (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
+fn roman_numeral(mut number: i64) -> Option<String> {
+    if !(1..=3999).contains(&number) {
+        return None;
+    }
+    let mut numeral = String::new();
+    for &(value, symbol) in ROMAN_NUMERALS.iter() {
+        while number >= value {
+            numeral.push_str(symbol);
+            number -= value;
+        }
+    }
+    Some(numeral)
+}
+
+#[derive(Deserialize)]
+pub struct RomanKwargs {
+    lowercase: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn to_roman(inputs: &[Series], kwargs: RomanKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_n: Option<i64>| {
+        let numeral = roman_numeral(opt_n?)?;
+        Some(if kwargs.lowercase { numeral.to_lowercase() } else { numeral })
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct FromRomanKwargs {
+    strict: bool,
+}
+
+#[polars_expr(output_type=Int64)]
+fn from_roman(inputs: &[Series], kwargs: FromRomanKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Int64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let numeral = opt_s?.trim().to_uppercase();
+        let values = numeral
+            .chars()
+            .map(|c| match c {
+                'I' => Some(1),
+                'V' => Some(5),
+                'X' => Some(10),
+                'L' => Some(50),
+                'C' => Some(100),
+                'D' => Some(500),
+                'M' => Some(1000),
+                _ => None,
+            })
+            .collect::<Option<Vec<i64>>>()?;
+        // A symbol followed by a larger one is subtracted, e.g. "IV" or "XC"
+        let mut number = 0;
+        for (i, &value) in values.iter().enumerate() {
+            if values.get(i + 1).is_some_and(|&next| next > value) {
+                number -= value;
+            } else {
+                number += value;
+            }
+        }
+        if !(1..=3999).contains(&number) {
+            return None;
+        }
+        // Only the canonical spelling of the number is accepted in strict mode, so "IIII" or "IC" are rejected
+        if kwargs.strict && roman_numeral(number).is_none_or(|canonical| canonical != numeral) {
+            return None;
+        }
+        Some(number)
+    });
+    Ok(out.into_series())
+}
+
+fn is_greek_vowel(c: char) -> bool {
+    matches!(c, 'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω' | 'ά' | 'έ' | 'ή' | 'ί' | 'ό' | 'ύ' | 'ώ' | 'ϊ' | 'ϋ' | 'ΐ' | 'ΰ')
+}
+
+// Maps a lowercase Greek letter given its lowercase neighbours, following ELOT 743
+fn romanize_greek(c: char, previous: Option<char>, next: Option<char>) -> Option<&'static str> {
+    let mapped = match c {
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => if matches!(next, Some('γ' | 'ξ' | 'χ')) { "n" } else { "g" },
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' => "i",
+        'θ' => "th",
+        'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        // "ου" is "ou", while "αυ", "ευ" and "ηυ" are voiceless before voiceless consonants and at the end
+        'υ' | 'ύ' => match previous {
+            Some('ο' | 'ό') => "u",
+            Some('α' | 'ά' | 'ε' | 'έ' | 'η' | 'ή') => {
+                let voiced = next.is_some_and(|n| is_greek_vowel(n) || matches!(n, 'β' | 'γ' | 'δ' | 'ζ' | 'λ' | 'μ' | 'ν' | 'ρ'));
+                if voiced { "v" } else { "f" }
+            },
+            _ => "y",
+        },
+        'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' | 'ώ' => "o",
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+// Latin letter sequences with the letters they stand for, where the longest sequence wins
+const LATIN_TO_CYRILLIC: &[(&str, &str)] = &[
+    ("shch", "щ"), ("zh", "ж"), ("kh", "х"), ("ts", "ц"), ("ch", "ч"), ("sh", "ш"), ("yu", "ю"),
+    ("ya", "я"), ("ye", "е"), ("yo", "ё"), ("a", "а"), ("b", "б"), ("v", "в"), ("g", "г"),
+    ("d", "д"), ("e", "е"), ("ë", "ё"), ("z", "з"), ("i", "и"), ("y", "й"), ("k", "к"), ("l", "л"),
+    ("m", "м"), ("n", "н"), ("o", "о"), ("p", "п"), ("r", "р"), ("s", "с"), ("t", "т"), ("u", "у"),
+    ("f", "ф"), ("h", "х"), ("c", "ц"), ("j", "й"), ("w", "в"), ("\u{2b9}", "ь"), ("\u{2ba}", "ъ"),
+];
+
+const LATIN_TO_GREEK: &[(&str, &str)] = &[
+    ("th", "θ"), ("ch", "χ"), ("ps", "ψ"), ("ks", "ξ"), ("ou", "ου"), ("a", "α"), ("b", "μπ"),
+    ("v", "β"), ("g", "γ"), ("d", "δ"), ("e", "ε"), ("z", "ζ"), ("i", "ι"), ("k", "κ"), ("l", "λ"),
+    ("m", "μ"), ("n", "ν"), ("x", "ξ"), ("o", "ο"), ("p", "π"), ("r", "ρ"), ("s", "σ"), ("t", "τ"),
+    ("y", "υ"), ("f", "φ"), ("u", "ου"), ("w", "ου"), ("c", "κ"), ("q", "κ"), ("j", "ι"),
+];
+
+fn push_delatinized(value: &str, table: &[(&str, &str)], output: &mut String) {
+    let chars: Vec<char> = value.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let matched = table
+            .iter()
+            .filter(|&&(latin, _)| {
+                let length = latin.chars().count();
+                chars.get(i..i + length).is_some_and(|chunk| {
+                    chunk.iter().flat_map(|c| c.to_lowercase()).eq(latin.chars())
+                })
+            })
+            .min_by_key(|&&(latin, _)| Reverse(latin.len()));
+        let Some(&(latin, mapped)) = matched else {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        };
+        let end = i + latin.chars().count();
+        // Greek uses a final form of sigma at the end of a word
+        let mapped =
+            if mapped == "σ" && !chars.get(end).is_some_and(|c| c.is_alphabetic()) {
+                "ς"
+            } else {
+                mapped
+            };
+        // Keep fully uppercased words uppercased, otherwise capitalize only the first letter
+        let chunk = &chars[i..end];
+        let neighbour_is_upper = chars.get(end).or(i.checked_sub(1).and_then(|j| chars.get(j)))
+            .is_some_and(|n| n.is_uppercase());
+        if chunk.iter().all(|c| c.is_uppercase()) && (chunk.len() > 1 || neighbour_is_upper) {
+            output.push_str(&mapped.to_uppercase());
+        } else if chunk[0].is_uppercase() {
+            let mut letters = mapped.chars();
+            if let Some(first) = letters.next() {
+                output.extend(first.to_uppercase());
+                output.extend(letters);
+            }
+        } else {
+            output.push_str(mapped);
+        }
+        i = end;
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TransliterateKwargs {
+    from: String,
+    to: String,
+}
+
+#[polars_expr(output_type=String)]
+fn transliterate(inputs: &[Series], kwargs: TransliterateKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let pair = (kwargs.from.to_lowercase(), kwargs.to.to_lowercase());
+    let push: fn(&str, &mut String) = match (pair.0.as_str(), pair.1.as_str()) {
+        ("cyrillic", "latin") => |value, output| push_transliterated_cyrillic(value, &TranslitScheme::Bgn, output),
+        ("greek", "latin") => |value, output| push_romanized(value, romanize_greek, output),
+        ("latin", "cyrillic") => |value, output| push_delatinized(value, LATIN_TO_CYRILLIC, output),
+        ("latin", "greek") => |value, output| push_delatinized(value, LATIN_TO_GREEK, output),
+        _ => polars_bail!(InvalidOperation: "unsupported transliteration from {:?} to {:?}", kwargs.from, kwargs.to),
+    };
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        push(value, output);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct LuhnKwargs {
+    strict: bool,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn luhn_check(inputs: &[Series], kwargs: LuhnKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let mut digits: Vec<u32> = Vec::new();
+        for c in opt_s?.chars() {
+            match c.to_digit(10) {
+                Some(digit) => digits.push(digit),
+                None if c == ' ' || c == '-' => {},
+                // Anything else is not a number at all, rather than a number which fails the checksum
+                None if kwargs.strict => return None,
+                None => return Some(false),
+            }
+        }
+        if digits.len() < 2 {
+            return Some(false);
+        }
+        // Every second digit from the right, starting with the one before the check digit, is doubled
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &digit)| {
+                if i % 2 == 1 {
+                    let doubled = digit * 2;
+                    if doubled > 9 { doubled - 9 } else { doubled }
+                } else {
+                    digit
+                }
+            })
+            .sum();
+        Some(sum.is_multiple_of(10))
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn fix_apostrophes(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let chars: Vec<char> = value.chars().collect();
+        for (i, &c) in chars.iter().enumerate() {
+            // Only marks between two letters are apostrophes for sure, as in "don’t" or "O’Brien",
+            // anything else may just as well be a quotation mark
+            let is_apostrophe = matches!(c, '\u{2018}' | '\u{2019}' | '\u{2bc}')
+                && i.checked_sub(1).is_some_and(|j| chars[j].is_alphabetic())
+                && chars.get(i + 1).is_some_and(|n| n.is_alphabetic());
+            output.push(if is_apostrophe { '\'' } else { c });
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashAlgo {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashOutput {
+    Hex,
+    Base64,
+}
+
+#[derive(Deserialize)]
+pub struct HashKwargs {
+    algorithm: HashAlgo,
+    output: HashOutput,
+}
+
+fn hash_digest(bytes: &[u8], kwargs: &HashKwargs) -> String {
+    let digest: Vec<u8> = match kwargs.algorithm {
+        HashAlgo::Md5 => Md5::digest(bytes).to_vec(),
+        HashAlgo::Sha1 => Sha1::digest(bytes).to_vec(),
+        HashAlgo::Sha256 => Sha256::digest(bytes).to_vec(),
+    };
+    match kwargs.output {
+        HashOutput::Hex => digest.iter().fold(String::with_capacity(digest.len() * 2), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        }),
+        HashOutput::Base64 => BASE64.encode(&digest),
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn hash_string(inputs: &[Series], kwargs: HashKwargs) -> PolarsResult<Series> {
+    let out: StringChunked =
+        if let DataType::Binary = inputs[0].dtype() {
+            unary_elementwise(inputs[0].binary()?, |opt_b: Option<&[u8]>| {
+                opt_b.map(|b| hash_digest(b, &kwargs))
+            })
+        } else {
+            unary_elementwise(inputs[0].str()?, |opt_s: Option<&str>| {
+                opt_s.map(|s| hash_digest(s.as_bytes(), &kwargs))
+            })
+        };
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct NKwargs {
+    n: usize,
+}
+
+// The words of a value ordered by grapheme length, with ties kept in order of first occurrence
+fn words_by_length(value: &str, longest_first: bool) -> Vec<&str> {
+    let mut words: Vec<(usize, &str)> = value.unicode_words().map(|word| (word.graphemes(true).count(), word)).collect();
+    if longest_first {
+        words.sort_by_key(|&(length, _)| Reverse(length));
+    } else {
+        words.sort_by_key(|&(length, _)| length);
+    }
+    words.into_iter().map(|(_, word)| word).collect()
+}
+
+fn words_by_length_expr(inputs: &[Series], n: usize, longest_first: bool) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| {
+        words_by_length(s, longest_first).into_iter().take(n).map(String::from).collect()
+    })
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn longest_words(inputs: &[Series], kwargs: NKwargs) -> PolarsResult<Series> {
+    words_by_length_expr(inputs, kwargs.n, true)
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn shortest_words(inputs: &[Series], kwargs: NKwargs) -> PolarsResult<Series> {
+    words_by_length_expr(inputs, kwargs.n, false)
+}
+
+#[derive(Deserialize)]
+pub struct HashU64Kwargs {
+    seed: u64,
+}
+
+#[polars_expr(output_type=UInt64)]
+fn hash_u64(inputs: &[Series], kwargs: HashU64Kwargs) -> PolarsResult<Series> {
+    // XXH64 is defined on bytes, so the values are the same on every platform and in every run
+    let out: UInt64Chunked =
+        if let DataType::Binary = inputs[0].dtype() {
+            unary_elementwise(inputs[0].binary()?, |opt_b: Option<&[u8]>| {
+                opt_b.map(|b| xxh64(b, kwargs.seed))
+            })
+        } else {
+            unary_elementwise(inputs[0].str()?, |opt_s: Option<&str>| {
+                opt_s.map(|s| xxh64(s.as_bytes(), kwargs.seed))
+            })
+        };
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn avg_word_length(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let lengths: Vec<usize> = opt_s?.unicode_words().map(|word| word.graphemes(true).count()).collect();
+        if lengths.is_empty() {
+            return None;
+        }
+        Some(lengths.iter().sum::<usize>() as f64 / lengths.len() as f64)
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn unique_word_ratio(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        // Words differing only in case count as the same word
+        let words: Vec<String> = opt_s?.unicode_words().map(str::to_lowercase).collect();
+        if words.is_empty() {
+            return None;
+        }
+        let distinct: HashSet<&String> = words.iter().collect();
+        Some(distinct.len() as f64 / words.len() as f64)
+    });
+    Ok(out.into_series())
+}
+
+fn list_uint64_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::UInt64)),
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct MinHashKwargs {
+    num_hashes: usize,
+    shingle_size: usize,
+    mode: ShingleMode,
+    seed: u64,
+}
+
+#[polars_expr(output_type_func=list_uint64_output)]
+fn minhash(inputs: &[Series], kwargs: MinHashKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.num_hashes > 0, InvalidOperation: "num_hashes must be greater than zero");
+    polars_ensure!(kwargs.shingle_size > 0, InvalidOperation: "shingle_size must be greater than zero");
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt64Type>::new("".into(), ca.len(), 0, DataType::UInt64);
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        // Each hash function is XXH64 with its own seed derived from the given one, the share of
+        // equal minimums between two signatures estimates the Jaccard similarity of their shingles
+        let shingles = shingle_set(s, &kwargs.mode, kwargs.shingle_size);
+        let signature: Vec<u64> = (0..kwargs.num_hashes as u64)
+            .map(|i| {
+                let seed = kwargs.seed.wrapping_add(i);
+                shingles.iter().map(|shingle| xxh64(shingle.as_bytes(), seed)).min().unwrap_or(u64::MAX)
+            })
+            .collect();
+        builder.append_slice(&signature);
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct CsvLineKwargs {
+    delimiter: char,
+    quote: char,
+    trim: bool,
+}
+
+fn parse_csv_fields(line: &str, kwargs: &CsvLineKwargs) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let (mut in_quotes, mut was_quoted) = (false, false);
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c != kwargs.quote {
+                field.push(c);
+            } else if chars.next_if_eq(&kwargs.quote).is_some() {
+                // A doubled quote stands for the quote itself
+                field.push(c);
+            } else {
+                in_quotes = false;
+            }
+            continue;
+        }
+        if c == kwargs.delimiter {
+            fields.push(if kwargs.trim && !was_quoted { field.trim().to_string() } else { field });
+            field = String::new();
+            was_quoted = false;
+        } else if c == kwargs.quote && !was_quoted && (field.is_empty() || kwargs.trim && field.trim().is_empty()) {
+            field.clear();
+            in_quotes = true;
+            was_quoted = true;
+        } else if !(kwargs.trim && was_quoted && c.is_whitespace()) {
+            field.push(c);
+        }
+    }
+    fields.push(if kwargs.trim && !was_quoted { field.trim().to_string() } else { field });
+    fields
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn parse_csv_line(inputs: &[Series], kwargs: CsvLineKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.delimiter != kwargs.quote, InvalidOperation: "delimiter and quote must differ");
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| parse_csv_fields(s, &kwargs))
+}
+
+// Byte length of the ANSI escape sequence at the start of the value, or zero if there is none
+fn ansi_escape_length(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return 0;
+    }
+    match bytes.get(1) {
+        // Control sequences like "\x1b[31m" end with a byte in the range of "@" to "~"
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(bytes.len(), |p| p + 3),
+        // Operating system commands like hyperlinks end with a bell or "\x1b\\"
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        },
+        Some(b) if b.is_ascii() => 2,
+        _ => 1,
+    }
+}
+
+// Splits a word into graphemes taking up a single column and ANSI escape sequences taking up none
+fn ansi_units(word: &str) -> Vec<(&str, usize)> {
+    let mut units = Vec::new();
+    let mut rest = word;
+    while !rest.is_empty() {
+        let length = ansi_escape_length(rest);
+        if length > 0 {
+            units.push((&rest[..length], 0));
+            rest = &rest[length..];
+            continue;
+        }
+        let grapheme = rest.graphemes(true).next().unwrap();
+        units.push((grapheme, 1));
+        rest = &rest[grapheme.len()..];
+    }
+    units
+}
+
+#[polars_expr(output_type=String)]
+fn word_wrap_ansi(inputs: &[Series], kwargs: WrapKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    polars_ensure!(kwargs.width > 0, InvalidOperation: "width must be greater than zero");
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        wrap_lines(value, &kwargs, ansi_units, output);
+    });
+    Ok(out.into_series())
+}
+
+enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+// Parses paths like "a.b[0].c", keys with dots or brackets in them can be quoted as in "a['b.c']"
+fn parse_json_path(path: &str) -> Option<Vec<JsonPathSegment>> {
+    let path = path.trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let chars: Vec<char> = path.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => i += 1,
+            '[' => {
+                let end = i + chars[i..].iter().position(|&c| c == ']')?;
+                let inner: String = chars[i + 1..end].iter().collect();
+                let inner = inner.trim();
+                let quoted = ['"', '\''].iter().find_map(|&q| inner.strip_prefix(q)?.strip_suffix(q));
+                let segment = match quoted {
+                    Some(key) => JsonPathSegment::Key(key.to_string()),
+                    None => JsonPathSegment::Index(inner.parse().ok()?),
+                };
+                segments.push(segment);
+                i = end + 1;
+            },
+            _ => {
+                let length = chars[i..].iter().position(|&c| c == '.' || c == '[').unwrap_or(chars.len() - i);
+                segments.push(JsonPathSegment::Key(chars[i..i + length].iter().collect()));
+                i += length;
+            },
+        }
+    }
+    Some(segments)
+}
+
+#[derive(Deserialize)]
+pub struct JsonExtractKwargs {
+    path: String,
+}
+
+#[polars_expr(output_type=String)]
+fn json_extract(inputs: &[Series], kwargs: JsonExtractKwargs) -> PolarsResult<Series> {
+    let Some(segments) = parse_json_path(&kwargs.path) else {
+        polars_bail!(InvalidOperation: "invalid JSON path {:?}", kwargs.path);
+    };
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let document: serde_json::Value = serde_json::from_str(opt_s?).ok()?;
+        let mut value = &document;
+        for segment in &segments {
+            value = match segment {
+                JsonPathSegment::Key(key) => value.get(key.as_str())?,
+                JsonPathSegment::Index(index) => value.get(*index)?,
+            };
+        }
+        // Strings are returned as they are, anything else as JSON
+        match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            _ => Some(value.to_string()),
+        }
+    });
+    Ok(out.into_series())
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && !year.is_multiple_of(100) || year.is_multiple_of(400)
+}
+
+fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+// Recognizes dates written as year-month-day, or day and month in either order before the year
+fn looks_like_date(value: &str) -> bool {
+    let Some(separator) = ['-', '/', '.'].into_iter().find(|&c| value.contains(c)) else {
+        return false;
+    };
+    let parts: Vec<&str> = value.split(separator).collect();
+    if parts.len() != 3 || !parts.iter().all(|p| !p.is_empty() && p.len() <= 4 && p.bytes().all(|b| b.is_ascii_digit())) {
+        return false;
+    }
+    let numbers: Vec<u32> = parts.iter().map(|p| p.parse().unwrap()).collect();
+    if parts[0].len() == 4 {
+        is_valid_date(numbers[0], numbers[1], numbers[2])
+    } else if parts[2].len() == 4 {
+        is_valid_date(numbers[2], numbers[1], numbers[0]) || is_valid_date(numbers[2], numbers[0], numbers[1])
+    } else {
+        false
+    }
+}
+
+fn is_full_match(regex: &Regex, value: &str) -> bool {
+    regex.find(value).is_some_and(|found| found.start() == 0 && found.end() == value.len())
+}
+
+#[polars_expr(output_type=String)]
+fn infer_value_type(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let value = opt_s?.trim();
+        // Blank values carry no type information
+        if value.is_empty() {
+            return None;
+        }
+        let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+        let label =
+            if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+                "integer"
+            } else if value.parse::<f64>().is_ok() && value.bytes().any(|b| b.is_ascii_digit()) {
+                "float"
+            } else if ["true", "false", "yes", "no"].iter().any(|b| value.eq_ignore_ascii_case(b)) {
+                "boolean"
+            } else if looks_like_date(value) {
+                "date"
+            } else if is_full_match(&EMAIL_REGEX, value) {
+                "email"
+            } else if is_full_match(&URL_REGEX, value) {
+                "url"
+            } else {
+                "text"
+            };
+        Some(label)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct TemplateKwargs {
+    strict: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn render_template(inputs: &[Series], kwargs: TemplateKwargs) -> PolarsResult<Series> {
+    let templates = inputs[0].str()?;
+    // Every other input provides the values of the placeholder named after it
+    let columns: Vec<Series> = inputs[1..]
+        .iter()
+        .map(|s| s.cast(&DataType::String).map(|s| s.rechunk()))
+        .collect::<PolarsResult<_>>()?;
+    let mut values: HashMap<&str, &StringChunked> = HashMap::new();
+    for column in &columns {
+        polars_ensure!(column.len() == 1 || column.len() == templates.len(),
+                       ShapeMismatch: "placeholder column {:?} does not match the length of the templates", column.name());
+        values.insert(column.name().as_str(), column.str()?);
+    }
+    let out: StringChunked = templates
+        .iter()
+        .enumerate()
+        .map(|(row, opt_template)| {
+            let template = opt_template?;
+            let mut output = String::with_capacity(template.len());
+            let mut rest = template;
+            while let Some(i) = rest.find(['{', '}']) {
+                output.push_str(&rest[..i]);
+                let tail = &rest[i..];
+                // Doubled braces stand for literal ones
+                if tail.starts_with("{{") || tail.starts_with("}}") {
+                    output.push_str(&tail[..1]);
+                    rest = &tail[2..];
+                    continue;
+                }
+                let placeholder = tail.strip_prefix('{').and_then(|t| {
+                    let end = t.find(['{', '}'])?;
+                    t[end..].starts_with('}').then(|| &t[..end])
+                });
+                let Some(name) = placeholder else {
+                    output.push_str(&tail[..1]);
+                    rest = &tail[1..];
+                    continue;
+                };
+                match values.get(name) {
+                    Some(column) => {
+                        let value = column.get(if column.len() == 1 { 0 } else { row });
+                        output.push_str(value.unwrap_or(""));
+                    },
+                    // Unknown placeholders are dropped in strict mode, otherwise kept as they are
+                    None if kwargs.strict => {},
+                    None => output.push_str(&tail[..name.len() + 2]),
+                }
+                rest = &tail[name.len() + 2..];
+            }
+            output.push_str(rest);
+            Some(output)
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CaseStyle {
+    Lower,
+    Upper,
+    Title,
+}
+
+fn to_case_style(value: &str, style: CaseStyle, output: &mut String) {
+    match style {
+        CaseStyle::Lower => output.push_str(&value.to_lowercase()),
+        CaseStyle::Upper => output.push_str(&value.to_uppercase()),
+        CaseStyle::Title => for_each_token(value, output, push_capitalized),
+    }
+}
+
+// Values without any cased letter do not follow any style
+fn case_style(value: &str) -> Option<CaseStyle> {
+    let mut cased = value.chars().filter(|c| c.is_lowercase() || c.is_uppercase()).peekable();
+    cased.peek()?;
+    if cased.clone().all(char::is_lowercase) {
+        return Some(CaseStyle::Lower);
+    }
+    if cased.clone().all(char::is_uppercase) {
+        return Some(CaseStyle::Upper);
+    }
+    let mut titled = String::with_capacity(value.len());
+    to_case_style(value, CaseStyle::Title, &mut titled);
+    (titled == value).then_some(CaseStyle::Title)
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_to_majority_case(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let styles = [CaseStyle::Lower, CaseStyle::Upper, CaseStyle::Title];
+    let mut counts = [0usize; 3];
+    for style in ca.into_iter().flatten().filter_map(case_style) {
+        counts[styles.iter().position(|&s| s == style).unwrap()] += 1;
+    }
+    // Leave the column untouched when no value follows any style
+    if counts.iter().all(|&count| count == 0) {
+        return Ok(ca.clone().into_series());
+    }
+    // Ties are settled in favour of the earlier style
+    let majority = styles[(0..styles.len()).min_by_key(|&i| Reverse(counts[i])).unwrap()];
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        to_case_style(value, majority, output);
+    });
+    Ok(out.into_series())
+}
+
+fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase()
+}
+
+// Whether the first cased letter is the only uppercase one
+fn is_capitalized_word(word: &str) -> bool {
+    let mut cased = word.chars().filter(|&c| is_cased(c));
+    cased.next().is_some_and(char::is_uppercase) && cased.all(char::is_lowercase)
+}
+
+fn detect_case_style(value: &str) -> &'static str {
+    let has_lowercase = value.chars().any(char::is_lowercase);
+    let has_uppercase = value.chars().any(char::is_uppercase);
+    // Values without any cased letter are ambiguous
+    if !has_lowercase && !has_uppercase {
+        return "mixed";
+    }
+    if !has_lowercase {
+        return "upper";
+    }
+
+    if value.contains(char::is_whitespace) {
+        if !has_uppercase {
+            return "lower";
+        }
+        // Words without any cased letter, e.g. numbers, do not affect the style
+        let words: Vec<&str> = value.split_whitespace().filter(|word| word.contains(is_cased)).collect();
+        if words.iter().all(|word| is_capitalized_word(word)) {
+            return "title";
+        }
+        if is_capitalized_word(words[0]) && !words[1..].iter().any(|word| word.contains(char::is_uppercase)) {
+            return "sentence";
+        }
+        return "mixed";
+    }
+
+    let is_word = |separator: char| value.chars().all(|c| c.is_alphanumeric() || c == separator);
+    if !has_uppercase {
+        return match (value.contains('_'), value.contains('-')) {
+            (true, false) if is_word('_') => "snake",
+            (false, true) if is_word('-') => "kebab",
+            (false, false) => "lower",
+            _ => "mixed",
+        };
+    }
+    if !value.chars().all(char::is_alphanumeric) {
+        return "mixed";
+    }
+    match value.chars().find(|&c| is_cased(c)) {
+        Some(first) if first.is_lowercase() => "camel",
+        _ if is_capitalized_word(value) => "title",
+        _ => "pascal",
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn detect_case(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca
+        .into_iter()
+        .map(|opt_s| opt_s.filter(|s| !s.is_empty()).map(detect_case_style))
+        .collect();
+    Ok(out.into_series())
+}
+
+// Splits an indented list marker such as "1.", "1)", "a)", "-", or "*" off the start of a line.
+// The marker must be followed by whitespace, which is dropped along with it.
+fn split_list_marker(line: &str) -> Option<(&str, &str)> {
+    let start = line.len() - line.trim_start().len();
+    let rest = &line[start..];
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let marker_length = match rest[digits..].chars().next()? {
+        '.' | ')' if (1..=9).contains(&digits) => digits + 1,
+        '-' | '*' | '+' | '•' if digits == 0 => rest.chars().next()?.len_utf8(),
+        c if digits == 0 && c.is_alphabetic() && rest[c.len_utf8()..].starts_with(')') => c.len_utf8() + 1,
+        _ => return None,
+    };
+    let text = &rest[marker_length..];
+    if !text.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some((&rest[..marker_length], text.trim_start()))
+}
+
+#[derive(Deserialize)]
+pub struct ListNumberingKwargs {
+    return_marker: bool,
+}
+
+fn list_numbering_output(_: &[Field], kwargs: ListNumberingKwargs) -> PolarsResult<Field> {
+    let dtype =
+        if kwargs.return_marker {
+            DataType::Struct(vec![
+                Field::new("text".into(), DataType::String),
+                Field::new("marker".into(), DataType::String),
+            ])
+        } else {
+            DataType::String
+        };
+    Ok(Field::new("".into(), dtype))
+}
+
+#[polars_expr(output_type_func_with_kwargs=list_numbering_output)]
+fn strip_list_numbering(inputs: &[Series], kwargs: ListNumberingKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut texts: Vec<Option<String>> = Vec::with_capacity(ca.len());
+    let mut markers: Vec<Option<String>> = Vec::with_capacity(ca.len());
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            texts.push(None);
+            markers.push(None);
+            continue;
+        };
+        let mut text = String::with_capacity(s.len());
+        // Markers are reported one per line, empty for lines without any
+        let mut line_markers: Vec<&str> = Vec::new();
+        for line in s.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            match split_list_marker(content) {
+                Some((marker, stripped)) => {
+                    text.push_str(stripped);
+                    line_markers.push(marker);
+                },
+                None => {
+                    text.push_str(content);
+                    line_markers.push("");
+                },
+            }
+            text.push_str(&line[content.len()..]);
+        }
+        texts.push(Some(text));
+        let has_marker = line_markers.iter().any(|marker| !marker.is_empty());
+        markers.push(has_marker.then(|| line_markers.join("\n")));
+    }
+
+    let texts = Series::new("text".into(), texts);
+    if !kwargs.return_marker {
+        return Ok(texts);
+    }
+    let fields = [texts, Series::new("marker".into(), markers)];
+    let out = StructChunked::from_series("".into(), ca.len(), fields.iter())?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct CharFreqKwargs {
+    case_insensitive: bool,
+    ignore_whitespace: bool,
+}
+
+fn char_frequency_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("char".into(), DataType::String),
+            Field::new("count".into(), DataType::UInt32),
+        ]))),
+    ))
+}
+
+#[polars_expr(output_type_func=char_frequency_output)]
+fn char_frequency(inputs: &[Series], kwargs: CharFreqKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let dtype = char_frequency_output(&[])?.dtype().inner_dtype().unwrap().clone();
+    let mut builder = get_list_builder(&dtype, ca.len(), ca.len(), "".into());
+    for opt_s in ca.into_iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        let folded = if kwargs.case_insensitive { s.to_lowercase() } else { s.to_string() };
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for grapheme in folded.graphemes(true) {
+            if kwargs.ignore_whitespace && grapheme.chars().all(char::is_whitespace) {
+                continue;
+            }
+            *counts.entry(grapheme).or_insert(0) += 1;
+        }
+        let mut frequencies: Vec<(&str, u32)> = counts.into_iter().collect();
+        frequencies.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        let fields = [
+            Series::new("char".into(), frequencies.iter().map(|&(grapheme, _)| grapheme).collect::<Vec<_>>()),
+            Series::new("count".into(), frequencies.iter().map(|&(_, count)| count).collect::<Vec<_>>()),
+        ];
+        let rows = StructChunked::from_series("".into(), fields[0].len(), fields.iter())?;
+        builder.append_series(&rows.into_series())?;
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ListItemsKwargs {
+    preserve_depth: bool,
+}
+
+fn indentation_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+// Infers the nesting depth of list items from their indentation
+#[derive(Default)]
+struct ListDepth {
+    // The indentation of each enclosing level, the innermost last
+    levels: Vec<usize>,
+}
+
+impl ListDepth {
+    fn depth(&mut self, indentation: usize) -> usize {
+        while self.levels.last().is_some_and(|&level| level > indentation) {
+            self.levels.pop();
+        }
+        if self.levels.last() != Some(&indentation) {
+            self.levels.push(indentation);
+        }
+        self.levels.len() - 1
+    }
+}
+
+fn list_items(value: &str, kwargs: &ListItemsKwargs) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    let mut nesting = ListDepth::default();
+    let mut in_item = false;
+    for line in value.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indentation = indentation_width(line);
+        let Some((_, text)) = split_list_marker(line) else {
+            // Indented lines continue the previous item, anything else ends the list
+            match items.last_mut() {
+                Some(item) if in_item && indentation > 0 => {
+                    item.push(' ');
+                    item.push_str(line.trim());
+                },
+                _ => {
+                    in_item = false;
+                    nesting = ListDepth::default();
+                },
+            }
+            continue;
+        };
+        let depth = if kwargs.preserve_depth { nesting.depth(indentation) } else { 0 };
+        items.push(format!("{}{}", "  ".repeat(depth), text.trim_end()));
+        in_item = true;
+    }
+    items
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_list_items(inputs: &[Series], kwargs: ListItemsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| list_items(s, &kwargs))
+}
+
+#[derive(Deserialize)]
+pub struct EntropyKwargs {
+    base: f64,
+}
+
+#[polars_expr(output_type=Float64)]
+fn shannon_entropy(inputs: &[Series], kwargs: EntropyKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.base > 0.0 && kwargs.base != 1.0, InvalidOperation: "base must be positive and other than one");
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for c in opt_s?.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+        let total = counts.values().sum::<usize>() as f64;
+        let entropy = counts
+            .values()
+            .map(|&count| {
+                let probability = count as f64 / total;
+                -probability * probability.log(kwargs.base)
+            })
+            .sum::<f64>();
+        // Avoid reporting a negative zero for empty strings and those made of a single character
+        Some(entropy.abs())
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ReplaceCiKwargs {
+    pattern: String,
+    replacement: String,
+}
+
+// Matches the pattern literally, only its case is ignored
+fn case_insensitive_literal(pattern: &str) -> PolarsResult<Regex> {
+    polars_ensure!(!pattern.is_empty(), InvalidOperation: "pattern must not be empty");
+    Regex::new(&format!("(?i){}", regex::escape(pattern)))
+        .map_err(|e| polars_err!(ComputeError: "invalid pattern {:?}: {}", pattern, e))
+}
+
+#[polars_expr(output_type=String)]
+fn replace_all_ci(inputs: &[Series], kwargs: ReplaceCiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let regex = case_insensitive_literal(&kwargs.pattern)?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.push_str(&regex.replace_all(value, NoExpand(&kwargs.replacement)));
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_valid_email(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        // Length limits as per RFC 5321
+        opt_s.map(|value| {
+            is_full_match(&EMAIL_REGEX, value)
+                && value.len() <= 254
+                && value.split_once('@').is_some_and(|(local, _)| local.len() <= 64)
+        })
+    });
+    Ok(out.into_series())
+}
+
+fn is_valid_hostname(host: &str) -> bool {
+    host.len() <= 253
+        && host.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+}
+
+fn is_valid_port(port: &str) -> bool {
+    port.chars().all(|c| c.is_ascii_digit()) && port.parse::<u16>().is_ok()
+}
+
+fn is_valid_url_value(value: &str, allowed_schemes: &[String]) -> bool {
+    if !is_full_match(&URL_REGEX, value) {
+        return false;
+    }
+    let (scheme, rest) = value.split_once("://").unwrap();
+    if !allowed_schemes.iter().any(|allowed| allowed.eq_ignore_ascii_case(scheme)) {
+        return false;
+    }
+    let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+    // IPv6 addresses are enclosed in brackets to keep their colons apart from the port
+    if let Some(bracketed) = host_port.strip_prefix('[') {
+        let Some((address, port)) = bracketed.split_once(']') else {
+            return false;
+        };
+        return address.parse::<Ipv6Addr>().is_ok()
+            && (port.is_empty() || port.strip_prefix(':').is_some_and(is_valid_port));
+    }
+    let host = match host_port.split_once(':') {
+        Some((host, port)) if is_valid_port(port) => host,
+        Some(_) => return false,
+        None => host_port,
+    };
+    host.parse::<Ipv4Addr>().is_ok() || is_valid_hostname(host)
+}
+
+#[derive(Deserialize)]
+pub struct ValidateKwargs {
+    allowed_schemes: Vec<String>,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_valid_url(inputs: &[Series], kwargs: ValidateKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: BooleanChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| is_valid_url_value(value, &kwargs.allowed_schemes))
+    });
+    Ok(out.into_series())
+}
+
+// Mimics the case of the matched text, whose letters are either all lowercase, all uppercase, or
+// only capitalized; a replacement for anything else is inserted as given
+fn push_with_case_of(matched: &str, replacement: &str, output: &mut String) {
+    let cased: Vec<char> = matched.chars().filter(|&c| is_cased(c)).collect();
+    match cased.split_first() {
+        Some((first, rest)) if first.is_uppercase() && rest.iter().all(|c| c.is_lowercase()) => {
+            push_capitalized(replacement, output);
+        },
+        _ if !cased.is_empty() && cased.iter().all(|c| c.is_lowercase()) => output.push_str(&replacement.to_lowercase()),
+        _ if !cased.is_empty() && cased.iter().all(|c| c.is_uppercase()) => output.push_str(&replacement.to_uppercase()),
+        _ => output.push_str(replacement),
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn replace_preserving_case(inputs: &[Series], kwargs: ReplaceCiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let regex = case_insensitive_literal(&kwargs.pattern)?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut last = 0;
+        for found in regex.find_iter(value) {
+            output.push_str(&value[last..found.start()]);
+            push_with_case_of(found.as_str(), &kwargs.replacement, output);
+            last = found.end();
+        }
+        output.push_str(&value[last..]);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct PartialMaskKwargs {
+    keep_start: usize,
+    keep_end: usize,
+    mask_char: char,
+    keep_short: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn partial_mask(inputs: &[Series], kwargs: PartialMaskKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let graphemes: Vec<&str> = value.graphemes(true).collect();
+        // Strings too short to keep both ends visible are masked entirely, unless requested otherwise
+        if graphemes.len() < kwargs.keep_start + kwargs.keep_end {
+            if kwargs.keep_short {
+                output.push_str(value);
+            } else {
+                output.extend(std::iter::repeat_n(kwargs.mask_char, graphemes.len()));
+            }
+            return;
+        }
+        let end = graphemes.len() - kwargs.keep_end;
+        output.extend(graphemes[..kwargs.keep_start].iter().copied());
+        output.extend(std::iter::repeat_n(kwargs.mask_char, end - kwargs.keep_start));
+        output.extend(graphemes[end..].iter().copied());
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ReindentListKwargs {
+    indent_width: usize,
+}
+
+#[polars_expr(output_type=String)]
+fn reindent_list(inputs: &[Series], kwargs: ReindentListKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut nesting = ListDepth::default();
+        // The depth of the last item, if still within a list
+        let mut item_depth: Option<usize> = None;
+        for line in value.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            let indentation = indentation_width(content);
+            let level =
+                if split_list_marker(content).is_some() {
+                    let level = nesting.depth(indentation);
+                    item_depth = Some(level);
+                    Some(level)
+                } else if content.trim().is_empty() {
+                    None
+                } else if indentation > 0 {
+                    // Indented lines continue the previous item, one level deeper than its marker
+                    item_depth.map(|level| level + 1)
+                } else {
+                    nesting = ListDepth::default();
+                    item_depth = None;
+                    None
+                };
+            match level {
+                Some(level) => {
+                    output.extend(std::iter::repeat_n(' ', level * kwargs.indent_width));
+                    output.push_str(content.trim_start());
+                },
+                None => output.push_str(content),
+            }
+            output.push_str(&line[content.len()..]);
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn swap_case(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        // Follows the Unicode default mappings, so "ß" becomes "SS" and a dotted "İ" becomes "i" with a combining dot
+        for c in value.chars() {
+            if c.is_lowercase() {
+                output.extend(c.to_uppercase());
+            } else if c.is_uppercase() {
+                output.extend(c.to_lowercase());
+            } else {
+                output.push(c);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+// ISO 4217 code, symbol, and the words spelling out each currency
+const CURRENCIES: &[(&str, char, &[&str])] = &[
+    ("USD", '$', &["dollar", "dollars"]),
+    ("EUR", '€', &["euro", "euros"]),
+    ("GBP", '£', &["pound sterling", "pounds sterling", "pound", "pounds"]),
+    ("JPY", '¥', &["yen"]),
+    ("INR", '₹', &["rupee", "rupees"]),
+    ("KRW", '₩', &[]),
+    ("RUB", '₽', &["ruble", "rubles", "rouble", "roubles"]),
+    ("NGN", '₦', &["naira"]),
+    ("THB", '฿', &["baht"]),
+    ("VND", '₫', &["dong"]),
+    ("ILS", '₪', &["shekel", "shekels"]),
+    ("UAH", '₴', &["hryvnia", "hryvnias"]),
+    ("BTC", '₿', &["bitcoin", "bitcoins"]),
+];
+
+// Codes are matched in uppercase only, words in any case. An amount right after is captured too.
+static CURRENCY_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    let mut codes: Vec<&str> = CURRENCIES.iter().map(|&(code, _, _)| code).collect();
+    let mut words: Vec<&str> = CURRENCIES.iter().flat_map(|&(_, _, words)| words.iter().copied()).collect();
+    // Prefer the longest alternative, e.g. "pounds sterling" over "pounds"
+    codes.sort_by_key(|code| Reverse(code.len()));
+    words.sort_by_key(|word| Reverse(word.len()));
+    Regex::new(&format!(r"\b({}|(?i:{}))\b(\s+\d)?", codes.join("|"), words.join("|"))).unwrap()
+});
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurrencyDirection {
+    ToSymbol,
+    ToCode,
+}
+
+#[derive(Deserialize)]
+pub struct CurrencyKwargs {
+    direction: CurrencyDirection,
+}
+
+fn currency_symbol(name: &str) -> char {
+    CURRENCIES
+        .iter()
+        .find(|&&(code, _, words)| code == name || words.iter().any(|word| word.eq_ignore_ascii_case(name)))
+        .map(|&(_, symbol, _)| symbol)
+        .unwrap()
+}
+
+fn currency_code(symbol: char) -> Option<&'static str> {
+    CURRENCIES.iter().find(|&&(_, s, _)| s == symbol).map(|&(code, _, _)| code)
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_currency(inputs: &[Series], kwargs: CurrencyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        match kwargs.direction {
+            CurrencyDirection::ToSymbol => {
+                let replaced = CURRENCY_NAME_REGEX.replace_all(value, |c: &regex::Captures| {
+                    let symbol = currency_symbol(&c[1]);
+                    // The symbol is attached to the amount following it, e.g. "USD 5" becomes "$5"
+                    match c.get(2) {
+                        Some(amount) => format!("{}{}", symbol, amount.as_str().trim_start()),
+                        None => symbol.to_string(),
+                    }
+                });
+                output.push_str(&replaced);
+            },
+            CurrencyDirection::ToCode => {
+                let mut chars = value.chars().peekable();
+                while let Some(c) = chars.next() {
+                    let Some(code) = currency_code(c) else {
+                        output.push(c);
+                        continue;
+                    };
+                    // Keep the code apart from the amount, e.g. "5€" becomes "5 EUR"
+                    if output.ends_with(char::is_alphanumeric) {
+                        output.push(' ');
+                    }
+                    output.push_str(code);
+                    if chars.peek().is_some_and(|next| next.is_alphanumeric()) {
+                        output.push(' ');
+                    }
+                }
+            },
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct CapitalizeKwargs {
+    only_first_letter: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn capitalize_words(inputs: &[Series], kwargs: CapitalizeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for_each_token(value, output, |token, output| {
+            if !kwargs.only_first_letter {
+                push_capitalized(token, output);
+                return;
+            }
+            // Leave the rest of the word as it is
+            match token.char_indices().find(|(_, c)| c.is_alphabetic()) {
+                Some((i, c)) => {
+                    output.push_str(&token[..i]);
+                    output.extend(c.to_uppercase());
+                    output.push_str(&token[i + c.len_utf8()..]);
+                },
+                None => output.push_str(token),
+            }
+        });
+    });
+    Ok(out.into_series())
+}
+
+// Estimates the syllables of an English word by counting its vowel groups, with the usual
+// exceptions for a silent trailing "e", "ed", or "es". Words without any letter have none.
+fn estimate_syllables(word: &str) -> u32 {
+    let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).map(|c| c.to_ascii_lowercase()).collect();
+    if letters.is_empty() {
+        return 0;
+    }
+    // A "y" sounds like a vowel unless it starts the word
+    let sounds_vowel = |i: usize| is_vowel(letters[i]) || (letters[i] == 'y' && i > 0);
+    let mut count = 0;
+    for i in 0..letters.len() {
+        if sounds_vowel(i) && (i == 0 || !sounds_vowel(i - 1)) {
+            count += 1;
+        }
+    }
+
+    let n = letters.len();
+    let is_silent_ending = match letters[n.saturating_sub(2)..] {
+        // Except in a consonant followed by "le", as in "table"
+        [before, 'e'] if !sounds_vowel(n - 2) => before != 'l' || n < 3 || sounds_vowel(n - 3),
+        ['e', 'd'] => n >= 3 && !sounds_vowel(n - 3) && !matches!(letters[n - 3], 't' | 'd'),
+        // Except after a sibilant, as in "boxes" or "watches"
+        ['e', 's'] if n >= 3 => {
+            let stem = &letters[..n - 2];
+            let after_sibilant = matches!(stem[n - 3], 's' | 'x' | 'z' | 'c' | 'g') || stem.ends_with(&['s', 'h']) || stem.ends_with(&['c', 'h']);
+            !sounds_vowel(n - 3) && !after_sibilant
+        },
+        _ => false,
+    };
+    if is_silent_ending && count > 1 {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+fn syllable_counts_per_word(ca: &StringChunked) -> Series {
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new("".into(), ca.len(), 0, DataType::UInt32);
+    for opt_s in ca.into_iter() {
+        match opt_s {
+            Some(s) => {
+                let counts: Vec<u32> = s.unicode_words().map(estimate_syllables).collect();
+                builder.append_slice(&counts);
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish().into_series()
+}
+
+#[polars_expr(output_type_func=list_uint32_output)]
+fn syllables_per_word(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    Ok(syllable_counts_per_word(ca))
+}
+
+#[derive(Deserialize)]
+pub struct ReverseWordsKwargs {
+    preserve_whitespace: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn reverse_words(inputs: &[Series], kwargs: ReverseWordsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        if !kwargs.preserve_whitespace {
+            output.push_str(&value.split_whitespace().rev().collect::<Vec<_>>().join(" "));
+            return;
+        }
+        // The runs of whitespace stay where they are, only the words in between swap places
+        let mut words = value.split_whitespace().rev();
+        for_each_token(value, output, |_, output| output.push_str(words.next().unwrap()));
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct PartitionKwargs {
+    separator: String,
+    from_end: bool,
+}
+
+fn partition_output(_: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        "".into(),
+        DataType::Struct(vec![
+            Field::new("before".into(), DataType::String),
+            Field::new("separator".into(), DataType::String),
+            Field::new("after".into(), DataType::String),
+        ]),
+    ))
+}
+
+#[polars_expr(output_type_func=partition_output)]
+fn partition(inputs: &[Series], kwargs: PartitionKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.separator.is_empty(), InvalidOperation: "separator must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut befores: Vec<&str> = Vec::with_capacity(ca.len());
+    let mut separators: Vec<&str> = Vec::with_capacity(ca.len());
+    let mut afters: Vec<&str> = Vec::with_capacity(ca.len());
+    for s in ca.into_iter().map(Option::unwrap_or_default) {
+        let found =
+            if kwargs.from_end {
+                s.rfind(&kwargs.separator)
+            } else {
+                s.find(&kwargs.separator)
+            };
+        // Without the separator, the whole string goes before it whichever end it is searched from
+        let (before, separator, after) = match found {
+            Some(i) => (&s[..i], &kwargs.separator[..], &s[i + kwargs.separator.len()..]),
+            None => (s, "", ""),
+        };
+        befores.push(before);
+        separators.push(separator);
+        afters.push(after);
+    }
+
+    let fields = [
+        Series::new("before".into(), befores),
+        Series::new("separator".into(), separators),
+        Series::new("after".into(), afters),
+    ];
+    let validity = ca.is_not_null().rechunk().downcast_as_array().values().clone();
+    let out = StructChunked::from_series("".into(), ca.len(), fields.iter())?.with_outer_validity(Some(validity));
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NonLetterMode {
+    Skip,
+    Advance,
+    Reset,
+}
+
+#[derive(Deserialize)]
+pub struct AlternatingCaseKwargs {
+    start_upper: bool,
+    non_letters: NonLetterMode,
+}
+
+#[polars_expr(output_type=String)]
+fn alternating_case(inputs: &[Series], kwargs: AlternatingCaseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut upper = kwargs.start_upper;
+        for c in value.chars() {
+            if c.is_alphabetic() {
+                if upper {
+                    output.extend(c.to_uppercase());
+                } else {
+                    output.extend(c.to_lowercase());
+                }
+                upper = !upper;
+                continue;
+            }
+            output.push(c);
+            // Other characters may take a turn, or start the pattern over, e.g. for each word
+            match kwargs.non_letters {
+                NonLetterMode::Skip => {},
+                NonLetterMode::Advance => upper = !upper,
+                NonLetterMode::Reset => upper = kwargs.start_upper,
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+// Marks and embeddings controlling the direction of bidirectional text
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+#[derive(Deserialize)]
+pub struct StripInvisibleKwargs {
+    keep_newlines: bool,
+    keep_tabs: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_invisible(inputs: &[Series], kwargs: StripInvisibleKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut previous: Option<char> = None;
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            // Joiners between two emoji are part of the glyph they build up
+            let is_emoji_joiner = c == '\u{200d}'
+                && previous.is_some_and(is_emoji_component)
+                && chars.peek().is_some_and(|&n| is_emoji_component(n));
+            let is_kept_control = match c {
+                '\n' | '\r' => kwargs.keep_newlines,
+                '\t' => kwargs.keep_tabs,
+                _ => false,
+            };
+            let is_invisible = (is_zero_width(c) && !is_emoji_joiner) || is_bidi_control(c) || (c.is_control() && !is_kept_control);
+            if !is_invisible {
+                output.push(c);
+                previous = Some(c);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ProperNounsKwargs {
+    proper_nouns: Vec<String>,
+}
+
+#[polars_expr(output_type=String)]
+fn lowercase_except_proper(inputs: &[Series], kwargs: ProperNounsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    // Proper nouns are matched in any case, then spelled as given
+    let proper_nouns: HashMap<String, &str> = kwargs
+        .proper_nouns
+        .iter()
+        .map(|noun| (noun.to_lowercase(), noun.as_str()))
+        .collect();
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut sentence_start = true;
+        for_each_token(value, output, |token, output| {
+            // Surrounding punctuation is left out of the word, e.g. in "(Paris),"
+            let start = token.find(char::is_alphanumeric).unwrap_or(token.len());
+            let word = token[start..].trim_end_matches(|c: char| !c.is_alphanumeric());
+            let end = start + word.len();
+            output.push_str(&token[..start]);
+            match proper_nouns.get(&word.to_lowercase()) {
+                Some(noun) => output.push_str(noun),
+                None if sentence_start => push_capitalized(word, output),
+                None => output.push_str(&word.to_lowercase()),
+            }
+            output.push_str(&token[end..]);
+
+            let ends_sentence = token.trim_end_matches(is_sentence_closer).ends_with(['.', '!', '?', '…']);
+            if !word.is_empty() || ends_sentence {
+                sentence_start = ends_sentence;
+            }
+        });
+    });
+    Ok(out.into_series())
+}
+
+// The Unicode name of the script a character belongs to, telling the Japanese kana apart from Han
+fn script_name(c: char, script: Script) -> &'static str {
+    match script {
+        Script::Latin => "Latin",
+        Script::Greek => "Greek",
+        Script::Cyrillic => "Cyrillic",
+        Script::Armenian => "Armenian",
+        Script::Hebrew => "Hebrew",
+        Script::Arabic => "Arabic",
+        Script::Devanagari => "Devanagari",
+        Script::Thai => "Thai",
+        Script::Hangul => "Hangul",
+        Script::Cjk => match c {
+            '\u{3040}'..='\u{309f}' => "Hiragana",
+            '\u{30a0}'..='\u{30ff}' => "Katakana",
+            _ => "Han",
+        },
+        Script::Other => "Unknown",
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DetectScriptKwargs {
+    include_common: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn detect_script(inputs: &[Series], kwargs: DetectScriptKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for c in opt_s?.chars() {
+                let name = match char_script(c) {
+                    Some(script) => script_name(c, script),
+                    // Punctuation, digits and symbols, leaving out whitespace and combining marks
+                    None if kwargs.include_common && !c.is_whitespace() && !is_combining_mark(c) => "Common",
+                    None => continue,
+                };
+                *counts.entry(name).or_insert(0) += 1;
+            }
+            let most = *counts.values().max()?;
+            // A tie between scripts has no dominant one
+            let mut dominant = counts.into_iter().filter(|&(_, count)| count == most);
+            match (dominant.next(), dominant.next()) {
+                (Some((name, _)), None) => Some(name),
+                _ => None,
+            }
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+// A word broken by a hyphen at the end of a line and continued in lowercase on the next one
+static LINE_BREAK_HYPHEN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\p{L})-[ \t]*\r?\n[ \t]*(\p{Ll})").unwrap()
+});
+
+#[polars_expr(output_type=String)]
+fn dehyphenate(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.push_str(&LINE_BREAK_HYPHEN_REGEX.replace_all(value, "$1$2"));
+    });
+    Ok(out.into_series())
+}
+
+// At least four letters spaced out one by one, e.g. "h e l l o"
+static SPACED_LETTERS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b\p{L}(?: \p{L}){3,}\b").unwrap()
+});
+
+// Characters OCR tends to confuse between letters and digits
+fn is_ocr_confusable(c: char) -> bool {
+    matches!(c, '0' | '1' | 'O' | 'o' | 'l' | 'I')
+}
+
+// Reads confusable characters as digits within numbers and as letters within words, a word made of
+// confusable characters only is ambiguous and left alone
+fn fix_ocr_confusions(word: &str, dictionary: &HashSet<String>) -> String {
+    let has_letters = word.chars().any(|c| c.is_alphabetic() && !is_ocr_confusable(c));
+    let has_digits = word.chars().any(|c| c.is_ascii_digit() && !is_ocr_confusable(c));
+    if has_digits && !has_letters {
+        return word.chars().map(|c| match c {
+            'O' | 'o' => '0',
+            'l' | 'I' => '1',
+            _ => c,
+        }).collect();
+    }
+    if !has_letters || has_digits {
+        return word.to_string();
+    }
+    let uppercase = word.chars().filter(|c| c.is_alphabetic() && !is_ocr_confusable(*c)).all(char::is_uppercase);
+    dictionary_reading(word, dictionary, |c| {
+        let readings: &[char] = match (c, uppercase) {
+            ('0', false) => &['o'],
+            ('0', true) => &['O'],
+            ('1', false) => &['l', 'i'],
+            ('1', true) => &['I', 'L'],
+            _ => return None,
+        };
+        Some(readings)
+    })
+}
+
+#[derive(Deserialize)]
+pub struct OcrCleanupKwargs {
+    dehyphenate: bool,
+    collapse_spaced_letters: bool,
+    normalize_quotes: bool,
+    fix_confusions: bool,
+    dictionary: Vec<String>,
+}
+
+#[polars_expr(output_type=String)]
+fn ocr_cleanup(inputs: &[Series], kwargs: OcrCleanupKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let dictionary: HashSet<String> = kwargs.dictionary.iter().map(|word| word.to_lowercase()).collect();
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut text = value.to_string();
+        if kwargs.dehyphenate {
+            text = LINE_BREAK_HYPHEN_REGEX.replace_all(&text, "$1$2").into_owned();
+        }
+        if kwargs.collapse_spaced_letters {
+            text = SPACED_LETTERS_REGEX
+                .replace_all(&text, |c: &regex::Captures| c[0].replace(' ', ""))
+                .into_owned();
+        }
+        if kwargs.normalize_quotes {
+            text = text
+                .chars()
+                .map(|c| match confusable_char(c) {
+                    Some(quote @ ('\'' | '"')) => quote,
+                    _ => c,
+                })
+                .collect();
+        }
+        if kwargs.fix_confusions {
+            let mut fixed = String::with_capacity(text.len());
+            for_each_token(&text, &mut fixed, |token, output| {
+                // Surrounding punctuation is left out of the word
+                let start = token.find(char::is_alphanumeric).unwrap_or(token.len());
+                let word = token[start..].trim_end_matches(|c: char| !c.is_alphanumeric());
+                output.push_str(&token[..start]);
+                output.push_str(&fix_ocr_confusions(word, &dictionary));
+                output.push_str(&token[start + word.len()..]);
+            });
+            text = fixed;
+        }
+        output.push_str(&text);
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=UInt32)]
+fn grapheme_length(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: UInt32Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| value.graphemes(true).count() as u32)
+    });
+    Ok(out.into_series())
+}
+
+// The terminal columns taken up by a grapheme, being as wide as its widest character. This keeps
+// combining marks and emoji joined into a sequence from adding up.
+fn grapheme_display_width(grapheme: &str) -> usize {
+    grapheme.chars().map(|c| c.width().unwrap_or(0)).max().unwrap_or(0)
+}
+
+#[polars_expr(output_type=UInt32)]
+fn display_width(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: UInt32Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|value| value.graphemes(true).map(grapheme_display_width).sum::<usize>() as u32)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct WrapIndentKwargs {
+    width: usize,
+    break_long_words: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn word_wrap_preserve_indent(inputs: &[Series], kwargs: WrapIndentKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    polars_ensure!(kwargs.width > 0, InvalidOperation: "width must be greater than zero");
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for (i, line) in value.split('\n').enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            if line.trim().is_empty() {
+                output.push_str(line);
+                continue;
+            }
+            let text = line.trim_start();
+            let indent = &line[..line.len() - text.len()];
+            // The indentation counts towards the width, but always leaves room for some text
+            let line_kwargs = WrapKwargs {
+                width: kwargs.width.saturating_sub(indentation_width(indent)).max(1),
+                break_long_words: kwargs.break_long_words,
+                preserve_newlines: false,
+            };
+            let mut wrapped = Vec::new();
+            wrap_paragraph(text, &line_kwargs, grapheme_units, &mut wrapped);
+            for (j, wrapped_line) in wrapped.iter().enumerate() {
+                if j > 0 {
+                    output.push('\n');
+                }
+                output.push_str(indent);
+                output.push_str(wrapped_line);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ChunkUnit {
+    Grapheme,
+    Char,
+    Byte,
+}
+
+#[derive(Deserialize)]
+pub struct ChunkKwargs {
+    size: usize,
+    unit: ChunkUnit,
+    // Whether a byte chunk ending inside a character is an error rather than cut short
+    strict: bool,
+}
+
+// Cuts each byte chunk at the last character boundary within it, a chunk only goes beyond the size
+// when a single character does not fit into it
+fn byte_chunks(value: &str, size: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < value.len() {
+        let mut end = (start + size).min(value.len());
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == start {
+            end = start + value[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        chunks.push(value[start..end].to_string());
+        start = end;
+    }
+    chunks
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn chunk(inputs: &[Series], kwargs: ChunkKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    polars_ensure!(kwargs.size > 0, InvalidOperation: "size must be greater than zero");
+    if matches!(kwargs.unit, ChunkUnit::Byte) && kwargs.strict {
+        let splits_char = ca.into_iter().flatten().any(|value| {
+            (kwargs.size..value.len()).step_by(kwargs.size).any(|end| !value.is_char_boundary(end))
+        });
+        polars_ensure!(!splits_char, ComputeError: "byte chunks of size {} would split a character", kwargs.size);
+    }
+    build_list_string_series(ca, |s| {
+        match kwargs.unit {
+            ChunkUnit::Grapheme => s.graphemes(true).collect::<Vec<_>>().chunks(kwargs.size).map(|c| c.concat()).collect(),
+            ChunkUnit::Char => s.chars().collect::<Vec<_>>().chunks(kwargs.size).map(|c| c.iter().collect()).collect(),
+            ChunkUnit::Byte => byte_chunks(s, kwargs.size),
+        }
+    })
+}
+
+#[derive(Deserialize)]
+pub struct HighlightMultiKwargs {
+    keywords: Vec<String>,
+    prefix: String,
+    suffix: String,
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn highlight_keywords(inputs: &[Series], kwargs: HighlightMultiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut keywords: Vec<String> = kwargs.keywords.iter()
+        .filter(|keyword| !keyword.is_empty())
+        .map(|keyword| regex::escape(keyword))
+        .collect();
+    if keywords.is_empty() {
+        return Ok(ca.clone().into_series());
+    }
+    // Prefer the longest keyword where several of them match at the same position
+    keywords.sort_by_key(|keyword| Reverse(keyword.len()));
+    let flags = if kwargs.case_insensitive { "(?i)" } else { "" };
+    let regex = Regex::new(&format!("{}(?:{})", flags, keywords.join("|")))
+        .map_err(|e| polars_err!(ComputeError: "invalid keywords: {}", e))?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut last = 0;
+        for m in regex.find_iter(value) {
+            output.push_str(&value[last..m.start()]);
+            output.push_str(&kwargs.prefix);
+            output.push_str(m.as_str());
+            output.push_str(&kwargs.suffix);
+            last = m.end();
+        }
+        output.push_str(&value[last..]);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct HumanizeKwargs {
+    binary: bool,
+    precision: usize,
+}
+
+fn humanize_byte_count(n: i64, kwargs: &HumanizeKwargs) -> String {
+    let (base, units) =
+        if kwargs.binary {
+            (1024.0, ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"])
+        } else {
+            (1000.0, ["B", "kB", "MB", "GB", "TB", "PB", "EB"])
+        };
+    let sign = if n < 0 { "-" } else { "" };
+    let magnitude = n.unsigned_abs();
+    if (magnitude as f64) < base {
+        return format!("{sign}{magnitude} B");
+    }
+    let scale = 10f64.powi(kwargs.precision as i32);
+    let mut value = magnitude as f64;
+    let mut unit = 0;
+    // Moves on to the next unit also when rounding would show a full one, e.g. "1024.0 KiB"
+    while unit + 1 < units.len() && (value * scale).round() / scale >= base {
+        value /= base;
+        unit += 1;
+    }
+    format!("{sign}{value:.precision$} {}", units[unit], precision = kwargs.precision)
+}
+
+#[polars_expr(output_type=String)]
+fn humanize_bytes(inputs: &[Series], kwargs: HumanizeKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_n: Option<i64>| {
+        opt_n.map(|n| humanize_byte_count(n, &kwargs))
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=UInt32)]
+fn factorize(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    // Codes are given out in the order the values first appear
+    let mut codes: HashMap<&str, u32> = HashMap::new();
+    let out: UInt32Chunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            let next_code = codes.len() as u32;
+            opt_s.map(|value| *codes.entry(value).or_insert(next_code))
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum DurationUnit {
+    Seconds,
+    Milliseconds,
+}
+
+#[derive(Deserialize)]
+pub struct DurationKwargs {
+    unit: DurationUnit,
+    compact: bool,
+}
+
+// The parts of a duration from the largest down, as milliseconds with a compact and a long label
+const DURATION_PARTS: [(u64, &str, &str); 5] = [
+    (86_400_000, "d", "day"),
+    (3_600_000, "h", "hour"),
+    (60_000, "m", "minute"),
+    (1_000, "s", "second"),
+    (1, "ms", "millisecond"),
+];
+
+fn humanize_duration_value(n: i64, kwargs: &DurationKwargs) -> String {
+    let (mut remaining, smallest) =
+        if kwargs.unit == DurationUnit::Seconds {
+            (n.unsigned_abs() as u128 * 1_000, 1_000)
+        } else {
+            (n.unsigned_abs() as u128, 1)
+        };
+    let mut parts: Vec<String> = Vec::new();
+    for &(size, short, long) in DURATION_PARTS.iter().filter(|&&(size, _, _)| size >= smallest) {
+        let count = remaining / size as u128;
+        remaining %= size as u128;
+        // A zero duration is still spelled out in the smallest unit
+        if count == 0 && !(parts.is_empty() && size == smallest) {
+            continue;
+        }
+        parts.push(
+            if kwargs.compact {
+                format!("{count}{short}")
+            } else if count == 1 {
+                format!("{count} {long}")
+            } else {
+                format!("{count} {long}s")
+            });
+    }
+    let sign = if n < 0 { "-" } else { "" };
+    format!("{sign}{}", parts.join(" "))
+}
+
+#[polars_expr(output_type=String)]
+fn humanize_duration(inputs: &[Series], kwargs: DurationKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_n: Option<i64>| {
+        opt_n.map(|n| humanize_duration_value(n, &kwargs))
+    });
+    Ok(out.into_series())
+}
+
+static DURATION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\s*(-)?\s*((?:\d+(?:\.\d+)?|\.\d+)\s*[a-z]+(?:\s*,\s*|\s+and\s+|\s*))+$").unwrap()
+});
+
+static DURATION_PART_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(\d+(?:\.\d+)?|\.\d+)\s*([a-z]+)").unwrap()
+});
+
+fn duration_unit_millis(label: &str) -> Option<f64> {
+    let millis = match label.to_lowercase().as_str() {
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => 1.0,
+        "s" | "sec" | "secs" | "second" | "seconds" => 1_000.0,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60_000.0,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600_000.0,
+        "d" | "day" | "days" => 86_400_000.0,
+        "w" | "wk" | "wks" | "week" | "weeks" => 604_800_000.0,
+        _ => return None,
+    };
+    Some(millis)
+}
+
+#[derive(Deserialize)]
+pub struct ParseDurationKwargs {
+    unit: DurationUnit,
+}
+
+#[polars_expr(output_type=Int64)]
+fn parse_duration(inputs: &[Series], kwargs: ParseDurationKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: Int64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let value = opt_s?;
+        let negative = DURATION_REGEX.captures(value)?.get(1).is_some();
+        let mut millis = 0.0;
+        for captures in DURATION_PART_REGEX.captures_iter(value) {
+            let amount: f64 = captures[1].parse().ok()?;
+            millis += amount * duration_unit_millis(&captures[2])?;
+        }
+        let duration =
+            if kwargs.unit == DurationUnit::Seconds {
+                millis / 1_000.0
+            } else {
+                millis
+            };
+        let duration = duration.round();
+        // Anything beyond the range of the output is not a usable duration
+        if duration > i64::MAX as f64 {
+            return None;
+        }
+        Some(if negative { -duration as i64 } else { duration as i64 })
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct MapKwargs {
+    mapping: Vec<(String, String)>,
+    default: Option<String>,
+}
+
+#[polars_expr(output_type=String)]
+fn map_values(inputs: &[Series], kwargs: MapKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mapping: HashMap<&str, &str> = kwargs.mapping
+        .iter()
+        .map(|(from, to)| (from.as_str(), to.as_str()))
+        .collect();
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mapped = mapping.get(value).copied().or(kwargs.default.as_deref()).unwrap_or(value);
+        output.push_str(mapped);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct DetectDelimiterKwargs {
+    candidates: Vec<char>,
+}
+
+// The candidate splitting every line into the same number of fields, the most fields winning.
+// Quoted delimiters are not counted, a tie between the best candidates leaves it undecided.
+fn detect_line_delimiter(value: &str, candidates: &[char]) -> Option<char> {
+    let lines: Vec<&str> = value.lines().filter(|line| !line.trim().is_empty()).collect();
+    let mut best: Option<(char, usize)> = None;
+    let mut tied = false;
+    for &candidate in candidates.iter().filter(|&&c| c != '"') {
+        let kwargs = CsvLineKwargs { delimiter: candidate, quote: '"', trim: false };
+        let mut field_counts = lines.iter().map(|line| parse_csv_fields(line, &kwargs).len());
+        // A value without any lines has nothing to go by
+        let field_count = field_counts.next()?;
+        if field_count < 2 || !field_counts.all(|count| count == field_count) {
+            continue;
+        }
+        match best {
+            Some((_, best_count)) if field_count < best_count => {}
+            Some((_, best_count)) if field_count == best_count => tied = true,
+            _ => {
+                best = Some((candidate, field_count));
+                tied = false;
+            }
+        }
+    }
+    best.filter(|_| !tied).map(|(candidate, _)| candidate)
+}
+
+#[polars_expr(output_type=String)]
+fn detect_delimiter(inputs: &[Series], kwargs: DetectDelimiterKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        detect_line_delimiter(opt_s?, &kwargs.candidates).map(String::from)
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn length_zscore(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let lengths: Vec<Option<f64>> = ca.into_iter().map(|opt_s| opt_s.map(|s| s.chars().count() as f64)).collect();
+    // The population statistics of the lengths, leaving nulls out
+    let known: Vec<f64> = lengths.iter().flatten().copied().collect();
+    let count = known.len().max(1) as f64;
+    let mean = known.iter().sum::<f64>() / count;
+    let std = (known.iter().map(|length| (length - mean).powi(2)).sum::<f64>() / count).sqrt();
+    let out: Float64Chunked = lengths
+        .into_iter()
+        .map(|opt_length| opt_length.map(|length| if std > 0.0 { (length - mean) / std } else { 0.0 }))
+        .collect();
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum NullBehavior {
+    Propagate,
+    Skip,
+    Replace(String),
+}
+
+#[derive(Deserialize)]
+pub struct JoinKwargs {
+    separator: String,
+    null_behavior: NullBehavior,
+    skip_empty: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn join_columns(inputs: &[Series], kwargs: JoinKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!inputs.is_empty(), InvalidOperation: "at least one column must be given");
+    let columns: Vec<Series> = inputs
+        .iter()
+        .map(|s| s.cast(&DataType::String).map(|s| s.rechunk()))
+        .collect::<PolarsResult<_>>()?;
+    let len = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+    let mut parts: Vec<&StringChunked> = Vec::with_capacity(columns.len());
+    for column in &columns {
+        polars_ensure!(column.len() == 1 || column.len() == len,
+                       ShapeMismatch: "column {:?} does not match the length of the others", column.name());
+        parts.push(column.str()?);
+    }
+    let out: StringChunked = (0..len)
+        .map(|row| {
+            let mut output = String::new();
+            let mut is_first = true;
+            for part in &parts {
+                let value = match (part.get(if part.len() == 1 { 0 } else { row }), &kwargs.null_behavior) {
+                    (Some(value), _) => value,
+                    (None, NullBehavior::Propagate) => return None,
+                    (None, NullBehavior::Skip) => continue,
+                    (None, NullBehavior::Replace(replacement)) => replacement.as_str(),
+                };
+                if kwargs.skip_empty && value.is_empty() {
+                    continue;
+                }
+                if !is_first {
+                    output.push_str(&kwargs.separator);
+                }
+                output.push_str(value);
+                is_first = false;
+            }
+            Some(output)
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+fn lowercase_token_set(value: &str) -> HashSet<String> {
+    shingle_set(&value.to_lowercase(), &ShingleMode::Word, 1)
+}
+
+#[polars_expr(output_type=UInt32)]
+fn token_overlap_count(inputs: &[Series]) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: UInt32Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (lowercase_token_set(a?), lowercase_token_set(b?));
+        Some(a.intersection(&b).count() as u32)
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn token_overlap_ratio(inputs: &[Series]) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: Float64Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (lowercase_token_set(a?), lowercase_token_set(b?));
+        let union = a.union(&b).count();
+        if union == 0 {
+            return Some(1.0);
+        }
+        Some(a.intersection(&b).count() as f64 / union as f64)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct DedupKwargs {
+    consecutive_only: bool,
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn dedup_words(inputs: &[Series], kwargs: DedupKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut previous: Option<String> = None;
+        for word in value.split_whitespace() {
+            let key = if kwargs.case_insensitive { word.to_lowercase() } else { word.to_string() };
+            // The first occurrence is kept with its own casing
+            let is_duplicate =
+                if kwargs.consecutive_only {
+                    previous.as_ref() == Some(&key)
+                } else {
+                    !seen.insert(key.clone())
+                };
+            previous = Some(key);
+            if is_duplicate {
+                continue;
+            }
+            if !output.is_empty() {
+                output.push(' ');
+            }
+            output.push_str(word);
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn avatar_initials(inputs: &[Series]) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        // The first letter or digit of each word, skipping over leading punctuation like in "(Bob)"
+        let initials: Vec<&str> = value
+            .split_whitespace()
+            .filter_map(|word| word.graphemes(true).find(|g| g.chars().next().is_some_and(char::is_alphanumeric)))
+            .collect();
+        let (Some(first), Some(last)) = (initials.first(), initials.last()) else {
+            return;
+        };
+        output.push_str(&first.to_uppercase());
+        if initials.len() > 1 {
+            output.push_str(&last.to_uppercase());
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct FindKwargs {
+    overlapping: bool,
+    case_insensitive: bool,
+}
+
+fn find_char_offsets(haystack: &[char], needle: &[char], overlapping: bool) -> Vec<u32> {
+    let mut offsets = Vec::new();
+    if needle.is_empty() {
+        return offsets;
+    }
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        if haystack[start..start + needle.len()] == *needle {
+            offsets.push(start as u32);
+            start += if overlapping { 1 } else { needle.len() };
+        } else {
+            start += 1;
+        }
+    }
+    offsets
+}
+
+#[polars_expr(output_type_func=list_uint32_output)]
+fn find_all(inputs: &[Series], kwargs: FindKwargs) -> PolarsResult<Series> {
+    let haystacks: &StringChunked = inputs[0].str()?;
+    let needles: &StringChunked = inputs[1].str()?;
+    polars_ensure!(needles.len() == 1 || needles.len() == haystacks.len(),
+                   ShapeMismatch: "needles do not match the length of the strings to search");
+    // Characters are folded one by one so that the offsets stay those of the original string
+    let chars = |value: &str| -> Vec<char> {
+        if kwargs.case_insensitive {
+            value.chars().map(|c| c.to_lowercase().next().unwrap_or(c)).collect()
+        } else {
+            value.chars().collect()
+        }
+    };
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new("".into(), haystacks.len(), 0, DataType::UInt32);
+    for (row, opt_h) in haystacks.iter().enumerate() {
+        let opt_n = needles.get(if needles.len() == 1 { 0 } else { row });
+        match (opt_h, opt_n) {
+            (Some(h), Some(n)) => builder.append_slice(&find_char_offsets(&chars(h), &chars(n), kwargs.overlapping)),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+#[derive(Deserialize)]
+pub struct RedactBetweenKwargs {
+    start: String,
+    end: String,
+    mask_char: char,
+}
+
+#[polars_expr(output_type=String)]
+fn redact_between(inputs: &[Series], kwargs: RedactBetweenKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.start.is_empty() && !kwargs.end.is_empty(), InvalidOperation: "markers must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut rest = value;
+        while let Some(start) = rest.find(&kwargs.start) {
+            let content_start = start + kwargs.start.len();
+            // An opening marker which is never closed is left alone
+            let Some(length) = rest[content_start..].find(&kwargs.end) else {
+                break;
+            };
+            output.push_str(&rest[..content_start]);
+            // Line breaks are kept so that the layout stays the same
+            for grapheme in rest[content_start..content_start + length].graphemes(true) {
+                if grapheme == "\n" || grapheme == "\r\n" {
+                    output.push_str(grapheme);
+                } else {
+                    output.push(kwargs.mask_char);
+                }
+            }
+            output.push_str(&kwargs.end);
+            rest = &rest[content_start + length + kwargs.end.len()..];
+        }
+        output.push_str(rest);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ReplaceNthKwargs {
+    pattern: String,
+    replacement: String,
+    n: i64,
+    literal: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn replace_nth(inputs: &[Series], kwargs: ReplaceNthKwargs) -> PolarsResult<Series> {
+    polars_ensure!(!kwargs.pattern.is_empty(), InvalidOperation: "pattern must not be empty");
+    let ca: &StringChunked = inputs[0].str()?;
+    let pattern = if kwargs.literal { regex::escape(&kwargs.pattern) } else { kwargs.pattern.clone() };
+    let regex = Regex::new(&pattern)
+        .map_err(|e| polars_err!(ComputeError: "invalid pattern {:?}: {}", kwargs.pattern, e))?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let matches: Vec<Captures> = regex.captures_iter(value).collect();
+        // Occurrences count from one, or backwards from minus one for the last
+        let index =
+            if kwargs.n > 0 {
+                usize::try_from(kwargs.n - 1).ok()
+            } else {
+                matches.len().checked_sub(kwargs.n.unsigned_abs() as usize)
+            };
+        let Some(captures) = index.and_then(|i| matches.get(i)) else {
+            output.push_str(value);
+            return;
+        };
+        let whole = captures.get(0).unwrap();
+        output.push_str(&value[..whole.start()]);
+        if kwargs.literal {
+            output.push_str(&kwargs.replacement);
+        } else {
+            captures.expand(&kwargs.replacement, output);
+        }
+        output.push_str(&value[whole.end()..]);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct QuotedSplitKwargs {
+    delimiter: char,
+    quote: char,
+}
+
+// Unlike CSV fields, quoted regions may start anywhere, e.g. in `key="a,b"`
+fn split_outside_quotes(value: &str, kwargs: &QuotedSplitKwargs) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut part = String::new();
+    let mut in_quotes = false;
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == kwargs.quote {
+            // A doubled quote within a quoted region stands for the quote itself
+            if in_quotes && chars.next_if_eq(&kwargs.quote).is_some() {
+                part.push(c);
+            } else {
+                in_quotes = !in_quotes;
+            }
+        } else if c == kwargs.delimiter && !in_quotes {
+            parts.push(std::mem::take(&mut part));
+        } else {
+            part.push(c);
+        }
+    }
+    parts.push(part);
+    parts
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_respecting_quotes(inputs: &[Series], kwargs: QuotedSplitKwargs) -> PolarsResult<Series> {
+    polars_ensure!(kwargs.delimiter != kwargs.quote, InvalidOperation: "delimiter and quote must differ");
+    let ca: &StringChunked = inputs[0].str()?;
+    build_list_string_series(ca, |s| split_outside_quotes(s, &kwargs))
+}
+
+#[derive(Deserialize)]
+pub struct QuotesKwargs {
+    convert_dashes: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn straighten_quotes(inputs: &[Series], kwargs: QuotesKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        for c in value.chars() {
+            match c {
+                '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}' => output.push('\''),
+                '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{201f}' => output.push('"'),
+                '\u{2013}' | '\u{2014}' if kwargs.convert_dashes => output.push('-'),
+                _ => output.push(c),
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+// Whether a quote following the character opens a quotation, e.g. after a space, a bracket or
+// another opening quote as in "'nested'"
+fn opens_quotation(previous: Option<char>) -> bool {
+    previous.is_none_or(|p| {
+        p.is_whitespace() || matches!(p, '(' | '[' | '{' | '\u{2013}' | '\u{2014}' | '\u{2018}' | '\u{201c}')
+    })
+}
+
+#[polars_expr(output_type=String)]
+fn smart_quotes(inputs: &[Series], kwargs: QuotesKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut chars = value.chars().peekable();
+        let mut previous: Option<char> = None;
+        while let Some(c) = chars.next() {
+            let next = chars.peek().copied();
+            let smart = match c {
+                '"' if opens_quotation(previous) => '\u{201c}',
+                '"' => '\u{201d}',
+                // Apostrophes within words take the closing mark, as in "don’t"
+                '\'' if opens_quotation(previous) && next.is_some_and(|n| !n.is_whitespace()) => '\u{2018}',
+                '\'' => '\u{2019}',
+                '-' if kwargs.convert_dashes && next == Some('-') => {
+                    chars.next();
+                    '\u{2014}'
+                },
+                '-' if kwargs.convert_dashes && previous == Some(' ') && next == Some(' ') => '\u{2013}',
+                _ => c,
+            };
+            output.push(smart);
+            previous = Some(smart);
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct HighlightKwargs {
+    prefix: String,
+    suffix: String,
+    case_insensitive: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn highlight(inputs: &[Series], kwargs: HighlightKwargs) -> PolarsResult<Series> {
+    let haystacks: &StringChunked = inputs[0].str()?;
+    let needles: &StringChunked = inputs[1].str()?;
+    let fold = |c: char| -> char {
+        if kwargs.case_insensitive {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+    let out: StringChunked = broadcast_binary_elementwise(haystacks, needles, |h: Option<&str>, n: Option<&str>| {
+        let (haystack, needle) = (h?, n?);
+        let (positions, chars): (Vec<usize>, Vec<char>) = haystack.char_indices().map(|(i, c)| (i, fold(c))).unzip();
+        let needle: Vec<char> = needle.chars().map(fold).collect();
+        // Matches are found in the folded characters, but the original ones are kept
+        let mut output = String::with_capacity(haystack.len());
+        let mut last = 0;
+        for offset in find_char_offsets(&chars, &needle, false) {
+            let start = positions[offset as usize];
+            let end = positions.get(offset as usize + needle.len()).copied().unwrap_or(haystack.len());
+            output.push_str(&haystack[last..start]);
+            output.push_str(&kwargs.prefix);
+            output.push_str(&haystack[start..end]);
+            output.push_str(&kwargs.suffix);
+            last = end;
+        }
+        output.push_str(&haystack[last..]);
+        Some(output)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct MojibakeKwargs {
+    aggressive: bool,
+}
+
+// Decodes every misdecoded sequence back from its Windows-1252 bytes, which also covers Latin-1 as
+// the two only differ in the control range. A byte order mark read the same way is dropped.
+fn repair_mojibake(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut output = String::with_capacity(value.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let length = misdecoded_sequence_length(&chars, i);
+        let bytes: Vec<u8> = chars[i..i + length].iter().filter_map(|&c| cp1252_byte(c)).collect();
+        match std::str::from_utf8(&bytes) {
+            Ok(decoded) if length > 0 => {
+                if !(i == 0 && decoded == "\u{feff}") {
+                    output.push_str(decoded);
+                }
+                i += length;
+            },
+            _ => {
+                output.push(chars[i]);
+                i += 1;
+            },
+        }
+    }
+    output
+}
+
+// Text which went through the wrong decoding more than once needs as many passes
+const MAX_MOJIBAKE_PASSES: usize = 4;
+
+#[polars_expr(output_type=String)]
+fn fix_mojibake(inputs: &[Series], kwargs: MojibakeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let passes = if kwargs.aggressive { MAX_MOJIBAKE_PASSES } else { 1 };
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut text = value.to_string();
+        for _ in 0..passes {
+            let repaired = repair_mojibake(&text);
+            if repaired == text {
+                break;
+            }
+            text = repaired;
+        }
+        output.push_str(&text);
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum KeyboardLayout {
+    Qwerty,
+    Azerty,
+}
+
+#[derive(Deserialize)]
+pub struct KeyboardKwargs {
+    layout: KeyboardLayout,
+}
+
+// Column and row of a letter key, the rows being staggered as on a physical keyboard
+fn key_position(c: char, layout: KeyboardLayout) -> Option<(f64, f64)> {
+    let rows: [&str; 3] = match layout {
+        KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+        KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+    };
+    const ROW_OFFSETS: [f64; 3] = [0.0, 0.25, 0.75];
+    rows.iter().zip(ROW_OFFSETS).enumerate().find_map(|(row, (keys, offset))| {
+        keys.chars().position(|key| key == c).map(|column| (column as f64 + offset, row as f64))
+    })
+}
+
+// Neighbouring keys cost half an edit, any other keys a full one, as do unknown characters
+fn key_substitution_cost(a: char, b: char, layout: KeyboardLayout) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    match (key_position(a, layout), key_position(b, layout)) {
+        (Some((ax, ay)), Some((bx, by))) if (ax - bx).hypot(ay - by) <= 1.5 => 0.5,
+        _ => 1.0,
+    }
+}
+
+// Optimal string alignment distance, with substitutions weighted by the distance between the keys
+fn keyboard_edit_distance(a: &[char], b: &[char], layout: KeyboardLayout) -> f64 {
+    let mut rows: Vec<Vec<f64>> = vec![vec![0.0; b.len() + 1]; a.len() + 1];
+    rows[0] = (0..=b.len()).map(|j| j as f64).collect();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i as f64;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = rows[i - 1][j - 1] + key_substitution_cost(a[i - 1], b[j - 1], layout);
+            let mut cost = substitution.min(rows[i - 1][j] + 1.0).min(rows[i][j - 1] + 1.0);
+            // Swapped neighbours like in "teh" count as a single edit
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cost = cost.min(rows[i - 2][j - 2] + 1.0);
+            }
+            rows[i][j] = cost;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[polars_expr(output_type=Float64)]
+fn keyboard_distance(inputs: &[Series], kwargs: KeyboardKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    // Letters differing in case only are typed on the same key
+    let chars = |value: &str| -> Vec<char> { value.chars().map(|c| c.to_lowercase().next().unwrap_or(c)).collect() };
+    let out: Float64Chunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        Some(keyboard_edit_distance(&chars(a?), &chars(b?), kwargs.layout))
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct SortCharsKwargs {
+    descending: bool,
+    case_insensitive: bool,
+    ignore_whitespace: bool,
+}
+
+// The graphemes of the value in ascending order, folded to lowercase when ignoring case. Punctuation
+// is anything which is neither a letter, a digit nor whitespace.
+fn sorted_graphemes(value: &str, case_insensitive: bool, ignore_whitespace: bool, ignore_punctuation: bool) -> Vec<String> {
+    let is_punctuation = |g: &str| !g.chars().any(|c| c.is_alphanumeric() || c.is_whitespace());
+    let mut graphemes: Vec<String> = value
+        .graphemes(true)
+        .filter(|g| !(ignore_whitespace && g.chars().all(char::is_whitespace)))
+        .filter(|g| !(ignore_punctuation && is_punctuation(g)))
+        .map(|g| if case_insensitive { g.to_lowercase() } else { g.to_string() })
+        .collect();
+    graphemes.sort_unstable();
+    graphemes
+}
+
+#[polars_expr(output_type=String)]
+fn sort_chars(inputs: &[Series], kwargs: SortCharsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let graphemes = sorted_graphemes(value, kwargs.case_insensitive, kwargs.ignore_whitespace, false);
+        if kwargs.descending {
+            output.extend(graphemes.iter().rev().map(String::as_str));
+        } else {
+            output.extend(graphemes.iter().map(String::as_str));
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct AnagramKwargs {
+    ignore_case: bool,
+    ignore_whitespace: bool,
+    ignore_punctuation: bool,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_anagram(inputs: &[Series], kwargs: AnagramKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let key = |value: &str| sorted_graphemes(value, kwargs.ignore_case, kwargs.ignore_whitespace, kwargs.ignore_punctuation);
+    let out: BooleanChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        Some(key(a?) == key(b?))
+    });
+    Ok(out.into_series())
+}
+
+// Articles, conjunctions and short prepositions which acronyms usually leave out
+const ACRONYM_SMALL_WORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "nor", "but", "of", "for", "in", "on", "at", "to", "by", "with",
+];
+
+#[derive(Deserialize)]
+pub struct AcronymKwargs {
+    uppercase: bool,
+    skip_small_words: bool,
+    max_length: Option<usize>,
+}
+
+#[polars_expr(output_type=String)]
+fn acronym(inputs: &[Series], kwargs: AcronymKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let initials = value
+            .unicode_words()
+            .filter(|word| !(kwargs.skip_small_words && ACRONYM_SMALL_WORDS.contains(&word.to_lowercase().as_str())))
+            .filter_map(|word| word.graphemes(true).next())
+            .take(kwargs.max_length.unwrap_or(usize::MAX));
+        for initial in initials {
+            if kwargs.uppercase {
+                output.push_str(&initial.to_uppercase());
+            } else {
+                output.push_str(initial);
+            }
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct MaskLettersKwargs {
+    mask_char: char,
+    treat_y_as_vowel: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn mask_vowels(inputs: &[Series], kwargs: MaskLettersKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| if is_vowel_letter(c, kwargs.treat_y_as_vowel) { kwargs.mask_char } else { c }));
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn mask_consonants(inputs: &[Series], kwargs: MaskLettersKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| {
+            if c.is_ascii_alphabetic() && !is_vowel_letter(c, kwargs.treat_y_as_vowel) { kwargs.mask_char } else { c }
+        }));
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct InterleaveKwargs {
+    pad: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn interleave(inputs: &[Series], kwargs: InterleaveKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: StringChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (a, b): (Vec<&str>, Vec<&str>) = (a?.graphemes(true).collect(), b?.graphemes(true).collect());
+        let shorter = a.len().min(b.len());
+        let mut output = String::new();
+        for (x, y) in a.iter().zip(&b) {
+            output.push_str(x);
+            output.push_str(y);
+        }
+        // Only one of the two has a tail left over
+        if kwargs.pad {
+            output.extend(a[shorter..].iter().chain(&b[shorter..]).copied());
+        }
+        Some(output)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct VigenereKwargs {
+    key: String,
+    decrypt: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn vigenere_cipher(inputs: &[Series], kwargs: VigenereKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    // Only the letters of the key count, each one shifting by its place in the alphabet
+    let shifts: Vec<u8> = kwargs.key
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase() as u8 - b'a')
+        .map(|shift| if kwargs.decrypt { (26 - shift) % 26 } else { shift })
+        .collect();
+    polars_ensure!(!shifts.is_empty(), InvalidOperation: "key must contain at least one ASCII letter");
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        let mut shift = shifts.iter().cycle();
+        for c in value.chars() {
+            if !c.is_ascii_alphabetic() {
+                output.push(c);
+                continue;
+            }
+            let base = if c.is_ascii_uppercase() { b'A' } else { b'a' };
+            let offset = (c as u8 - base + shift.next().unwrap()) % 26;
+            output.push((base + offset) as char);
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_sentences(inputs: &[Series], kwargs: SentenceSplitKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let abbreviations = sentence_abbreviations(&kwargs.abbreviations);
+    build_list_string_series(ca, |s| {
+        let chars: Vec<char> = s.chars().collect();
+        sentence_spans(&chars, &abbreviations)
+            .into_iter()
+            .map(|(start, end)| chars[start..end].iter().collect())
+            .collect()
+    })
+}
+
+#[derive(Deserialize)]
+pub struct SyllableKwargs {
+    per_word_list: bool,
+}
+
+fn syllables_output(_: &[Field], kwargs: SyllableKwargs) -> PolarsResult<Field> {
+    let dtype =
+        if kwargs.per_word_list {
+            DataType::List(Box::new(DataType::UInt32))
+        } else {
+            DataType::UInt32
+        };
+    Ok(Field::new("".into(), dtype))
+}
+
+#[polars_expr(output_type_func_with_kwargs=syllables_output)]
+fn count_syllables(inputs: &[Series], kwargs: SyllableKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    if kwargs.per_word_list {
+        return Ok(syllable_counts_per_word(ca));
+    }
+    let out: UInt32Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        opt_s.map(|s| s.unicode_words().map(estimate_syllables).sum())
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ReadabilityKwargs {
+    abbreviations: Vec<String>,
+}
+
+// The average words per sentence and syllables per word, unless there are no sentences or words
+fn readability_ratios(value: &str, abbreviations: &[String]) -> Option<(f64, f64)> {
+    let chars: Vec<char> = value.chars().collect();
+    let sentences = sentence_spans(&chars, abbreviations).len();
+    let syllables: Vec<u32> = value.unicode_words().map(estimate_syllables).collect();
+    if sentences == 0 || syllables.is_empty() {
+        return None;
+    }
+    let words = syllables.len() as f64;
+    Some((words / sentences as f64, syllables.iter().sum::<u32>() as f64 / words))
+}
+
+#[polars_expr(output_type=Float64)]
+fn flesch_reading_ease(inputs: &[Series], kwargs: ReadabilityKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let abbreviations = sentence_abbreviations(&kwargs.abbreviations);
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let (words_per_sentence, syllables_per_word) = readability_ratios(opt_s?, &abbreviations)?;
+        Some(206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word)
+    });
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=Float64)]
+fn flesch_kincaid_grade(inputs: &[Series], kwargs: ReadabilityKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let abbreviations = sentence_abbreviations(&kwargs.abbreviations);
+    let out: Float64Chunked = unary_elementwise(ca, |opt_s: Option<&str>| {
+        let (words_per_sentence, syllables_per_word) = readability_ratios(opt_s?, &abbreviations)?;
+        Some(0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DiffMode {
+    Char,
+    Word,
+}
+
+#[derive(Deserialize)]
+pub struct DiffKwargs {
+    mode: DiffMode,
+}
+
+// Words and the whitespace between them, so that the text can be put back together from them
+fn diff_tokens<'a>(value: &'a str, mode: &DiffMode) -> Vec<&'a str> {
+    match mode {
+        DiffMode::Char => value.graphemes(true).collect(),
+        DiffMode::Word => value.split_word_bounds().collect(),
+    }
+}
+
+enum DiffOp<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+// Lengths of the longest common subsequences of the whole of a and every prefix of b
+fn lcs_lengths(a: &[&str], b: &[&str]) -> Vec<u32> {
+    let mut previous = vec![0u32; b.len() + 1];
+    let mut current = vec![0u32; b.len() + 1];
+    for token in a {
+        for (j, other) in b.iter().enumerate() {
+            current[j + 1] =
+                if token == other {
+                    previous[j] + 1
+                } else {
+                    previous[j + 1].max(current[j])
+                };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+// Hirschberg's algorithm, which finds the same edits as the full table of the longest common
+// subsequence in linear space by splitting the old tokens in half at a point the optimal path crosses
+fn push_diff_ops<'a>(old: &[&'a str], new: &[&'a str], ops: &mut Vec<DiffOp<'a>>) {
+    if old.is_empty() {
+        ops.extend(new.iter().map(|token| DiffOp::Insert(token)));
+        return;
+    }
+    if new.is_empty() {
+        ops.extend(old.iter().map(|token| DiffOp::Delete(token)));
+        return;
+    }
+    if old.len() == 1 {
+        match new.iter().position(|token| *token == old[0]) {
+            Some(p) => {
+                ops.extend(new[..p].iter().map(|token| DiffOp::Insert(token)));
+                ops.push(DiffOp::Equal(old[0]));
+                ops.extend(new[p + 1..].iter().map(|token| DiffOp::Insert(token)));
+            },
+            None => {
+                ops.push(DiffOp::Delete(old[0]));
+                ops.extend(new.iter().map(|token| DiffOp::Insert(token)));
+            },
+        }
+        return;
+    }
+
+    let middle = old.len() / 2;
+    let forward = lcs_lengths(&old[..middle], new);
+    let old_tail: Vec<&str> = old[middle..].iter().rev().copied().collect();
+    let new_reversed: Vec<&str> = new.iter().rev().copied().collect();
+    let backward = lcs_lengths(&old_tail, &new_reversed);
+    let split = (0..=new.len()).max_by_key(|&k| (forward[k] + backward[new.len() - k], Reverse(k))).unwrap();
+    push_diff_ops(&old[..middle], &new[..split], ops);
+    push_diff_ops(&old[middle..], &new[split..], ops);
+}
+
+// Writes every run of changes as its deletions followed by its insertions, the common prefix and
+// suffix are set aside first since most pairs of values only differ in a small part
+fn push_diff(old: &[&str], new: &[&str], output: &mut String) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+    let mut ops: Vec<DiffOp> = Vec::new();
+    push_diff_ops(&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix], &mut ops);
+
+    old[..prefix].iter().for_each(|token| output.push_str(token));
+    let (mut deleted, mut inserted) = (String::new(), String::new());
+    let flush = |deleted: &mut String, inserted: &mut String, output: &mut String| {
+        if !deleted.is_empty() {
+            output.push_str("[-");
+            output.push_str(deleted);
+            output.push_str("-]");
+            deleted.clear();
+        }
+        if !inserted.is_empty() {
+            output.push_str("{+");
+            output.push_str(inserted);
+            output.push_str("+}");
+            inserted.clear();
+        }
+    };
+    for op in ops {
+        match op {
+            DiffOp::Equal(token) => {
+                flush(&mut deleted, &mut inserted, output);
+                output.push_str(token);
+            },
+            DiffOp::Delete(token) => deleted.push_str(token),
+            DiffOp::Insert(token) => inserted.push_str(token),
+        }
+    }
+    flush(&mut deleted, &mut inserted, output);
+    old[old.len() - suffix..].iter().for_each(|token| output.push_str(token));
+}
+
+#[polars_expr(output_type=String)]
+fn diff_strings(inputs: &[Series], kwargs: DiffKwargs) -> PolarsResult<Series> {
+    let lhs: &StringChunked = inputs[0].str()?;
+    let rhs: &StringChunked = inputs[1].str()?;
+    let out: StringChunked = broadcast_binary_elementwise(lhs, rhs, |a: Option<&str>, b: Option<&str>| {
+        let (old, new) = (a?, b?);
+        let mut output = String::with_capacity(old.len().max(new.len()));
+        push_diff(&diff_tokens(old, &kwargs.mode), &diff_tokens(new, &kwargs.mode), &mut output);
+        Some(output)
+    });
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct NormalizeKeyKwargs {
+    lowercase: bool,
+    strip_accents: bool,
+    squeeze_whitespace: bool,
+    remove_punctuation: bool,
+    sort_tokens: bool,
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_key(inputs: &[Series], kwargs: NormalizeKeyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = inputs[0].str()?;
+    let mut normalized = String::new();
+    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+        normalized.clear();
+        let chars: Box<dyn Iterator<Item = char>> =
+            if kwargs.strip_accents {
+                Box::new(value.nfd().filter(|c| !is_combining_mark(*c)))
+            } else {
+                Box::new(value.chars())
+            };
+        for c in chars {
+            if kwargs.remove_punctuation && !c.is_alphanumeric() && !c.is_whitespace() {
+                continue;
+            }
+            if kwargs.lowercase {
+                push_casefolded(c, &mut normalized);
+            } else {
+                normalized.push(c);
+            }
+        }
+        if !kwargs.squeeze_whitespace && !kwargs.sort_tokens {
+            output.push_str(&normalized);
+            return;
+        }
+
+        // Sorting works on whitespace-separated tokens, so the result is squeezed either way
+        let mut tokens: Vec<&str> = normalized.split_whitespace().collect();
+        if kwargs.sort_tokens {
+            tokens.sort_unstable();
+        }
+        output.push_str(&tokens.join(" "));
+    });
+    Ok(out.into_series())
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+# test_acronym.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import acronym
+import polars
+
+def test_acronym():
+    df = polars.DataFrame({
+        'input': [
+            'Portable Document Format',
+            'Bank of America',
+            'the united states of america',
+            'read-only memory',
+            '',
+            None,
+        ],
+        'expected': [
+            'PDF',
+            'BA',
+            'USA',
+            'ROM',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=acronym('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_acronym_keep_small_words():
+    df = polars.DataFrame({
+        'input': [
+            'Bank of America',
+            'the united states of america',
+        ],
+        'expected': [
+            'BoA',
+            'tusoa',
+        ],
+    })
+    df = df.with_columns(output=acronym('input', uppercase=False, skip_small_words=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_acronym_max_length():
+    df = polars.DataFrame({
+        'input': [
+            'Portable Document Format',
+            'Bank',
+        ],
+        'expected': [
+            'PD',
+            'B',
+        ],
+    })
+    df = df.with_columns(output=acronym('input', max_length=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
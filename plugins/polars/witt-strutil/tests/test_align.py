@@ -0,0 +1,78 @@
+# test_align.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import align
+import polars
+
+def test_align():
+    df = polars.DataFrame({
+        'input': [
+            'abc',
+            'ab',
+            'abcdef',
+            'e\u0301te\u0301',
+            '',
+            None,
+        ],
+        'left': [
+            'abc...',
+            'ab....',
+            'abcdef',
+            'e\u0301te\u0301...',
+            '......',
+            None,
+        ],
+        'right': [
+            '...abc',
+            '....ab',
+            'abcdef',
+            '...e\u0301te\u0301',
+            '......',
+            None,
+        ],
+        'center': [
+            '.abc..',
+            '..ab..',
+            'abcdef',
+            '.e\u0301te\u0301..',
+            '......',
+            None,
+        ],
+    })
+    for alignment in ['left', 'right', 'center']:
+        output = df.select(output=align('input', width=6, alignment=alignment, fill='.'))
+        assert output['output'].to_list() == df[alignment].to_list()
+
+def test_align_truncate():
+    df = polars.DataFrame({
+        'input': [
+            'abcdefgh',
+            'abcdef',
+            'abc',
+        ],
+        'expected': [
+            'abcdef',
+            'abcdef',
+            'abc   ',
+        ],
+    })
+    df = df.with_columns(output=align('input', width=6, truncate=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = df.with_columns(output=align('input', width=6))
+    assert df['output'].to_list() == ['abcdefgh', 'abcdef', 'abc   ']
\ No newline at end of file
@@ -0,0 +1,61 @@
+# test_alternating_case.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import alternating_case
+import polars
+
+def test_alternating_case():
+    df = polars.DataFrame({
+        'input': [
+            'hello world',
+            'a1b2c',
+            '',
+            None,
+        ],
+        'expected': [
+            'hElLo WoRlD',
+            'a1B2c',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=alternating_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_alternating_case_start_upper():
+    df = polars.DataFrame({
+        'input': [
+            'hello world',
+            'a1b2c',
+        ],
+        'expected': [
+            'HeLlO wOrLd',
+            'A1b2C',
+        ],
+    })
+    df = df.with_columns(output=alternating_case('input', start_upper=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_alternating_case_non_letters():
+    df = polars.DataFrame({'input': ['ab cd ef']})
+    df = df.select(advance=alternating_case('input', start_upper=True, non_letters='advance'),
+                   reset=alternating_case('input', start_upper=True, non_letters='reset'))
+
+    assert df.row(0) == ('Ab cD Ef', 'Ab Cd Ef')
\ No newline at end of file
@@ -0,0 +1,60 @@
+# test_avatar_initials.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import avatar_initials
+import polars
+
+def test_avatar_initials():
+    df = polars.DataFrame({
+        'input': [
+            'ada',
+            'ada lovelace',
+            '  john   ronald  reuel tolkien ',
+            '(bob) smith',
+            '',
+            None,
+        ],
+        'expected': [
+            'A',
+            'AL',
+            'JT',
+            'BS',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=avatar_initials('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_avatar_initials_non_latin():
+    df = polars.DataFrame({
+        'input': [
+            '毛泽东',
+            'дмитрий менделеев',
+            'émile zola',
+        ],
+        'expected': [
+            '毛',
+            'ДМ',
+            'ÉZ',
+        ],
+    })
+    df = df.with_columns(output=avatar_initials('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
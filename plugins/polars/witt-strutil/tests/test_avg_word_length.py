@@ -0,0 +1,65 @@
+# test_avg_word_length.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import avg_word_length
+from witt_strutil import unique_word_ratio
+import polars
+
+def test_avg_word_length():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox',
+            'a bb, ccc!',
+            'lorem',
+            '',
+            '!?',
+            None,
+        ],
+        'expected': [
+            4.0,
+            2.0,
+            5.0,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=avg_word_length('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_unique_word_ratio():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox',
+            'the cat and the hat',
+            'Buffalo buffalo BUFFALO buffalo',
+            '',
+            None,
+        ],
+        'expected': [
+            1.0,
+            0.8,
+            0.25,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=unique_word_ratio('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,63 @@
+# test_balance_quotes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import balance_quotes
+import polars
+
+def test_balance_quotes():
+    df = polars.DataFrame({
+        'input': [
+            'He said (hello [world',
+            'stray) closer]',
+            '("quoted)',
+            '“open quote',
+            'balanced {a [b] (c)} "d"',
+            None,
+        ],
+        'expected': [
+            'He said (hello [world])',
+            'stray closer',
+            '("quoted")',
+            '“open quote”',
+            'balanced {a [b] (c)} "d"',
+            None,
+        ],
+    })
+    df = df.with_columns(output=balance_quotes('input', strategy='close'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = polars.DataFrame({
+        'input': [
+            'He said (hello [world',
+            'stray) closer]',
+            '("quoted)',
+            '“open quote',
+            'balanced {a [b] (c)} "d"',
+            None,
+        ],
+        'expected': [
+            'He said hello world',
+            'stray closer',
+            '(quoted)',
+            'open quote',
+            'balanced {a [b] (c)} "d"',
+            None,
+        ],
+    })
+    df = df.with_columns(output=balance_quotes('input', strategy='remove'))
+    assert df['output'].to_list() == df['expected'].to_list()
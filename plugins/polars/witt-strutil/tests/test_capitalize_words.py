@@ -0,0 +1,53 @@
+# test_capitalize_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import capitalize_words
+from witt_strutil import smart_title
+import polars
+
+def test_capitalize_words():
+    df = polars.DataFrame({
+        'input': [
+            'the quick brown fox',
+            '(hello) wORLD  of 42',
+            '',
+            None,
+        ],
+        'expected': [
+            'The Quick Brown Fox',
+            '(Hello) World  Of 42',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=capitalize_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_capitalize_words_only_first_letter():
+    df = polars.DataFrame({'input': ['the NASA report on iPhone use']})
+    df = df.with_columns(output=capitalize_words('input', only_first_letter=True))
+
+    assert df['output'].to_list() == ['The NASA Report On IPhone Use']
+
+def test_capitalize_words_versus_smart_title():
+    df = polars.DataFrame({'input': ['the NASA report on iPhone use']})
+    df = df.select(capitalized=capitalize_words('input'),
+                   titled=smart_title('input'))
+
+    assert df.row(0) == ('The Nasa Report On Iphone Use', 'The NASA Report On Iphone Use')
\ No newline at end of file
@@ -0,0 +1,48 @@
+# test_char_frequency.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import char_frequency
+import polars
+
+def test_char_frequency():
+    df = polars.DataFrame({
+        'input': [
+            'aabbbc',
+            '',
+            None,
+        ],
+        'expected': [
+            [{'char': 'b', 'count': 3}, {'char': 'a', 'count': 2}, {'char': 'c', 'count': 1}],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=char_frequency('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_char_frequency_options():
+    df = polars.DataFrame({'input': ['Aa a\n']})
+    df = df.select(default=char_frequency('input'),
+                   folded=char_frequency('input', case_insensitive=True, ignore_whitespace=True))
+
+    assert df['default'].to_list() == [[{'char': 'a', 'count': 2},
+                                        {'char': '\n', 'count': 1},
+                                        {'char': ' ', 'count': 1},
+                                        {'char': 'A', 'count': 1}]]
+    assert df['folded'].to_list() == [[{'char': 'a', 'count': 3}]]
\ No newline at end of file
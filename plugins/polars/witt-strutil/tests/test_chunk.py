@@ -0,0 +1,78 @@
+# test_chunk.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import chunk
+import polars
+import pytest
+
+def test_chunk_grapheme():
+    df = polars.DataFrame({
+        'input': [
+            'ABCD1234',
+            'ABCDE',
+            'e\u0301e\u0301x',
+            '',
+            None,
+        ],
+        'expected': [
+            ['AB', 'CD', '12', '34'],
+            ['AB', 'CD', 'E'],
+            ['e\u0301e\u0301', 'x'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=chunk('input', 2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_chunk_char():
+    df = polars.DataFrame({
+        'input': [
+            'ABCD1234',
+            'e\u0301e\u0301x',
+        ],
+        'expected': [
+            ['ABCD', '1234'],
+            ['e\u0301e\u0301', 'x'],
+        ],
+    })
+    df = df.with_columns(output=chunk('input', 4, unit='char'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_chunk_byte():
+    df = polars.DataFrame({
+        'input': [
+            'ABCDE',
+            'héllo',
+        ],
+        'expected': [
+            ['AB', 'CD', 'E'],
+            ['h', 'é', 'll', 'o'],
+        ],
+    })
+    df = df.with_columns(output=chunk('input', 2, unit='byte'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_chunk_byte_strict():
+    df = polars.DataFrame({'input': ['héllo']})
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=chunk('input', 2, unit='byte', strict=True))
\ No newline at end of file
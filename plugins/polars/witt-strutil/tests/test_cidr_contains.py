@@ -0,0 +1,89 @@
+# test_cidr_contains.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import cidr_contains
+import polars
+
+def test_cidr_contains():
+    # IPv4 network given as a literal
+    df = polars.DataFrame({
+        'input': [
+            '192.168.1.0',
+            '192.168.1.42',
+            '192.168.1.255',
+            '192.168.2.0',
+            '10.0.0.1',
+            '2001:db8::1',
+            'not an ip',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+            False,
+            False,
+            False,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=cidr_contains('input', polars.lit('192.168.1.0/24')))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # IPv6 network given as a literal
+    df = polars.DataFrame({
+        'input': [
+            '2001:db8::1',
+            '2001:db8:ffff::1',
+            '2001:db9::1',
+            '192.168.1.1',
+        ],
+        'expected': [
+            True,
+            True,
+            False,
+            False,
+        ],
+    })
+    df = df.with_columns(output=cidr_contains('input', polars.lit('2001:db8::/32')))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Networks given per row
+    df = polars.DataFrame({
+        'input': [
+            '10.1.2.3',
+            '11.0.0.1',
+            '8.8.8.8',
+            '1.2.3.4',
+        ],
+        'network': [
+            '10.0.0.0/8',
+            '10.0.0.0/8',
+            '0.0.0.0/0',
+            '1.2.3.4/33',
+        ],
+        'expected': [
+            True,
+            False,
+            True,
+            None,
+        ],
+    })
+    df = df.with_columns(output=cidr_contains('input', 'network'))
+    assert df['output'].to_list() == df['expected'].to_list()
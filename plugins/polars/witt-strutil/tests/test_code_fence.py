@@ -0,0 +1,55 @@
+# test_code_fence.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import code_fence
+from witt_strutil import extract_code_blocks
+import polars
+
+def test_code_fence():
+    df = polars.DataFrame({
+        'input': [
+            "print('hi')",
+            "print('hi')\n",
+            '```js\nx\n```',
+            'a ```` b',
+            '',
+            None,
+        ],
+        'expected': [
+            "```python\nprint('hi')\n```",
+            "```python\nprint('hi')\n```",
+            '````python\n```js\nx\n```\n````',
+            '`````python\na ```` b\n`````',
+            '```python\n```',
+            None,
+        ],
+    })
+    df = df.with_columns(output=code_fence('input', language='python'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Without a language
+    df = df.with_columns(output=code_fence('input'))
+    assert df['output'][0] == "```\nprint('hi')\n```"
+
+def test_code_fence_round_trip():
+    df = polars.DataFrame({'input': ['```js\nx\n```', 'a ```` b']})
+    df = df.with_columns(output=extract_code_blocks(code_fence('input', language='md')))
+    assert df['output'].to_list() == [
+        [{'language': 'md', 'code': '```js\nx\n```'}],
+        [{'language': 'md', 'code': 'a ```` b'}],
+    ]
@@ -0,0 +1,95 @@
+# test_common_prefix.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import common_prefix
+from witt_strutil import common_suffix
+import polars
+
+def test_common_prefix():
+    df = polars.DataFrame({
+        'input': [
+            '/home/user/documents/report.pdf',
+            'apple',
+            'pre',
+            'prefix',
+            'same',
+            'e\u0301te',
+            None,
+            'lorem',
+        ],
+        'other': [
+            '/home/user/downloads/image.png',
+            'banana',
+            'prefix',
+            'pre',
+            'same',
+            'e\u0300te',
+            'lorem',
+            None,
+        ],
+        'expected': [
+            '/home/user/do',
+            '',
+            'pre',
+            'pre',
+            'same',
+            '',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=common_prefix('input', 'other'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_common_suffix():
+    df = polars.DataFrame({
+        'input': [
+            '2024_sales_report.csv',
+            'apple',
+            'fix',
+            'suffix',
+            'same',
+            'cafe\u0301',
+            None,
+            'lorem',
+        ],
+        'other': [
+            '2025_budget_report.csv',
+            'banana',
+            'suffix',
+            'fix',
+            'same',
+            'cafa\u0301',
+            'lorem',
+            None,
+        ],
+        'expected': [
+            '_report.csv',
+            '',
+            'fix',
+            'fix',
+            'same',
+            '',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=common_suffix('input', 'other'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,64 @@
+# test_compress_ranges.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import compress_ranges, expand_ranges
+import polars
+
+def test_compress_ranges():
+    df = polars.DataFrame({
+        'input': [
+            [1, 2, 3, 5, 7, 8, 9],
+            [4],
+            [1, 3, 5],
+            [10, 11, 20, 21, 22],
+            [],
+            None,
+        ],
+        'expected': [
+            '1-3,5,7-9',
+            '4',
+            '1,3,5',
+            '10-11,20-22',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=compress_ranges('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Custom separators
+    df = polars.DataFrame({
+        'input': [
+            [1, 2, 3, 6],
+        ],
+        'expected': [
+            '1..3; 6',
+        ],
+    })
+    df = df.with_columns(output=compress_ranges('input', range_separator='..', list_separator='; '))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_compress_ranges_round_trip():
+    df = polars.DataFrame({
+        'input': [
+            '1-3,5,7-9',
+            '2,4,6-10',
+        ],
+    })
+    df = df.with_columns(output=compress_ranges(expand_ranges('input')))
+    assert df['output'].to_list() == df['input'].to_list()
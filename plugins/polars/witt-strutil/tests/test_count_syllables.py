@@ -0,0 +1,64 @@
+# test_count_syllables.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import count_syllables
+import polars
+
+def test_count_syllables():
+    df = polars.DataFrame({
+        'input': [
+            'syllable',
+            'cake',
+            'table',
+            'The quick brown fox',
+            'reading ease',
+            '',
+            None,
+        ],
+        'expected': [
+            3,
+            1,
+            2,
+            4,
+            3,
+            0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=count_syllables('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_count_syllables_per_word():
+    df = polars.DataFrame({
+        'input': [
+            'syllable cake',
+            'reading ease',
+            '',
+            None,
+        ],
+        'expected': [
+            [3, 1],
+            [2, 1],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=count_syllables('input', per_word_list=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,70 @@
+# test_dedent.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import dedent
+from witt_strutil import indent
+import polars
+
+def test_dedent():
+    df = polars.DataFrame({
+        'input': [
+            '    def f():\n        return 1\n\n    x = 2\n',
+            '\t\ta\n\t\t  b\n   \n\t\tc',
+            '  a\n\tb',
+            'no indent',
+            '',
+            None,
+        ],
+        'expected': [
+            'def f():\n    return 1\n\nx = 2\n',
+            'a\n  b\n\nc',
+            '  a\n\tb',
+            'no indent',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=dedent('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_indent():
+    df = polars.DataFrame({
+        'input': [
+            'a\n  b\n\nc\n',
+            'a\n   \nb',
+            '',
+            None,
+        ],
+        'expected': [
+            '> a\n>   b\n\n> c\n',
+            '> a\n   \n> b',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=indent('input', prefix='> '))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Prefix empty lines too
+    df = df.with_columns(output=indent('input', prefix='> ', skip_empty_lines=False))
+    assert df['output'].to_list() == ['> a\n>   b\n> \n> c\n', '> a\n>    \n> b', '', None]
+
+def test_dedent_indent_round_trip():
+    df = polars.DataFrame({'input': ['def f():\n    return 1\n\nx = 2']})
+    df = df.with_columns(output=dedent(indent('input', prefix='    ')))
+    assert df['output'].to_list() == df['input'].to_list()
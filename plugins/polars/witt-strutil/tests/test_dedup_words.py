@@ -0,0 +1,73 @@
+# test_dedup_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import dedup_words
+import polars
+
+def test_dedup_words():
+    df = polars.DataFrame({
+        'input': [
+            'the the quick quick fox',
+            'the quick the fox',
+            '  spaced    out   out  ',
+            'The the fox',
+            '',
+            None,
+        ],
+        'expected': [
+            'the quick fox',
+            'the quick the fox',
+            'spaced out',
+            'The the fox',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=dedup_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_dedup_words_global():
+    df = polars.DataFrame({
+        'input': [
+            'the quick the fox',
+            'a b a b c',
+        ],
+        'expected': [
+            'the quick fox',
+            'a b c',
+        ],
+    })
+    df = df.with_columns(output=dedup_words('input', consecutive_only=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_dedup_words_case_insensitive():
+    df = polars.DataFrame({
+        'input': [
+            'The the fox',
+            'The quick THE fox',
+        ],
+        'expected': [
+            'The fox',
+            'The quick THE fox',
+        ],
+    })
+    df = df.with_columns(output=dedup_words('input', case_insensitive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,57 @@
+# test_detect_case.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import detect_case
+import polars
+
+def test_detect_case():
+    df = polars.DataFrame({
+        'input': [
+            'foo_bar',
+            'foo-bar',
+            'fooBar',
+            'FooBar',
+            'FOO_BAR',
+            'foo bar',
+            'Foo Bar',
+            'Foo bar baz',
+            'hELLO wORLD',
+            'foo_Bar-baz',
+            '123',
+            '',
+            None,
+        ],
+        'expected': [
+            'snake',
+            'kebab',
+            'camel',
+            'pascal',
+            'upper',
+            'lower',
+            'title',
+            'sentence',
+            'mixed',
+            'mixed',
+            'mixed',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
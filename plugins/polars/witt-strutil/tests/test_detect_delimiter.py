@@ -0,0 +1,73 @@
+# test_detect_delimiter.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import detect_delimiter
+import polars
+
+def test_detect_delimiter():
+    df = polars.DataFrame({
+        'input': [
+            'id,name,city',
+            'id\tname\tcity',
+            'name;note\n"x, y";z\nw;v',
+            'a|b|c\nd|e,f|g',
+            'plain text',
+            'a,b\nc,d,e',
+            None,
+        ],
+        'expected': [
+            ',',
+            '\t',
+            ';',
+            '|',
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_delimiter('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_detect_delimiter_ambiguous():
+    df = polars.DataFrame({
+        'input': [
+            'a,b;c',
+        ],
+        'expected': [
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_delimiter('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_detect_delimiter_candidates():
+    df = polars.DataFrame({
+        'input': [
+            'a:b:c',
+            'a,b,c',
+        ],
+        'expected': [
+            ':',
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_delimiter('input', candidates=[':']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,65 @@
+# test_detect_language.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import detect_language
+import polars
+import pytest
+
+def test_detect_language():
+    df = polars.DataFrame({
+        'input': [
+            'The weather is nice today and we are going to the park.',
+            'I would like a cup of coffee, please.',
+            "Il fait beau aujourd'hui et nous allons au parc.",
+            "Je voudrais une tasse de café, s'il vous plaît.",
+            'Das Wetter ist heute schön und wir gehen in den Park.',
+            'Ich hätte gern eine Tasse Kaffee, bitte.',
+            'Hola',
+            '12345 !!!',
+            None,
+        ],
+        'expected': [
+            'en',
+            'en',
+            'fr',
+            'fr',
+            'de',
+            'de',
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_language('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Restrict the candidates
+    df = polars.DataFrame({
+        'input': [
+            'The weather is nice today and we are going to the park.',
+            'Das Wetter ist heute schön und wir gehen in den Park.',
+        ],
+    })
+    df = df.with_columns(output=detect_language('input', candidates=['en', 'fr']))
+    assert df['output'].to_list()[0] == 'en'
+    assert df['output'].to_list()[1] in ('en', 'fr')
+
+def test_detect_language_unsupported_candidate():
+    df = polars.DataFrame({'input': ['hello world, how are you?']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=detect_language('input', candidates=['xx']))
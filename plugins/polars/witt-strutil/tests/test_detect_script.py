@@ -0,0 +1,55 @@
+# test_detect_script.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import detect_script
+import polars
+
+def test_detect_script():
+    df = polars.DataFrame({
+        'input': [
+            'Hello world',
+            'Привет мир and hi',
+            '中文字符',
+            'مرحبا',
+            'ab вг',
+            '123 !!',
+            '',
+            None,
+        ],
+        'expected': [
+            'Latin',
+            'Cyrillic',
+            'Han',
+            'Arabic',
+            None,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=detect_script('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_detect_script_include_common():
+    df = polars.DataFrame({'input': ['12345 abc', '123 !!']})
+    df = df.select(letters=detect_script('input'),
+                   common=detect_script('input', include_common=True))
+
+    assert df['letters'].to_list() == ['Latin', None]
+    assert df['common'].to_list() == ['Common', 'Common']
\ No newline at end of file
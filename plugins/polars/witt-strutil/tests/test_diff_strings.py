@@ -0,0 +1,73 @@
+# test_diff_strings.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import diff_strings
+import polars
+
+def test_diff_strings():
+    df = polars.DataFrame({
+        'input': [
+            'color',
+            'kitten',
+            'same',
+            '',
+            'abc',
+            None,
+        ],
+        'other': [
+            'colour',
+            'sitting',
+            'same',
+            'new',
+            '',
+            'x',
+        ],
+        'expected': [
+            'colo{+u+}r',
+            '[-k-]{+s+}itt[-e-]{+i+}n{+g+}',
+            'same',
+            '{+new+}',
+            '[-abc-]',
+            None,
+        ],
+    })
+    df = df.with_columns(output=diff_strings('input', 'other'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_diff_strings_word():
+    df = polars.DataFrame({
+        'input': [
+            'the quick fox',
+            'hello world',
+            'keep this line',
+        ],
+        'other': [
+            'the slow fox',
+            'hello, world',
+            'keep this line',
+        ],
+        'expected': [
+            'the [-quick-]{+slow+} fox',
+            'hello{+,+} world',
+            'keep this line',
+        ],
+    })
+    df = df.with_columns(output=diff_strings('input', 'other', mode='word'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
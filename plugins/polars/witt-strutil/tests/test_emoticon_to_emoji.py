@@ -0,0 +1,77 @@
+# test_emoticon_to_emoji.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import emoji_to_emoticon
+from witt_strutil import emoticon_to_emoji
+import polars
+
+def test_emoticon_to_emoji():
+    df = polars.DataFrame({
+        'input': [
+            ':) hello :-)',
+            'lol :D :-D XD',
+            'nice ;-), sad :(',
+            'love <3 and </3',
+            'ok :P. wow :O!',
+            'see http://example.com f(x) a:)',
+            None,
+        ],
+        'expected': [
+            '\U0001f642 hello \U0001f642',
+            'lol \U0001f603 \U0001f603 \U0001f606',
+            'nice \U0001f609, sad \U0001f641',
+            'love \u2764\ufe0f and \U0001f494',
+            'ok \U0001f61b. wow \U0001f62e!',
+            'see http://example.com f(x) a:)',
+            None,
+        ],
+    })
+    df = df.with_columns(output=emoticon_to_emoji('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_emoji_to_emoticon_round_trip():
+    df = polars.DataFrame({
+        'input': [
+            ':) hello',
+            ':D :( ;)',
+            'love <3',
+            ":'( :P",
+            None,
+        ],
+    })
+    df = df.with_columns(output=emoji_to_emoticon(emoticon_to_emoji('input')))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+def test_emoji_to_emoticon():
+    df = polars.DataFrame({
+        'input': [
+            'I \u2764 it \u2764\ufe0f',
+            '\U0001f642\U0001f642',
+            'no emoji here',
+        ],
+        'expected': [
+            'I <3 it <3',
+            ':):)',
+            'no emoji here',
+        ],
+    })
+    df = df.with_columns(output=emoji_to_emoticon('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
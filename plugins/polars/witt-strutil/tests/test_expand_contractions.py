@@ -0,0 +1,58 @@
+# test_expand_contractions.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import expand_contractions
+import polars
+
+def test_expand_contractions():
+    df = polars.DataFrame({
+        'input': [
+            "I don't know, they're here",
+            "Don't go. He's fine",
+            "Don’t go. It’s late",
+            "DON'T i'm",
+            "John's car",
+            '',
+            None,
+        ],
+        'expected': [
+            'I do not know, they are here',
+            'Do not go. He is fine',
+            'Do not go. It is late',
+            'DO NOT I am',
+            "John's car",
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=expand_contractions('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_expand_contractions_extra():
+    df = polars.DataFrame({
+        'input': [
+            "He's gone",
+            "y'all'd better",
+        ],
+        'expected': [
+            'He has gone',
+            'you all would better',
+        ],
+    })
+    df = df.with_columns(output=expand_contractions('input', extra={"he's": 'he has', "y'all'd": 'you all would'}))
+    assert df['output'].to_list() == df['expected'].to_list()
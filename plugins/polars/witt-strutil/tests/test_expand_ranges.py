@@ -0,0 +1,72 @@
+# test_expand_ranges.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import expand_ranges
+import polars
+import pytest
+
+def test_expand_ranges():
+    df = polars.DataFrame({
+        'input': [
+            '1-3,5,7-9',
+            ' 10 - 12 , 4',
+            '42',
+            '-3--1,0',
+            '',
+            None,
+        ],
+        'expected': [
+            [1, 2, 3, 5, 7, 8, 9],
+            [10, 11, 12, 4],
+            [42],
+            [-3, -2, -1, 0],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=expand_ranges('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Custom separators and skipping inverted ranges
+    df = polars.DataFrame({
+        'input': [
+            '1..3;6',
+            '5..3;8',
+        ],
+        'expected': [
+            [1, 2, 3, 6],
+            [8],
+        ],
+    })
+    df = df.with_columns(output=expand_ranges('input', range_separator='..', list_separator=';', skip_invalid=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_expand_ranges_inverted():
+    df = polars.DataFrame({'input': ['1-3,9-7']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=expand_ranges('input'))
+
+
+def test_expand_ranges_max_items():
+    df = polars.DataFrame({'input': ['1-1000000000000']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=expand_ranges('input'))
+
+    df = polars.DataFrame({'input': ['1-3,10-20,5']})
+    df = df.with_columns(output=expand_ranges('input', skip_invalid=True, max_items=5))
+    assert df['output'].to_list() == [[1, 2, 3, 5]]
\ No newline at end of file
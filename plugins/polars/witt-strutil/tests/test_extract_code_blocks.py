@@ -0,0 +1,55 @@
+# test_extract_code_blocks.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import extract_code_blocks
+import polars
+
+def test_extract_code_blocks():
+    df = polars.DataFrame({
+        'input': [
+            'Intro\n```python\nprint("hi")\n```\ntext\n```sql\nSELECT 1;\n```',
+            '  ~~~ rust\n  fn main() {}\n      indented\n  ~~~',
+            '````markdown\n```js\nx\n```\n````',
+            '```\nplain\n```',
+            '```js\nunclosed',
+            'no code',
+            None,
+        ],
+        'expected': [
+            [
+                {'language': 'python', 'code': 'print("hi")'},
+                {'language': 'sql',    'code': 'SELECT 1;'},
+            ],
+            [
+                {'language': 'rust', 'code': 'fn main() {}\n    indented'},
+            ],
+            [
+                {'language': 'markdown', 'code': '```js\nx\n```'},
+            ],
+            [
+                {'language': None, 'code': 'plain'},
+            ],
+            [
+                {'language': 'js', 'code': 'unclosed'},
+            ],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_code_blocks('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
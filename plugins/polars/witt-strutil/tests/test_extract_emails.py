@@ -0,0 +1,51 @@
+# test_extract_emails.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import extract_emails
+import polars
+
+def test_extract_emails():
+    df = polars.DataFrame({
+        'input': [
+            'Contact John.Doe@Example.com or jane+news@mail.example.co.uk.',
+            'price @ 5, user@localhost, @handle, foo@bar..com',
+            'no email here',
+            '',
+            None,
+        ],
+        'expected': [
+            ['John.Doe@Example.com', 'jane+news@mail.example.co.uk'],
+            [],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_emails('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = polars.DataFrame({
+        'input': [
+            'Contact John.Doe@Example.com or jane+news@mail.example.co.uk.',
+        ],
+        'expected': [
+            ['john.doe@example.com', 'jane+news@mail.example.co.uk'],
+        ],
+    })
+    df = df.with_columns(output=extract_emails('input', lowercase=True))
+    assert df['output'].to_list() == df['expected'].to_list()
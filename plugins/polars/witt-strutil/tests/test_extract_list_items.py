@@ -0,0 +1,52 @@
+# test_extract_list_items.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import extract_list_items
+import polars
+
+LIST = '''Groceries:
+- Fruit
+  1. Apples
+     the green ones
+  2) Pears
+- Bread
+* Milk
+a) Eggs'''
+
+def test_extract_list_items():
+    df = polars.DataFrame({
+        'input': [
+            LIST,
+            'No list here',
+            None,
+        ],
+        'expected': [
+            ['Fruit', 'Apples the green ones', 'Pears', 'Bread', 'Milk', 'Eggs'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_list_items('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_extract_list_items_preserve_depth():
+    df = polars.DataFrame({'input': [LIST]})
+    df = df.with_columns(output=extract_list_items('input', preserve_depth=True))
+
+    assert df['output'].to_list() == [['Fruit', '  Apples the green ones', '  Pears', 'Bread', 'Milk', 'Eggs']]
\ No newline at end of file
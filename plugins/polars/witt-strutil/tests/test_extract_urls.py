@@ -0,0 +1,54 @@
+# test_extract_urls.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import extract_urls
+import polars
+
+def test_extract_urls():
+    df = polars.DataFrame({
+        'input': [
+            'See https://example.com and http://foo.org/bar for details.',
+            'Search at https://example.com/search?q=polars&page=2.',
+            '(see https://en.wikipedia.org/wiki/Foo_(bar))',
+            'Visit example.com or www.example.com today',
+            'Download from ftp://files.example.com/pub',
+            None,
+        ],
+        'expected': [
+            ['https://example.com', 'http://foo.org/bar'],
+            ['https://example.com/search?q=polars&page=2'],
+            ['https://en.wikipedia.org/wiki/Foo_(bar)'],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_urls('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Restrict the schemes
+    df = polars.DataFrame({
+        'input': [
+            'See https://example.com or ftp://files.example.com/pub!',
+        ],
+        'expected': [
+            ['ftp://files.example.com/pub'],
+        ],
+    })
+    df = df.with_columns(output=extract_urls('input', schemes=['ftp']))
+    assert df['output'].to_list() == df['expected'].to_list()
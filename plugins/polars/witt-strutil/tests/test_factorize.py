@@ -0,0 +1,45 @@
+# test_factorize.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import factorize
+import polars
+
+def test_factorize():
+    df = polars.DataFrame({
+        'input': [
+            'banana',
+            'apple',
+            None,
+            'banana',
+            'cherry',
+            'apple',
+            '',
+        ],
+        'expected': [
+            0,
+            1,
+            None,
+            0,
+            2,
+            1,
+            3,
+        ],
+    })
+    df = df.with_columns(output=factorize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
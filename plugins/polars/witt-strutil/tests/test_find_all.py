@@ -0,0 +1,92 @@
+# test_find_all.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import find_all
+import polars
+
+def test_find_all():
+    df = polars.DataFrame({
+        'input': [
+            'aaaa',
+            'Hello hello HELLO',
+            'héllo lo',
+            'abc',
+            'abc',
+            None,
+        ],
+        'needle': [
+            'aa',
+            'hello',
+            'lo',
+            '',
+            'x',
+            'a',
+        ],
+        'expected': [
+            [0, 2],
+            [6],
+            [3, 6],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=find_all('input', 'needle'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_find_all_overlapping():
+    df = polars.DataFrame({
+        'input': [
+            'aaaa',
+            'abababa',
+        ],
+        'needle': [
+            'aa',
+            'aba',
+        ],
+        'expected': [
+            [0, 1, 2],
+            [0, 2, 4],
+        ],
+    })
+    df = df.with_columns(output=find_all('input', 'needle', overlapping=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_find_all_case_insensitive():
+    df = polars.DataFrame({
+        'input': [
+            'Hello hello HELLO',
+        ],
+        'needle': [
+            'hElLo',
+        ],
+        'expected': [
+            [0, 6, 12],
+        ],
+    })
+    df = df.with_columns(output=find_all('input', 'needle', case_insensitive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_find_all_literal():
+    df = polars.DataFrame({'input': ['a-b-c', 'abc']})
+    df = df.with_columns(output=find_all('input', polars.lit('-')))
+
+    assert df['output'].to_list() == [[1, 3], []]
\ No newline at end of file
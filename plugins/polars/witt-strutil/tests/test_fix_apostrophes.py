@@ -0,0 +1,49 @@
+# test_fix_apostrophes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import fix_apostrophes
+import polars
+
+def test_fix_apostrophes():
+    df = polars.DataFrame({
+        'input': [
+            'don\u2019t',
+            'I\u2019m sure it\u2019s fine',
+            'O\u2019Brien',
+            'D\u2018Angelo',
+            'Hawai\u02bbi',
+            '\u2018quoted\u2019',
+            'he said \u2018I can\u2019t\u2019 twice',
+            "O'Brien",
+            None,
+        ],
+        'expected': [
+            "don't",
+            "I'm sure it's fine",
+            "O'Brien",
+            "D'Angelo",
+            'Hawai\u02bbi',
+            '\u2018quoted\u2019',
+            "he said \u2018I can't\u2019 twice",
+            "O'Brien",
+            None,
+        ],
+    })
+    df = df.with_columns(output=fix_apostrophes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
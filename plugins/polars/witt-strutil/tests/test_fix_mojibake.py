@@ -0,0 +1,70 @@
+# test_fix_mojibake.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import fix_mojibake
+import polars
+
+def test_fix_mojibake():
+    df = polars.DataFrame({
+        'input': [
+            'CafÃ©',
+            'Itâ€™s',
+            'naÃ¯ve â€” ok',
+            'ï»¿hello',
+            'CafÃƒÂ©',
+            None,
+        ],
+        'expected': [
+            'Café',
+            'It\u2019s',
+            'naïve \u2014 ok',
+            'hello',
+            'CafÃ©',
+            None,
+        ],
+    })
+    df = df.with_columns(output=fix_mojibake('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_fix_mojibake_clean():
+    df = polars.DataFrame({
+        'input': [
+            'Café déjà vu',
+            'It\u2019s plain',
+            '',
+        ],
+    })
+    df = df.with_columns(output=fix_mojibake('input', aggressive=True))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+def test_fix_mojibake_aggressive():
+    df = polars.DataFrame({
+        'input': [
+            'CafÃƒÂ©',
+            'CafÃ©',
+        ],
+        'expected': [
+            'Café',
+            'Café',
+        ],
+    })
+    df = df.with_columns(output=fix_mojibake('input', aggressive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
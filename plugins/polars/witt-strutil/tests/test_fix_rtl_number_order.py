@@ -0,0 +1,42 @@
+# test_fix_rtl_number_order.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import fix_rtl_number_order
+import polars
+
+def test_fix_rtl_number_order():
+    df = polars.DataFrame({
+        'input': [
+            'שלום 123 עולם 3.14',
+            'مرحبا 2024-01-01',
+            'שלום \u206612\u2069',
+            'plain 123',
+            '',
+            None,
+        ],
+        'expected': [
+            'שלום \u2066123\u2069 עולם \u20663.14\u2069',
+            'مرحبا \u20662024-01-01\u2069',
+            'שלום \u206612\u2069',
+            'plain 123',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=fix_rtl_number_order('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,69 @@
+# test_flesch_reading_ease.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import flesch_kincaid_grade
+from witt_strutil import flesch_reading_ease
+import polars
+
+def test_flesch_reading_ease():
+    df = polars.DataFrame({
+        'input': [
+            'The cat sat on the mat.',
+            '',
+            None,
+        ],
+        'expected': [
+            116.145,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=flesch_reading_ease('input').round(6))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_flesch_kincaid_grade():
+    df = polars.DataFrame({
+        'input': [
+            'The cat sat on the mat.',
+            '',
+            None,
+        ],
+        'expected': [
+            -1.45,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=flesch_kincaid_grade('input').round(6))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_flesch_simple_and_complex():
+    df = polars.DataFrame({
+        'input': [
+            'The cat sat on the mat. It was warm.',
+            'Notwithstanding considerable institutional opposition, the administration implemented comprehensive organizational restructuring initiatives.',
+        ],
+    })
+    df = df.with_columns(ease=flesch_reading_ease('input'),
+                         grade=flesch_kincaid_grade('input'))
+    simple, complex = df.rows()
+
+    assert simple[1] > complex[1]
+    assert simple[2] < complex[2]
\ No newline at end of file
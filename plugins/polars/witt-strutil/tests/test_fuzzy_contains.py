@@ -0,0 +1,69 @@
+# test_fuzzy_contains.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import fuzzy_contains
+import polars
+
+def test_fuzzy_contains():
+    df = polars.DataFrame({
+        'input': [
+            'Brand new Samsumg Galaxy S24, sealed',
+            'Brand new Samsung Galxy S24, sealed',
+            'Brand new SAMSUNG Galaxy S24, sealed',
+            'Brand new Samsnug Galaxy S24, sealed',
+            'Brand new Pixel 8, sealed',
+            'lorem ipsum',
+            None,
+        ],
+        'needle': [
+            'Samsung',
+            'Galaxy',
+            'Samsung',
+            'Samsung',
+            'Samsung',
+            '',
+            'lorem',
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+            False,
+            False,
+            True,
+            None,
+        ],
+    })
+    df = df.with_columns(output=fuzzy_contains('input', 'needle', max_edits=1))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_fuzzy_contains_edit_budget():
+    df = polars.DataFrame({
+        'input': [
+            'Brand new Samsnug Galaxy S24, sealed',
+            'Brand new SAMSUNG Galaxy S24, sealed',
+        ],
+        'expected': [
+            True,
+            False,
+        ],
+    })
+    df = df.with_columns(output=fuzzy_contains('input', polars.lit('Samsung'), max_edits=2, case_insensitive=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,77 @@
+# test_grapheme_length.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import display_width
+from witt_strutil import grapheme_length
+import polars
+
+def test_grapheme_length():
+    df = polars.DataFrame({
+        'input': [
+            'abc',
+            'e\u0301',
+            '\U0001F468\u200d\U0001F469\u200d\U0001F467',
+            '日本語',
+            '',
+            None,
+        ],
+        'expected': [
+            3,
+            1,
+            1,
+            3,
+            0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=grapheme_length('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_display_width():
+    df = polars.DataFrame({
+        'input': [
+            'abc',
+            'e\u0301',
+            '\U0001F468\u200d\U0001F469\u200d\U0001F467',
+            '日本語',
+            'a\u200bb',
+            '',
+            None,
+        ],
+        'expected': [
+            3,
+            1,
+            2,
+            6,
+            2,
+            0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=display_width('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_lengths_differ():
+    df = polars.DataFrame({'input': ['\U0001F468\u200d\U0001F469\u200d\U0001F467 日本']})
+    df = df.select(chars=polars.col('input').str.len_chars(),
+                   graphemes=grapheme_length('input'),
+                   width=display_width('input'))
+
+    assert df.row(0) == (8, 4, 7)
\ No newline at end of file
@@ -0,0 +1,74 @@
+# test_hamming_distance.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import hamming_distance
+import polars
+
+def test_hamming_distance():
+    df = polars.DataFrame({
+        'left': [
+            'GATTACA',
+            'karolin',
+            'abc',
+            'héllo',
+            '',
+            'abc',
+            None,
+        ],
+        'right': [
+            'GACTATA',
+            'kathrin',
+            'abc',
+            'hello',
+            '',
+            'abcde',
+            'abc',
+        ],
+        'expected': [
+            2,
+            3,
+            0,
+            1,
+            0,
+            None,
+            None,
+        ],
+    }, schema_overrides={'expected': polars.UInt32})
+    df = df.with_columns(output=hamming_distance('left', 'right'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Count the length difference as mismatches
+    df = polars.DataFrame({
+        'left': [
+            'abc',
+            'abx',
+            '',
+        ],
+        'right': [
+            'abcde',
+            'abcde',
+            'abc',
+        ],
+        'expected': [
+            2,
+            3,
+            3,
+        ],
+    }, schema_overrides={'expected': polars.UInt32})
+    df = df.with_columns(output=hamming_distance('left', 'right', pad_mismatch=True))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,73 @@
+# test_has_repeated_word.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import first_repeated_word
+from witt_strutil import has_repeated_word
+import polars
+
+def test_has_repeated_word():
+    df = polars.DataFrame({
+        'input': [
+            'I saw the the cat',
+            'The the end',
+            'it is is, ok',
+            'that. That is fine',
+            'no repeats here',
+            '',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+            False,
+            False,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=has_repeated_word('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Case-sensitive
+    df = df.with_columns(output=has_repeated_word('input', case_insensitive=False))
+    assert df['output'].to_list() == [True, False, True, False, False, False, None]
+
+def test_first_repeated_word():
+    df = polars.DataFrame({
+        'input': [
+            'I saw the the cat',
+            'The the end',
+            'it is is, ok',
+            'no repeats here',
+            None,
+        ],
+        'expected': [
+            'the',
+            'The',
+            'is',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=first_repeated_word('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Case-sensitive
+    df = df.with_columns(output=first_repeated_word('input', case_insensitive=False))
+    assert df['output'].to_list() == ['the', None, 'is', None, None]
@@ -0,0 +1,58 @@
+# test_hash_string.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import hash_string
+import polars
+
+def test_hash_string_hex():
+    df = polars.DataFrame({'input': ['abc', '', None]})
+    df = df.with_columns(md5=hash_string('input', algorithm='md5'),
+                         sha1=hash_string('input', algorithm='sha1'),
+                         sha256=hash_string('input', algorithm='sha256'))
+
+    assert df['md5'].to_list() == [
+        '900150983cd24fb0d6963f7d28e17f72',
+        'd41d8cd98f00b204e9800998ecf8427e',
+        None,
+    ]
+    assert df['sha1'].to_list() == [
+        'a9993e364706816aba3e25717850c26c9cd0d89d',
+        'da39a3ee5e6b4b0d3255bfef95601890afd80709',
+        None,
+    ]
+    assert df['sha256'].to_list() == [
+        'ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad',
+        'e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855',
+        None,
+    ]
+
+def test_hash_string_base64():
+    df = polars.DataFrame({'input': ['abc']})
+    df = df.with_columns(md5=hash_string('input', algorithm='md5', output='base64'),
+                         sha1=hash_string('input', algorithm='sha1', output='base64'),
+                         sha256=hash_string('input', algorithm='sha256', output='base64'))
+
+    assert df['md5'].to_list() == ['kAFQmDzST7DWlj99KOF/cg==']
+    assert df['sha1'].to_list() == ['qZk+NkcGgWq6PiVxeFDCbJzQ2J0=']
+    assert df['sha256'].to_list() == ['ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=']
+
+def test_hash_string_binary():
+    df = polars.DataFrame({'input': [b'abc', None]})
+    df = df.with_columns(output=hash_string('input', algorithm='md5'))
+
+    assert df['output'].to_list() == ['900150983cd24fb0d6963f7d28e17f72', None]
\ No newline at end of file
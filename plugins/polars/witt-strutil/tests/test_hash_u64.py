@@ -0,0 +1,44 @@
+# test_hash_u64.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import hash_u64
+import polars
+
+def test_hash_u64():
+    df = polars.DataFrame({'input': ['abc', '', None]})
+    df = df.with_columns(output=hash_u64('input'))
+
+    # Reference values of XXH64 with a zero seed
+    assert df['output'].to_list() == [0x44bc2cf5ad770999, 0xef46db3751d8e999, None]
+    assert df['output'].dtype == polars.UInt64
+
+def test_hash_u64_deterministic():
+    df = polars.DataFrame({'input': ['lorem', 'ipsum', 'lorem']})
+    df = df.with_columns(first=hash_u64('input', seed=42),
+                         second=hash_u64('input', seed=42))
+
+    assert df['first'].to_list() == df['second'].to_list()
+    assert df['first'][0] == df['first'][2]
+    assert df['first'][0] != df['first'][1]
+
+def test_hash_u64_seed():
+    df = polars.DataFrame({'input': ['lorem']})
+    df = df.with_columns(first=hash_u64('input', seed=1),
+                         second=hash_u64('input', seed=2))
+
+    assert df['first'][0] != df['second'][0]
\ No newline at end of file
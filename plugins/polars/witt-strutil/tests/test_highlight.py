@@ -0,0 +1,70 @@
+# test_highlight.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import highlight
+import polars
+
+def test_highlight():
+    df = polars.DataFrame({
+        'input': [
+            'the quick fox',
+            'fox and fox',
+            'aaaa',
+            'Fox',
+            None,
+        ],
+        'needle': [
+            'fox',
+            'fox',
+            'aa',
+            'fox',
+            'fox',
+        ],
+        'expected': [
+            'the quick **fox**',
+            '**fox** and **fox**',
+            '**aa****aa**',
+            'Fox',
+            None,
+        ],
+    })
+    df = df.with_columns(output=highlight('input', 'needle'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_highlight_case_insensitive():
+    df = polars.DataFrame({
+        'input': [
+            'Fox fox FOX',
+        ],
+        'needle': [
+            'fOx',
+        ],
+        'expected': [
+            '<mark>Fox</mark> <mark>fox</mark> <mark>FOX</mark>',
+        ],
+    })
+    df = df.with_columns(output=highlight('input', 'needle', prefix='<mark>', suffix='</mark>', case_insensitive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_highlight_empty_needle():
+    df = polars.DataFrame({'input': ['the quick fox']})
+    df = df.with_columns(output=highlight('input', polars.lit('')))
+
+    assert df['output'].to_list() == df['input'].to_list()
\ No newline at end of file
@@ -0,0 +1,52 @@
+# test_highlight_keywords.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import highlight_keywords
+import polars
+
+def test_highlight_keywords():
+    df = polars.DataFrame({
+        'input': [
+            'foobar foo bar',
+            'Rust and rustacean love RUST',
+            'nothing here',
+            None,
+        ],
+        'expected': [
+            '[foobar] [foo] [bar]',
+            'Rust and [rustacean] love RUST',
+            'nothing here',
+            None,
+        ],
+    })
+    df = df.with_columns(output=highlight_keywords('input', ['foo', 'foobar', 'bar', 'rust', 'rustacean'], prefix='[', suffix=']'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_highlight_keywords_case_insensitive():
+    df = polars.DataFrame({
+        'input': [
+            'Rust and rustacean love RUST',
+        ],
+        'expected': [
+            '**Rust** and **rustacean** love **RUST**',
+        ],
+    })
+    df = df.with_columns(output=highlight_keywords('input', ['rust', 'rustacean'], case_insensitive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
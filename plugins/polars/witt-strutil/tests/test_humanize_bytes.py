@@ -0,0 +1,85 @@
+# test_humanize_bytes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import humanize_bytes
+import polars
+
+def test_humanize_bytes_binary():
+    df = polars.DataFrame({
+        'input': [
+            0,
+            1023,
+            1024,
+            1536,
+            1048575,
+            -2300000,
+            None,
+        ],
+        'expected': [
+            '0 B',
+            '1023 B',
+            '1.0 KiB',
+            '1.5 KiB',
+            '1.0 MiB',
+            '-2.2 MiB',
+            None,
+        ],
+    })
+    df = df.with_columns(output=humanize_bytes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_humanize_bytes_decimal():
+    df = polars.DataFrame({
+        'input': [
+            0,
+            999,
+            1000,
+            1023,
+            1024,
+            -2300000,
+            None,
+        ],
+        'expected': [
+            '0 B',
+            '999 B',
+            '1.0 kB',
+            '1.0 kB',
+            '1.0 kB',
+            '-2.3 MB',
+            None,
+        ],
+    })
+    df = df.with_columns(output=humanize_bytes('input', binary=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_humanize_bytes_precision():
+    df = polars.DataFrame({
+        'input': [
+            1536,
+            1024,
+        ],
+        'expected': [
+            '1.50 KiB',
+            '1.00 KiB',
+        ],
+    })
+    df = df.with_columns(output=humanize_bytes('input', precision=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
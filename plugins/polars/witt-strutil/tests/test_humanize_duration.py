@@ -0,0 +1,126 @@
+# test_humanize_duration.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import humanize_duration
+from witt_strutil import parse_duration
+import polars
+
+def test_humanize_duration():
+    df = polars.DataFrame({
+        'input': [
+            0,
+            59,
+            3600,
+            7503,
+            90061,
+            -61,
+            None,
+        ],
+        'expected': [
+            '0s',
+            '59s',
+            '1h',
+            '2h 5m 3s',
+            '1d 1h 1m 1s',
+            '-1m 1s',
+            None,
+        ],
+    })
+    df = df.with_columns(output=humanize_duration('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_humanize_duration_long():
+    df = polars.DataFrame({
+        'input': [
+            3600,
+            7503,
+        ],
+        'expected': [
+            '1 hour',
+            '2 hours 5 minutes 3 seconds',
+        ],
+    })
+    df = df.with_columns(output=humanize_duration('input', compact=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_humanize_duration_milliseconds():
+    df = polars.DataFrame({
+        'input': [
+            7503,
+            250,
+        ],
+        'expected': [
+            '7s 503ms',
+            '250ms',
+        ],
+    })
+    df = df.with_columns(output=humanize_duration('input', unit='milliseconds'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_duration():
+    df = polars.DataFrame({
+        'input': [
+            '2h 5m 3s',
+            '1.5h',
+            '90m',
+            '1 day, 2 hours and 3 minutes',
+            '-1m 1s',
+            '3',
+            'soon',
+            None,
+        ],
+        'expected': [
+            7503,
+            5400,
+            5400,
+            93780,
+            -61,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=parse_duration('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_duration_milliseconds():
+    df = polars.DataFrame({
+        'input': [
+            '1.5s',
+            '250ms',
+        ],
+        'expected': [
+            1500,
+            250,
+        ],
+    })
+    df = df.with_columns(output=parse_duration('input', unit='milliseconds'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_duration_round_trip():
+    df = polars.DataFrame({'input': [0, 59, 7503, 90061, -61]})
+    df = df.with_columns(compact=parse_duration(humanize_duration('input')),
+                         long=parse_duration(humanize_duration('input', compact=False)))
+
+    assert df['compact'].to_list() == df['input'].to_list()
+    assert df['long'].to_list() == df['input'].to_list()
\ No newline at end of file
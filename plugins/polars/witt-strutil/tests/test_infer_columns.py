@@ -0,0 +1,48 @@
+# test_infer_columns.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import infer_columns
+import polars
+
+def test_infer_columns():
+    df = polars.DataFrame({
+        'input': [
+            'Name    Age  City\n'
+            'Alice   30   Paris\n'
+            'Bob     4    Oslo\n',
+            '  id  value\n'
+            '  1   lorem\n'
+            '  22  ipsum',
+            'Zo\u00eb   1\n'
+            '\u00c5sa   22',
+            'lorem ipsum',
+            '',
+            None,
+        ],
+        'expected': [
+            [0, 8, 13],
+            [2, 6],
+            [0, 6],
+            [0, 6],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=infer_columns('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
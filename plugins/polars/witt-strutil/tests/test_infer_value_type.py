@@ -0,0 +1,63 @@
+# test_infer_value_type.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import infer_value_type
+import polars
+
+def test_infer_value_type():
+    df = polars.DataFrame({
+        'input': [
+            '42',
+            '-7',
+            '3.14',
+            '1e5',
+            'TRUE',
+            'no',
+            '2024-02-29',
+            '31/12/2024',
+            'ada@example.com',
+            'https://example.com/path?q=1',
+            'hello world',
+            '2023-02-29',
+            'mail ada@example.com',
+            '1,234',
+            '  ',
+            None,
+        ],
+        'expected': [
+            'integer',
+            'integer',
+            'float',
+            'float',
+            'boolean',
+            'boolean',
+            'date',
+            'date',
+            'email',
+            'url',
+            'text',
+            'text',
+            'text',
+            'text',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=infer_value_type('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,55 @@
+# test_insert_soft_hyphens.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import insert_soft_hyphens
+import polars
+
+def test_insert_soft_hyphens():
+    df = polars.DataFrame({
+        'input': [
+            'internationalization is wonderful',
+            'a cat sat',
+            'winter, mother-in-law',
+            '',
+            None,
+        ],
+        'expected': [
+            'in\xadter\xadna\xadtio\xadna\xadli\xadza\xadtion is won\xadder\xadful',
+            'a cat sat',
+            'win\xadter, mother-in-law',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=insert_soft_hyphens('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Removing the soft hyphens gives back the original text
+    assert df['output'].str.replace_all('\xad', '').to_list() == df['input'].to_list()
+
+def test_insert_soft_hyphens_min_word_length():
+    df = polars.DataFrame({
+        'input': [
+            'winter is wonderful',
+        ],
+        'expected': [
+            'winter is won\xadder\xadful',
+        ],
+    })
+    df = df.with_columns(output=insert_soft_hyphens('input', min_word_length=7))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,89 @@
+# test_interleave.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import interleave
+import polars
+
+def test_interleave():
+    df = polars.DataFrame({
+        'left': [
+            'abc',
+            'abcde',
+            'ab',
+            '',
+            None,
+        ],
+        'right': [
+            '123',
+            '12',
+            '1234',
+            'xy',
+            'a',
+        ],
+        'expected': [
+            'a1b2c3',
+            'a1b2cde',
+            'a1b234',
+            'xy',
+            None,
+        ],
+    })
+    df = df.with_columns(output=interleave('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_interleave_truncate():
+    df = polars.DataFrame({
+        'left': [
+            'abc',
+            'abcde',
+            'ab',
+        ],
+        'right': [
+            '123',
+            '12',
+            '1234',
+        ],
+        'expected': [
+            'a1b2c3',
+            'a1b2',
+            'a1b2',
+        ],
+    })
+    df = df.with_columns(output=interleave('left', 'right', pad=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_interleave_graphemes():
+    df = polars.DataFrame({
+        'left': [
+            '日本',
+            'e\u0301x',
+        ],
+        'right': [
+            '\U0001F1EF\U0001F1F5\U0001F1EB\U0001F1F7',
+            '12',
+        ],
+        'expected': [
+            '日\U0001F1EF\U0001F1F5本\U0001F1EB\U0001F1F7',
+            'e\u03011x2',
+        ],
+    })
+    df = df.with_columns(output=interleave('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,44 @@
+# test_ip_version.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import ip_version
+import polars
+
+def test_ip_version():
+    df = polars.DataFrame({
+        'input': [
+            '192.168.1.1',
+            '2001:db8::1',
+            '::ffff:192.168.1.1',
+            '256.1.1.1',
+            'gggg::1',
+            None,
+        ],
+        'expected': [
+            4,
+            6,
+            6,
+            None,
+            None,
+            None,
+        ],
+    }, schema_overrides={'expected': polars.Int8})
+    df = df.with_columns(output=ip_version('input'))
+
+    assert df['output'].dtype == polars.Int8
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,86 @@
+# test_ipv4_to_int.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import int_to_ipv4, ipv4_to_int
+import polars
+
+def test_ipv4_to_int():
+    df = polars.DataFrame({
+        'input': [
+            '0.0.0.0',
+            '0.0.0.1',
+            '10.0.0.1',
+            '192.168.1.1',
+            '255.255.255.255',
+            '256.0.0.1',
+            '2001:db8::1',
+            'localhost',
+            None,
+        ],
+        'expected': [
+            0,
+            1,
+            167772161,
+            3232235777,
+            4294967295,
+            None,
+            None,
+            None,
+            None,
+        ],
+    }, schema_overrides={'expected': polars.UInt32})
+    df = df.with_columns(output=ipv4_to_int('input'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_int_to_ipv4():
+    df = polars.DataFrame({
+        'input': [
+            0,
+            167772161,
+            4294967295,
+            4294967296,
+            -1,
+            None,
+        ],
+        'expected': [
+            '0.0.0.0',
+            '10.0.0.1',
+            '255.255.255.255',
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=int_to_ipv4('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_ipv4_round_trip():
+    df = polars.DataFrame({
+        'input': [
+            '0.0.0.0',
+            '8.8.4.4',
+            '172.16.254.1',
+            '255.255.255.255',
+        ],
+    })
+    df = df.with_columns(output=int_to_ipv4(ipv4_to_int('input')))
+
+    assert df['output'].to_list() == df['input'].to_list()
@@ -0,0 +1,73 @@
+# test_is_anagram.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import is_anagram
+import polars
+
+def test_is_anagram():
+    df = polars.DataFrame({
+        'left': [
+            'listen',
+            'listen',
+            'aab',
+            'Listen',
+            '',
+            None,
+        ],
+        'right': [
+            'silent',
+            'silenu',
+            'abb',
+            'silent',
+            '',
+            'silent',
+        ],
+        'expected': [
+            True,
+            False,
+            False,
+            False,
+            True,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_anagram('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_is_anagram_phrase():
+    df = polars.DataFrame({
+        'left': [
+            'Dormitory',
+            'Madam Curie!',
+            'Listen',
+        ],
+        'right': [
+            'dirty room',
+            'Radium came.',
+            'enlist',
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+        ],
+    })
+    df = df.with_columns(output=is_anagram('left', 'right', ignore_case=True, ignore_whitespace=True, ignore_punctuation=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,63 @@
+# test_is_ascii.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import is_ascii
+from witt_strutil import non_ascii_ratio
+import polars
+
+def test_is_ascii():
+    df = polars.DataFrame({
+        'input': [
+            'clean text',
+            'café',
+            '日本',
+            '',
+            None,
+        ],
+        'expected': [
+            True,
+            False,
+            False,
+            True,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_ascii('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_non_ascii_ratio():
+    df = polars.DataFrame({
+        'input': [
+            'clean text',
+            'café',
+            '日本',
+            '',
+            None,
+        ],
+        'expected': [
+            0.0,
+            0.25,
+            1.0,
+            0.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=non_ascii_ratio('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
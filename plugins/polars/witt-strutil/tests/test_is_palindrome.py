@@ -0,0 +1,62 @@
+# test_is_palindrome.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import is_palindrome
+import polars
+
+def test_is_palindrome():
+    df = polars.DataFrame({
+        'input': [
+            'A man, a plan, a canal: Panama',
+            'racecar',
+            'hello world',
+            'été',
+            'e\u0301te\u0301',
+            '',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            False,
+            True,
+            True,
+            True,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_palindrome('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Strict comparison
+    df = polars.DataFrame({
+        'input': [
+            'A man, a plan, a canal: Panama',
+            'Racecar',
+            'racecar',
+        ],
+        'expected': [
+            False,
+            False,
+            True,
+        ],
+    })
+    df = df.with_columns(output=is_palindrome('input', ignore_case=False, ignore_non_alphanumeric=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,83 @@
+# test_is_valid_email.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import is_valid_email
+from witt_strutil import is_valid_url
+import polars
+
+def test_is_valid_email():
+    df = polars.DataFrame({
+        'input': [
+            'ada@example.com',
+            'a.b+c@sub.example.co.uk',
+            'ada@example.com ',
+            'ada@example',
+            'Contact ada@example.com',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            False,
+            False,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_valid_email('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_is_valid_url():
+    df = polars.DataFrame({
+        'input': [
+            'https://example.com',
+            'http://user:pw@localhost:8080/path?q=1#top',
+            'http://[::1]:80/',
+            'http://192.168.0.1/a',
+            'https://example.com ',
+            'see https://example.com',
+            'https://',
+            'https://-bad-.com',
+            'https://example.com:99999',
+            'ftp://example.com',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+            True,
+            False,
+            False,
+            False,
+            False,
+            False,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_valid_url('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_is_valid_url_allowed_schemes():
+    df = polars.DataFrame({'input': ['ftp://example.com', 'https://example.com']})
+    df = df.with_columns(output=is_valid_url('input', allowed_schemes=['ftp']))
+
+    assert df['output'].to_list() == [True, False]
\ No newline at end of file
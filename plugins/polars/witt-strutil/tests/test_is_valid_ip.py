@@ -0,0 +1,51 @@
+# test_is_valid_ip.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import is_valid_ip
+import polars
+
+def test_is_valid_ip():
+    df = polars.DataFrame({
+        'input': [
+            '192.168.1.1',
+            '0.0.0.0',
+            '2001:db8::1',
+            '::1',
+            '256.1.1.1',
+            '1.2.3',
+            'not an ip',
+            ' 10.0.0.1',
+            '',
+            None,
+        ],
+        'expected': [
+            True,
+            True,
+            True,
+            True,
+            False,
+            False,
+            False,
+            False,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=is_valid_ip('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,72 @@
+# test_jaccard_similarity.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import jaccard_similarity
+import polars
+
+def test_jaccard_similarity():
+    # Word tokens
+    df = polars.DataFrame({
+        'left': [
+            'the quick brown fox',
+            'The Quick',
+            'apple banana',
+            '',
+            '',
+            None,
+        ],
+        'right': [
+            'the lazy brown dog',
+            'the quick',
+            'cherry durian',
+            '',
+            'something',
+            'something',
+        ],
+        'expected': [
+            2 / 6,
+            1.0,
+            0.0,
+            1.0,
+            0.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=jaccard_similarity('left', 'right', mode='word'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Character 2-shingles
+    df = polars.DataFrame({
+        'left': [
+            'night',
+            'abab',
+            'Night',
+        ],
+        'right': [
+            'nacht',
+            'baba',
+            'night',
+        ],
+        'expected': [
+            1 / 7,
+            1.0,
+            0.6,
+        ],
+    })
+    df = df.with_columns(output=jaccard_similarity('left', 'right', mode='char', shingle_size=2, case_insensitive=False))
+    assert df['output'].to_list() == df['expected'].to_list()
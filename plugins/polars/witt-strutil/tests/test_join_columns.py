@@ -0,0 +1,64 @@
+# test_join_columns.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import join_columns
+import polars
+
+def test_join_columns_propagate():
+    df = polars.DataFrame({
+        'street': ['1 Main St', '2 High St'],
+        'unit': [None, 'Apt 4'],
+        'city': ['Springfield', 'Shelbyville'],
+        'expected': [None, '2 High St, Apt 4, Shelbyville'],
+    })
+    df = df.with_columns(output=join_columns('street', 'unit', 'city', separator=', ', null_behavior='propagate'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_join_columns_skip():
+    df = polars.DataFrame({
+        'street': ['1 Main St', '2 High St'],
+        'unit': [None, 'Apt 4'],
+        'city': ['Springfield', 'Shelbyville'],
+        'expected': ['1 Main St, Springfield', '2 High St, Apt 4, Shelbyville'],
+    })
+    df = df.with_columns(output=join_columns('street', 'unit', 'city', separator=', ', null_behavior='skip'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_join_columns_replace():
+    df = polars.DataFrame({
+        'street': ['1 Main St', '2 High St'],
+        'unit': [None, 'Apt 4'],
+        'city': ['Springfield', 'Shelbyville'],
+        'expected': ['1 Main St, -, Springfield', '2 High St, Apt 4, Shelbyville'],
+    })
+    df = df.with_columns(output=join_columns('street', 'unit', 'city', separator=', ', null_behavior='replace', null_replacement='-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_join_columns_skip_empty():
+    df = polars.DataFrame({
+        'street': ['1 Main St', '2 High St'],
+        'unit': ['', 'Apt 4'],
+        'city': ['Springfield', 'Shelbyville'],
+        'expected': ['1 Main St, Springfield', '2 High St, Apt 4, Shelbyville'],
+    })
+    df = df.with_columns(output=join_columns('street', 'unit', 'city', separator=', ', skip_empty=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,60 @@
+# test_json_extract.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import json_extract
+import polars
+import pytest
+
+def test_json_extract():
+    df = polars.DataFrame({
+        'input': [
+            '{"a": {"b": [{"c": 1}, {"c": "two"}]}}',
+            '{"a": {"b": [{"c": {"d": [1, 2]}}]}}',
+            '{"a": {"b": [{"c": null}]}}',
+            '{"a": {"b": []}}',
+            '{"x": 1}',
+            'not json',
+            None,
+        ],
+        'expected': [
+            '1',
+            '{"d":[1,2]}',
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=json_extract('input', path='a.b[0].c'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_json_extract_paths():
+    df = polars.DataFrame({'input': ['{"user": {"name": "Ada", "tags": ["x", "y"], "a.b": true}}']})
+    df = df.select(name=json_extract('input', path='user.name'),
+                   tag=json_extract('input', path='$.user.tags[1]'),
+                   tags=json_extract('input', path='user.tags'),
+                   quoted=json_extract('input', path="user['a.b']"))
+
+    assert df.row(0) == ('Ada', 'y', '["x","y"]', 'true')
+
+def test_json_extract_invalid_path():
+    df = polars.DataFrame({'input': ['{}']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=json_extract('input', path='a[x]'))
\ No newline at end of file
@@ -0,0 +1,69 @@
+# test_keyboard_distance.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import keyboard_distance
+import polars
+
+def test_keyboard_distance():
+    df = polars.DataFrame({
+        'left': [
+            'thr',
+            'teh',
+            'thp',
+            'xqz',
+            'The',
+            'th',
+            None,
+        ],
+        'right': [
+            'the',
+            'the',
+            'the',
+            'the',
+            'the',
+            'the',
+            'the',
+        ],
+        'expected': [
+            0.5,
+            1.0,
+            1.0,
+            3.0,
+            0.0,
+            1.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=keyboard_distance('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_keyboard_distance_ranking():
+    df = polars.DataFrame({'left': ['teh', 'thr', 'xqz'], 'right': ['the', 'the', 'the']})
+    df = df.with_columns(output=keyboard_distance('left', 'right'))
+    swapped, adjacent, far = df['output'].to_list()
+
+    assert adjacent < swapped < far
+
+def test_keyboard_distance_azerty():
+    df = polars.DataFrame({'left': ['mop', 'wet'], 'right': ['lop', 'eet']})
+    df = df.select(qwerty=keyboard_distance('left', 'right'),
+                   azerty=keyboard_distance('left', 'right', layout='azerty'))
+
+    assert df['qwerty'].to_list() == [1.0, 0.5]
+    assert df['azerty'].to_list() == [0.5, 1.0]
\ No newline at end of file
@@ -0,0 +1,59 @@
+# test_kwic.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import kwic
+import polars
+
+def test_kwic():
+    df = polars.DataFrame({
+        'input': [
+            'the cat sat on the mat with another cat.',
+            'Cat',
+            'no match here',
+            '',
+            None,
+        ],
+        'expected': [
+            [
+                {'left': 'the',          'keyword': 'cat',  'right': 'sat on'},
+                {'left': 'with another', 'keyword': 'cat.', 'right': ''},
+            ],
+            [
+                {'left': '', 'keyword': 'Cat', 'right': ''},
+            ],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=kwic('input', keyword='cat', window=2))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_kwic_window():
+    df = polars.DataFrame({
+        'input': [
+            'one two three four five',
+        ],
+        'expected': [
+            [
+                {'left': 'two', 'keyword': 'three', 'right': 'four'},
+            ],
+        ],
+    })
+    df = df.with_columns(output=kwic('input', keyword='three', window=1))
+    assert df['output'].to_list() == df['expected'].to_list()
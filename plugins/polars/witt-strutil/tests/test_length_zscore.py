@@ -0,0 +1,60 @@
+# test_length_zscore.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import length_zscore
+import polars
+
+def test_length_zscore():
+    df = polars.DataFrame({
+        'input': [
+            'abc',
+            'abd',
+            'xyz',
+            'qrs',
+            'this one is far too long',
+            None,
+        ],
+        'expected': [
+            -0.5,
+            -0.5,
+            -0.5,
+            -0.5,
+            2.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=length_zscore('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_length_zscore_zero_variance():
+    df = polars.DataFrame({
+        'input': [
+            'abc',
+            'xyz',
+            None,
+        ],
+        'expected': [
+            0.0,
+            0.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=length_zscore('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,70 @@
+# test_longest_common_substring.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import longest_common_substring
+import polars
+
+def test_longest_common_substring():
+    df = polars.DataFrame({
+        'input': [
+            'quarterly_sales_report_2024',
+            'abcxyz',
+            'lorem',
+            'Hello World',
+            'same',
+            None,
+        ],
+        'other': [
+            'annual_sales_report',
+            'xyzabc',
+            'ipsum',
+            'say hello world!',
+            'same',
+            'lorem',
+        ],
+        'expected': [
+            '_sales_report',
+            'abc',
+            '',
+            'ello ',
+            'same',
+            None,
+        ],
+    })
+    df = df.with_columns(output=longest_common_substring('input', 'other'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_longest_common_substring_case_insensitive():
+    df = polars.DataFrame({
+        'input': [
+            'Hello World',
+            'INVOICE-2024-001',
+        ],
+        'other': [
+            'say hello world!',
+            'invoice-2024-002',
+        ],
+        'expected': [
+            'Hello World',
+            'INVOICE-2024-00',
+        ],
+    })
+    df = df.with_columns(output=longest_common_substring('input', 'other', case_insensitive=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
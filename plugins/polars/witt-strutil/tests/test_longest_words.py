@@ -0,0 +1,67 @@
+# test_longest_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import longest_words
+from witt_strutil import shortest_words
+import polars
+
+def test_longest_words():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox jumps over the lazy dog',
+            'a bb cc d eee',
+            'e\u0301te\u0301 ab, abc!',
+            'one',
+            '',
+            None,
+        ],
+        'expected': [
+            ['quick', 'brown', 'jumps'],
+            ['eee', 'bb', 'cc'],
+            ['e\u0301te\u0301', 'abc', 'ab'],
+            ['one'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=longest_words('input', n=3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_shortest_words():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox jumps over the lazy dog',
+            'a bb cc d eee',
+            'e\u0301te\u0301 ab, abc!',
+            'one',
+            '',
+            None,
+        ],
+        'expected': [
+            ['The', 'fox', 'the'],
+            ['a', 'd', 'bb'],
+            ['ab', 'e\u0301te\u0301', 'abc'],
+            ['one'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=shortest_words('input', n=3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
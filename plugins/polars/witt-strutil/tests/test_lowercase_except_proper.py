@@ -0,0 +1,47 @@
+# test_lowercase_except_proper.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import lowercase_except_proper
+import polars
+
+def test_lowercase_except_proper():
+    df = polars.DataFrame({
+        'input': [
+            'WE FLEW TO PARIS WITH NASA. THEN TO (LONDON)! DONE?',
+            'the IPHONE is made by APPLE',
+            'NOTHING PROPER HERE',
+            '',
+            None,
+        ],
+        'expected': [
+            'We flew to Paris with NASA. Then to (London)! Done?',
+            'The iPhone is made by Apple',
+            'Nothing proper here',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=lowercase_except_proper('input', proper_nouns=['Paris', 'NASA', 'London', 'iPhone', 'Apple']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_lowercase_except_proper_without_nouns():
+    df = polars.DataFrame({'input': ['HELLO, PARIS. BYE!']})
+    df = df.with_columns(output=lowercase_except_proper('input'))
+
+    assert df['output'].to_list() == ['Hello, paris. Bye!']
\ No newline at end of file
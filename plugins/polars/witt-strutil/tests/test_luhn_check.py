@@ -0,0 +1,64 @@
+# test_luhn_check.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import luhn_check
+import polars
+
+def test_luhn_check():
+    df = polars.DataFrame({
+        'input': [
+            '4539578763621486',
+            '4539578763621468',
+            '4539 5787 6362 1486',
+            '4539-5787-6362-1486',
+            '79927398713',
+            '4539x5787',
+            '',
+            None,
+        ],
+        'expected': [
+            True,
+            False,
+            True,
+            True,
+            True,
+            False,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=luhn_check('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_luhn_check_strict():
+    df = polars.DataFrame({
+        'input': [
+            '4539 5787 6362 1486',
+            '4539578763621468',
+            '4539x5787',
+        ],
+        'expected': [
+            True,
+            False,
+            None,
+        ],
+    })
+    df = df.with_columns(output=luhn_check('input', strict=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
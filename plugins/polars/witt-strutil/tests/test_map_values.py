@@ -0,0 +1,60 @@
+# test_map_values.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import map_values
+import polars
+
+def test_map_values():
+    df = polars.DataFrame({
+        'input': [
+            'NY',
+            'CA',
+            'TX',
+            '',
+            None,
+        ],
+        'expected': [
+            'New York',
+            'California',
+            'TX',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=map_values('input', {'NY': 'New York', 'CA': 'California'}))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_map_values_default():
+    df = polars.DataFrame({
+        'input': [
+            'NY',
+            'CA',
+            'TX',
+            None,
+        ],
+        'expected': [
+            'New York',
+            'California',
+            'Other',
+            None,
+        ],
+    })
+    df = df.with_columns(output=map_values('input', {'NY': 'New York', 'CA': 'California'}, default='Other'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
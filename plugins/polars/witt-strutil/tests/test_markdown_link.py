@@ -0,0 +1,82 @@
+# test_markdown_link.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import make_markdown_link
+from witt_strutil import parse_markdown_link
+import polars
+
+def test_make_markdown_link():
+    df = polars.DataFrame({
+        'text': [
+            'Rust',
+            'a [b] c',
+            'wiki',
+            'docs',
+            None,
+        ],
+        'url': [
+            'https://rust-lang.org',
+            'https://example.com/',
+            'https://en.wikipedia.org/wiki/Foo_(bar)',
+            'file:///my docs/a.txt',
+            'https://example.com/',
+        ],
+        'expected': [
+            '[Rust](https://rust-lang.org)',
+            '[a \\[b\\] c](https://example.com/)',
+            '[wiki](https://en.wikipedia.org/wiki/Foo_\\(bar\\))',
+            '[docs](<file:///my docs/a.txt>)',
+            None,
+        ],
+    })
+    df = df.with_columns(output=make_markdown_link('text', 'url'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_markdown_link():
+    df = polars.DataFrame({
+        'input': [
+            'See [one](http://a.com) and [two](<http://b.com/x y> "Title")',
+            '![image](i.png) and [not a link] (x)',
+            'no links',
+            None,
+        ],
+        'expected': [
+            [
+                {'text': 'one', 'url': 'http://a.com'},
+                {'text': 'two', 'url': 'http://b.com/x y'},
+            ],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=parse_markdown_link('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_markdown_link_round_trip():
+    df = polars.DataFrame({
+        'text': ['Rust', 'a [b] c\\d', 'wiki', 'docs'],
+        'url': [
+            'https://rust-lang.org',
+            'https://example.com/',
+            'https://en.wikipedia.org/wiki/Foo_(bar)',
+            'file:///my docs/a.txt',
+        ],
+    })
+    df = df.with_columns(output=parse_markdown_link(make_markdown_link('text', 'url')))
+    assert df['output'].to_list() == [[row] for row in df.select('text', 'url').to_dicts()]
@@ -0,0 +1,54 @@
+# test_markdown_links_to_urls.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import markdown_links_to_urls
+import polars
+
+def test_markdown_links_to_urls():
+    df = polars.DataFrame({
+        'input': [
+            'See [one](http://a.com) and [two](<http://b.com/x y> "Title").',
+            '![image](i.png) and [not a link] here',
+            'Read [the docs][docs] or [Docs].\n\n[docs]: https://docs.example.com\n',
+            'no links',
+            None,
+        ],
+        'expected': [
+            'See http://a.com and http://b.com/x y.',
+            '![image](i.png) and [not a link] here',
+            'Read https://docs.example.com or https://docs.example.com.\n\n',
+            'no links',
+            None,
+        ],
+    })
+    df = df.with_columns(output=markdown_links_to_urls('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_markdown_links_to_text():
+    df = polars.DataFrame({
+        'input': [
+            'See [one](http://a.com) and [two](<http://b.com/x y> "Title").',
+            'Read [the docs][docs] or [Docs].\n\n[docs]: https://docs.example.com\n',
+        ],
+        'expected': [
+            'See one and two.',
+            'Read the docs or Docs.\n\n',
+        ],
+    })
+    df = df.with_columns(output=markdown_links_to_urls('input', keep='text'))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,71 @@
+# test_mask_ip.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import mask_ip
+import polars
+
+def test_mask_ip():
+    df = polars.DataFrame({
+        'input': [
+            'connection from 192.168.1.10 refused',
+            'request from 10.0.0.1.',
+            'ipv6 peer 2001:db8::1 joined',
+            '999.1.1.1 is not an address',
+            'version 1.2.3.4.5 released',
+            'build v1.2.3.4 tagged',
+            'meeting at 12:30:45',
+            'conn from 10.0.0.1:8080',
+            'client 10.0.0.1: refused',
+            'ip:192.168.1.1',
+            'peer fe80::1: gone',
+            'no address here',
+            None,
+        ],
+        'expected': [
+            'connection from 192.*.*.* refused',
+            'request from 10.*.*.*.',
+            'ipv6 peer 2001:*:*:*:*:*:*:* joined',
+            '999.1.1.1 is not an address',
+            'version 1.2.3.4.5 released',
+            'build v1.2.3.4 tagged',
+            'meeting at 12:30:45',
+            'conn from 10.*.*.*:8080',
+            'client 10.*.*.*: refused',
+            'ip:192.*.*.*',
+            'peer fe80:*:*:*:*:*:*:*: gone',
+            'no address here',
+            None,
+        ],
+    })
+    df = df.with_columns(output=mask_ip('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = polars.DataFrame({
+        'input': [
+            '192.168.1.10',
+            'fe80::1',
+        ],
+        'expected': [
+            '192.168.x.x',
+            'fe80:0:x:x:x:x:x:x',
+        ],
+    })
+    df = df.with_columns(output=mask_ip('input', keep_segments=2, mask='x'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,60 @@
+# test_mask_pii.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import mask_pii
+import polars
+
+def test_mask_pii():
+    df = polars.DataFrame({
+        'input': [
+            'Email john.doe@example.com now',
+            'Call +1 (415) 555-2671 today',
+            'Order 123 shipped, call 020 7946 0958.',
+            'Reach a@b.co or 555-1234',
+            'Due 2024-01-15, qty 12 34 56 7',
+            'call 555 123 4567 ext 12 34 56',
+            'Call 555-1234\n020 7946 0958',
+            'Nothing to hide',
+            None,
+        ],
+        'expected': [
+            'Email j***@example.com now',
+            'Call +* (***) ***-2671 today',
+            'Order 123 shipped, call *** **** 0958.',
+            'Reach a***@b.co or ***-1234',
+            'Due 2024-01-15, qty 12 34 56 7',
+            'call *** *** 4567 ext 12 34 56',
+            'Call ***-1234\n*** **** 0958',
+            'Nothing to hide',
+            None,
+        ],
+    })
+    df = df.with_columns(output=mask_pii('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Mask phone numbers only, with a custom character
+    df = polars.DataFrame({
+        'input': [
+            'Email john.doe@example.com or call 555-1234',
+        ],
+        'expected': [
+            'Email john.doe@example.com or call ###-1234',
+        ],
+    })
+    df = df.with_columns(output=mask_pii('input', mask_char='#', mask_emails=False))
+    assert df['output'].to_list() == df['expected'].to_list()
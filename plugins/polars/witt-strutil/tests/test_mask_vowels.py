@@ -0,0 +1,70 @@
+# test_mask_vowels.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import mask_consonants
+from witt_strutil import mask_vowels
+import polars
+
+def test_mask_vowels():
+    df = polars.DataFrame({
+        'input': [
+            'Hello World',
+            'Gym 42, yes!',
+            '',
+            None,
+        ],
+        'expected': [
+            'H*ll* W*rld',
+            'Gym 42, y*s!',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=mask_vowels('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_mask_vowels_treat_y_as_vowel():
+    df = polars.DataFrame({
+        'input': [
+            'Gym 42, yes!',
+        ],
+        'expected': [
+            'G_m 42, __s!',
+        ],
+    })
+    df = df.with_columns(output=mask_vowels('input', mask_char='_', treat_y_as_vowel=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_mask_consonants():
+    df = polars.DataFrame({
+        'input': [
+            'Hello World',
+            'Gym 42, yes!',
+            None,
+        ],
+        'expected': [
+            '*e**o *o***',
+            '*** 42, *e*!',
+            None,
+        ],
+    })
+    df = df.with_columns(output=mask_consonants('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,50 @@
+# test_max_bracket_depth.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import max_bracket_depth
+import polars
+
+def test_max_bracket_depth():
+    df = polars.DataFrame({
+        'input': [
+            'f(a[0], {b: (c)})',
+            '(a) [b] {c}',
+            'no brackets',
+            '((()))',
+            '(a]',
+            '(unclosed',
+            'stray)',
+            '',
+            None,
+        ],
+        'expected': [
+            3,
+            1,
+            0,
+            3,
+            None,
+            None,
+            None,
+            0,
+            None,
+        ],
+    }, schema_overrides={'expected': polars.UInt32})
+    df = df.with_columns(output=max_bracket_depth('input'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,67 @@
+# test_minhash.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import minhash
+import polars
+import pytest
+
+def shared_fraction(a: list[int], b: list[int]) -> float:
+    return sum(x == y for x, y in zip(a, b)) / len(a)
+
+def test_minhash_identical():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox jumps over the lazy dog',
+            'The quick brown fox jumps over the lazy dog',
+            None,
+        ],
+    })
+    df = df.with_columns(output=minhash('input', num_hashes=64, seed=7))
+    signatures = df['output'].to_list()
+
+    assert len(signatures[0]) == 64
+    assert signatures[0] == signatures[1]
+    assert signatures[2] is None
+
+def test_minhash_deterministic():
+    df = polars.DataFrame({'input': ['lorem ipsum dolor sit amet']})
+    df = df.with_columns(first=minhash('input', seed=7),
+                         second=minhash('input', seed=7),
+                         third=minhash('input', seed=8))
+
+    assert df['first'].to_list() == df['second'].to_list()
+    assert df['first'].to_list() != df['third'].to_list()
+
+def test_minhash_near_duplicates():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox jumps over the lazy dog',
+            'The quick brown fox jumped over the lazy dog',
+            'Lorem ipsum dolor sit amet, consectetur',
+        ],
+    })
+    df = df.with_columns(output=minhash('input', num_hashes=128, shingle_size=3, mode='char', seed=7))
+    signatures = df['output'].to_list()
+
+    assert shared_fraction(signatures[0], signatures[1]) > 0.7
+    assert shared_fraction(signatures[0], signatures[2]) < 0.2
+
+def test_minhash_invalid_parameters():
+    df = polars.DataFrame({'input': ['lorem']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=minhash('input', num_hashes=0))
\ No newline at end of file
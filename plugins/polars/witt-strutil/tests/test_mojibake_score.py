@@ -0,0 +1,68 @@
+# test_mojibake_score.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import mojibake_score
+import polars
+
+def test_mojibake_score_clean_text():
+    df = polars.DataFrame({
+        'input': [
+            'hello world',
+            'café crème',
+            'Ünïcödé Straße',
+            '© 2024 — “quoted”',
+            '',
+            None,
+        ],
+        'expected': [
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=mojibake_score('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_mojibake_score_mojibake_text():
+    df = polars.DataFrame({
+        'input': [
+            'cafÃ© crÃ¨me',
+            'Itâ€™s fine',
+            'caf\ufffd cr\ufffdme',
+            'ðŸ˜€ emoji',
+        ],
+        'expected': [
+            1.0,
+            1.0,
+            1.0,
+            1.0,
+        ],
+    })
+    df = df.with_columns(output=mojibake_score('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_mojibake_score_partial():
+    df = polars.DataFrame({'input': ['naïve résumé, Ã© once']})
+    df = df.with_columns(output=mojibake_score('input'))
+
+    assert 0.0 < df['output'][0] < 1.0
\ No newline at end of file
@@ -0,0 +1,89 @@
+# test_ngrams.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import ngrams
+import polars
+
+def test_ngrams():
+    # Word bigrams
+    df = polars.DataFrame({
+        'input': [
+            'the quick brown fox',
+            'hello, world!',
+            'single',
+            '',
+            None,
+        ],
+        'expected': [
+            ['the quick', 'quick brown', 'brown fox'],
+            ['hello world'],
+            [],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=ngrams('input', n=2, mode='word'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Word trigrams
+    df = polars.DataFrame({
+        'input': [
+            'the quick brown fox',
+            'the quick brown',
+            'the quick',
+        ],
+        'expected': [
+            ['the quick brown', 'quick brown fox'],
+            ['the quick brown'],
+            [],
+        ],
+    })
+    df = df.with_columns(output=ngrams('input', n=3, mode='word'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Character bigrams
+    df = polars.DataFrame({
+        'input': [
+            'abcd',
+            'ab',
+            'a',
+        ],
+        'expected': [
+            ['ab', 'bc', 'cd'],
+            ['ab'],
+            [],
+        ],
+    })
+    df = df.with_columns(output=ngrams('input', n=2, mode='char'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Character trigrams over grapheme clusters
+    df = polars.DataFrame({
+        'input': [
+            'abcd',
+            'e\u0301te\u0301',
+            'ab',
+        ],
+        'expected': [
+            ['abc', 'bcd'],
+            ['e\u0301te\u0301'],
+            [],
+        ],
+    })
+    df = df.with_columns(output=ngrams('input', n=3, mode='char'))
+    assert df['output'].to_list() == df['expected'].to_list()
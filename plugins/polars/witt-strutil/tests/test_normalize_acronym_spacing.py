@@ -0,0 +1,68 @@
+# test_normalize_acronym_spacing.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_acronym_spacing
+import polars
+
+def test_normalize_acronym_spacing():
+    df = polars.DataFrame({
+        'input': [
+            'the U. S. A. is big',
+            'U S A',
+            'U.S.A.',
+            'Plan A is fine',
+            'Dr. J. Smith',
+            'J. K. Rowling wrote it',
+            'Grade A I guess',
+            'U. S. A. I think',
+            '',
+            None,
+        ],
+        'expected': [
+            'the USA is big',
+            'USA',
+            'USA',
+            'Plan A is fine',
+            'Dr. J. Smith',
+            'J. K. Rowling wrote it',
+            'Grade A I guess',
+            'USA I think',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_acronym_spacing('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_acronym_spacing_max_token_length():
+    df = polars.DataFrame({
+        'input': [
+            'the UK. EU. deal',
+            'the UK and EU deal',
+            'IT IS',
+            'the U. S. A. is big',
+        ],
+        'expected': [
+            'the UK. EU. deal',
+            'the UK and EU deal',
+            'IT IS',
+            'the USA is big',
+        ],
+    })
+    df = df.with_columns(output=normalize_acronym_spacing('input', max_token_length=2))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,55 @@
+# test_normalize_confusables.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+            '\u201chi\u201d \u2014 it\u2019s',
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_confusables
+import polars
+
+def test_normalize_confusables():
+    df = polars.DataFrame({
+        'input': [
+            'pаypаl',
+            'Аpple Inc​.',
+            '“hi” — it’s',
+            'already latin',
+            '',
+            None,
+        ],
+        'expected': [
+            'paypal',
+            'Apple Inc.',
+            '"hi" - it\'s',
+            'already latin',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_confusables('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert all(value.isascii() for value in df['output'].drop_nulls())
+
+def test_normalize_confusables_keep_whitespace():
+    df = polars.DataFrame({
+        'input': [
+            'gооgle search',
+        ],
+        'expected': [
+            'google search',
+        ],
+    })
+    df = df.with_columns(output=normalize_confusables('input', fold_whitespace=False))
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
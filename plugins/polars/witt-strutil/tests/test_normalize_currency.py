@@ -0,0 +1,62 @@
+# test_normalize_currency.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_currency
+import polars
+
+def test_normalize_currency_to_symbol():
+    df = polars.DataFrame({
+        'input': [
+            'Price: USD 100',
+            '90 euros',
+            '5 Pounds Sterling',
+            'USDT and usd stay',
+            None,
+        ],
+        'expected': [
+            'Price: $100',
+            '90 €',
+            '5 £',
+            'USDT and usd stay',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_currency('input', direction='to_symbol'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_currency_to_code():
+    df = polars.DataFrame({
+        'input': [
+            '$100',
+            '5€',
+            '¥ 2000',
+            'no currency',
+            None,
+        ],
+        'expected': [
+            'USD 100',
+            '5 EUR',
+            'JPY 2000',
+            'no currency',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_currency('input', direction='to_code'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,60 @@
+# test_normalize_join_key.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_join_key
+import polars
+
+def test_normalize_join_key():
+    df = polars.DataFrame({
+        'input': [
+            '  Acme\u200b   Corp  ',
+            'acme corp',
+            'ＡＣＭＥ\u00a0CORP',
+            'Straße',
+            '   ',
+            None,
+        ],
+        'expected': [
+            'acme corp',
+            'acme corp',
+            'acme corp',
+            'strasse',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_join_key('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Differently written keys now match each other
+    assert df['output'][0] == df['output'][1] == df['output'][2]
+
+def test_normalize_join_key_toggles():
+    df = polars.DataFrame({
+        'input': [
+            '  Acme   Corp  ',
+        ],
+        'expected': [
+            ' Acme Corp ',
+        ],
+    })
+    df = df.with_columns(output=normalize_join_key('input', trim=False, casefold=False))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = df.with_columns(output=normalize_join_key('input', collapse_whitespace=False))
+    assert df['output'].to_list() == ['acme   corp']
@@ -0,0 +1,53 @@
+# test_normalize_key.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_key
+import polars
+
+def test_normalize_key():
+    df = polars.DataFrame({
+        'input': [
+            '  The  Café, Inc. ',
+            'Straße',
+            'Café',
+            '',
+            None,
+        ],
+        'expected': [
+            'the cafe, inc.',
+            'strasse',
+            'cafe',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_key('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_key_sort_tokens():
+    df = polars.DataFrame({'input': ['The Café, Inc.', 'cafe the inc']})
+    df = df.with_columns(output=normalize_key('input', remove_punctuation=True, sort_tokens=True))
+
+    assert df['output'].to_list() == ['cafe inc the', 'cafe inc the']
+
+def test_normalize_key_disabled():
+    df = polars.DataFrame({'input': ['  The  Café, Inc. ']})
+    df = df.with_columns(output=normalize_key('input', lowercase=False, strip_accents=False, squeeze_whitespace=False))
+
+    assert df['output'].to_list() == df['input'].to_list()
\ No newline at end of file
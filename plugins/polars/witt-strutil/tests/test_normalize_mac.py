@@ -0,0 +1,51 @@
+# test_normalize_mac.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_mac
+import polars
+
+def test_normalize_mac():
+    df = polars.DataFrame({
+        'input': [
+            'AA:BB:CC:DD:EE:FF',
+            'aa-bb-cc-dd-ee-0f',
+            'AABB.CCDD.EEFF',
+            'aabbccddeeff',
+            ' 00:1a:2b:3c:4d:5e ',
+            'aa:bb:cc:dd:ee',
+            'gg:bb:cc:dd:ee:ff',
+            'aa:bb-cc:dd:ee:ff',
+            'aabb.ccdd.eef',
+            None,
+        ],
+        'expected': [
+            'aa:bb:cc:dd:ee:ff',
+            'aa:bb:cc:dd:ee:0f',
+            'aa:bb:cc:dd:ee:ff',
+            'aa:bb:cc:dd:ee:ff',
+            '00:1a:2b:3c:4d:5e',
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_mac('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
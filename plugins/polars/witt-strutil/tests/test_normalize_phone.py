@@ -0,0 +1,74 @@
+# test_normalize_phone.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_phone
+import polars
+import pytest
+
+def test_normalize_phone_us():
+    df = polars.DataFrame({
+        'input': [
+            '(415) 555-2671',
+            '1-415-555-2671',
+            '415.555.2671',
+            '+1 415 555 2671',
+            '011 44 20 7946 0958',
+            '+14155552671',
+            'not a phone',
+            '123',
+            None,
+        ],
+        'expected': [
+            '+14155552671',
+            '+14155552671',
+            '+14155552671',
+            '+14155552671',
+            '+442079460958',
+            '+14155552671',
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_phone('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_phone_gb():
+    df = polars.DataFrame({
+        'input': [
+            '020 7946 0958',
+            '+44 (0)20 7946 0958',
+            '0044 20 7946 0958',
+            '+14155552671',
+            '(415) 555-2671',
+        ],
+        'expected': [
+            '+442079460958',
+            '+442079460958',
+            '+442079460958',
+            '+14155552671',
+            None,
+        ],
+    })
+    df = df.with_columns(output=normalize_phone('input', default_region='GB'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_phone_unknown_region():
+    df = polars.DataFrame({'input': ['020 7946 0958']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=normalize_phone('input', default_region='XX'))
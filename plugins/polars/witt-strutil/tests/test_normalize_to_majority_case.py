@@ -0,0 +1,66 @@
+# test_normalize_to_majority_case.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import normalize_to_majority_case
+import polars
+
+def test_normalize_to_majority_case():
+    df = polars.DataFrame({
+        'input': [
+            'apple',
+            'BANANA',
+            None,
+            'cherry pie',
+            '123',
+            'Dates',
+        ],
+        'expected': [
+            'apple',
+            'banana',
+            None,
+            'cherry pie',
+            '123',
+            'dates',
+        ],
+    })
+    df = df.with_columns(output=normalize_to_majority_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_to_majority_case_title():
+    df = polars.DataFrame({
+        'input': [
+            'New York',
+            'los angeles',
+            'San Francisco',
+        ],
+        'expected': [
+            'New York',
+            'Los Angeles',
+            'San Francisco',
+        ],
+    })
+    df = df.with_columns(output=normalize_to_majority_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_normalize_to_majority_case_without_style():
+    df = polars.DataFrame({'input': ['123', 'Mixed CASE']})
+    df = df.with_columns(output=normalize_to_majority_case('input'))
+
+    assert df['output'].to_list() == ['123', 'Mixed CASE']
\ No newline at end of file
@@ -0,0 +1,112 @@
+# test_number_to_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import number_to_words
+import polars
+
+def test_number_to_words():
+    df = polars.DataFrame({
+        'input': [
+            0,
+            12,
+            99,
+            100,
+            101,
+            999,
+            1000,
+            1234,
+            1000000,
+            2000021,
+            -7,
+            None,
+        ],
+        'expected': [
+            'zero',
+            'twelve',
+            'ninety-nine',
+            'one hundred',
+            'one hundred one',
+            'nine hundred ninety-nine',
+            'one thousand',
+            'one thousand two hundred thirty-four',
+            'one million',
+            'two million twenty-one',
+            'negative seven',
+            None,
+        ],
+    })
+    df = df.with_columns(output=number_to_words('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_number_to_words_strings():
+    df = polars.DataFrame({
+        'input': [
+            '1234',
+            ' 42 ',
+            'abc',
+            None,
+        ],
+        'expected': [
+            'one thousand two hundred thirty-four',
+            'forty-two',
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=number_to_words('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_number_to_words_ordinal():
+    df = polars.DataFrame({
+        'input': [
+            1,
+            2,
+            12,
+            21,
+            40,
+            100,
+            1000000,
+        ],
+        'expected': [
+            'first',
+            'second',
+            'twelfth',
+            'twenty-first',
+            'fortieth',
+            'one hundredth',
+            'one millionth',
+        ],
+    })
+    df = df.with_columns(output=number_to_words('input', ordinal=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_number_to_words_and_connector():
+    df = polars.DataFrame({
+        'input': [
+            101,
+            1001,
+            1234,
+        ],
+        'expected': [
+            'one hundred and one',
+            'one thousand and one',
+            'one thousand two hundred and thirty-four',
+        ],
+    })
+    df = df.with_columns(output=number_to_words('input', and_connector=True))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,54 @@
+# test_ocr_cleanup.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import ocr_cleanup
+import polars
+
+SAMPLE = 'The inter-\nnational c0mmittee met in Ber1in on 2O2l-05-1O.\n\u201cH e l l o\u201d said SC0TT.'
+
+def test_ocr_cleanup():
+    df = polars.DataFrame({
+        'input': [
+            SAMPLE,
+            'Nothing to fix here.',
+            None,
+        ],
+        'expected': [
+            'The international committee met in Berlin on 2021-05-10.\n"Hello" said SCOTT.',
+            'Nothing to fix here.',
+            None,
+        ],
+    })
+    df = df.with_columns(output=ocr_cleanup('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_ocr_cleanup_toggles():
+    df = polars.DataFrame({'input': [SAMPLE]})
+    df = df.select(quotes_only=ocr_cleanup('input', dehyphenate=False, collapse_spaced_letters=False, fix_confusions=False),
+                   no_quotes=ocr_cleanup('input', normalize_quotes=False))
+
+    assert df.row(0) == ('The inter-\nnational c0mmittee met in Ber1in on 2O2l-05-1O.\n"H e l l o" said SC0TT.',
+                         'The international committee met in Berlin on 2021-05-10.\n\u201cHello\u201d said SCOTT.')
+
+def test_ocr_cleanup_dictionary():
+    df = polars.DataFrame({'input': ['w1n']})
+    df = df.select(guessed=ocr_cleanup('input'),
+                   looked_up=ocr_cleanup('input', dictionary=['win']))
+
+    assert df.row(0) == ('wln', 'win')
\ No newline at end of file
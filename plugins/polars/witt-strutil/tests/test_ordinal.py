@@ -0,0 +1,98 @@
+# test_ordinal.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import ordinal
+import polars
+
+def test_ordinal():
+    df = polars.DataFrame({
+        'input': [
+            1,
+            2,
+            3,
+            4,
+            11,
+            12,
+            13,
+            21,
+            22,
+            23,
+            0,
+            -1,
+            -12,
+            None,
+        ],
+        'expected': [
+            '1st',
+            '2nd',
+            '3rd',
+            '4th',
+            '11th',
+            '12th',
+            '13th',
+            '21st',
+            '22nd',
+            '23rd',
+            '0th',
+            '-1st',
+            '-12th',
+            None,
+        ],
+    })
+    df = df.with_columns(output=ordinal('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_ordinal_hundreds():
+    df = polars.DataFrame({
+        'input': [
+            100,
+            101,
+            102,
+            103,
+            104,
+            105,
+            106,
+            107,
+            108,
+            109,
+            110,
+            111,
+            112,
+            113,
+        ],
+        'expected': [
+            '100th',
+            '101st',
+            '102nd',
+            '103rd',
+            '104th',
+            '105th',
+            '106th',
+            '107th',
+            '108th',
+            '109th',
+            '110th',
+            '111th',
+            '112th',
+            '113th',
+        ],
+    })
+    df = df.with_columns(output=ordinal('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,54 @@
+# test_pad_list_elements.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import pad_list_elements
+import polars
+
+def test_pad_list_elements():
+    df = polars.DataFrame({
+        'input': [
+            ['a', 'bbb', 'cc'],
+            ['xxxxx', None, 'y'],
+            [],
+            None,
+        ],
+        'expected': [
+            ['a  ', 'bbb', 'cc '],
+            ['xxxxx', None, 'y    '],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=pad_list_elements('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_pad_list_elements_column():
+    df = polars.DataFrame({
+        'input': [
+            ['a', 'bbb', 'cc'],
+            ['xxxxx', None, 'y'],
+            None,
+        ],
+        'expected': [
+            ['a    ', 'bbb  ', 'cc   '],
+            ['xxxxx', None, 'y    '],
+            None,
+        ],
+    })
+    df = df.with_columns(output=pad_list_elements('input', scope='column'))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,42 @@
+# test_parallel_rows.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import ngrams, split_by_character_transition
+import polars
+import string
+
+def test_parallel_rows():
+    n_rows = 1_000_000
+    chunk_size = 50_000 # below the parallel threshold
+
+    df = polars.DataFrame({
+        'input': [None if i % 7 == 0 else f'fooBar{i}Baz qux' for i in range(n_rows)],
+    })
+
+    expressions = [
+        split_by_character_transition('input', list(string.ascii_lowercase), list(string.ascii_uppercase)),
+        ngrams('input', n=2, mode='char'),
+    ]
+
+    for expression in expressions:
+        parallel = df.select(output=expression)
+        serial = polars.concat([
+            df.slice(offset, chunk_size).select(output=expression)
+            for offset in range(0, n_rows, chunk_size)
+        ])
+        assert parallel.equals(serial)
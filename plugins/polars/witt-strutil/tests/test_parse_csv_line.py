@@ -0,0 +1,69 @@
+# test_parse_csv_line.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import parse_csv_line
+import polars
+
+def test_parse_csv_line():
+    df = polars.DataFrame({
+        'input': [
+            'a,b,c',
+            '"Smith, John",42,Paris',
+            '"He said ""hi""",ok',
+            'a,b,',
+            '',
+            None,
+        ],
+        'expected': [
+            ['a', 'b', 'c'],
+            ['Smith, John', '42', 'Paris'],
+            ['He said "hi"', 'ok'],
+            ['a', 'b', ''],
+            [''],
+            None,
+        ],
+    })
+    df = df.with_columns(output=parse_csv_line('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_csv_line_trim():
+    df = polars.DataFrame({
+        'input': [
+            ' a , "b, c" ,d ',
+        ],
+        'expected': [
+            ['a', 'b, c', 'd'],
+        ],
+    })
+    df = df.with_columns(output=parse_csv_line('input', trim=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_csv_line_custom_delimiter_and_quote():
+    df = polars.DataFrame({
+        'input': [
+            "'a;b';'it''s';c",
+        ],
+        'expected': [
+            ['a;b', "it's", 'c'],
+        ],
+    })
+    df = df.with_columns(output=parse_csv_line('input', delimiter=';', quote="'"))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
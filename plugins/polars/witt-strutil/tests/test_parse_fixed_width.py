@@ -0,0 +1,49 @@
+# test_parse_fixed_width.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import parse_fixed_width
+import polars
+import pytest
+
+def test_parse_fixed_width():
+    df = polars.DataFrame({
+        'input': [
+            'Alice   30   Paris',
+            'Zo\u00eb     4    \u0141\u00f3d\u017a extra',
+            '\u6771\u4eac\u90fd     12   \u65e5\u672c',
+            'Bob',
+            '',
+            None,
+        ],
+        'expected': [
+            ['Alice', '30', 'Paris'],
+            ['Zo\u00eb', '4', '\u0141\u00f3d\u017a extra'],
+            ['\u6771\u4eac\u90fd', '12', '\u65e5\u672c'],
+            ['Bob', '', ''],
+            ['', '', ''],
+            None,
+        ],
+    })
+    df = df.with_columns(output=parse_fixed_width('input', widths=[8, 5, 5]))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_fixed_width_empty_widths():
+    df = polars.DataFrame({'input': ['lorem']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=parse_fixed_width('input', widths=[]))
\ No newline at end of file
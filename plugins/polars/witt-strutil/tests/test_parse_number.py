@@ -0,0 +1,82 @@
+# test_parse_number.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import parse_number
+import polars
+import pytest
+
+def test_parse_number():
+    df = polars.DataFrame({
+        'input': [
+            '$1,234.56',
+            'approx 42 units',
+            '7 apples and 3 pears',
+            '-12.5%',
+            '.5',
+            'no number',
+            None,
+        ],
+        'expected': [
+            1234.56,
+            42.0,
+            7.0,
+            -12.5,
+            0.5,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=parse_number('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_number_european():
+    df = polars.DataFrame({
+        'input': [
+            '1.234,56',
+            '€ 0,99',
+        ],
+        'expected': [
+            1234.56,
+            0.99,
+        ],
+    })
+    df = df.with_columns(output=parse_number('input', thousands='.', decimal=','))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_number_scale_suffixes():
+    df = polars.DataFrame({
+        'input': [
+            '3.5k',
+            '2M views',
+            '1.2b users',
+            '5 km',
+        ],
+        'expected': [
+            3500.0,
+            2000000.0,
+            1200000000.0,
+            5.0,
+        ],
+    })
+    df = df.with_columns(output=parse_number('input', scale_suffixes=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_parse_number_same_separators():
+    df = polars.DataFrame({'input': ['1,234']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=parse_number('input', thousands=',', decimal=','))
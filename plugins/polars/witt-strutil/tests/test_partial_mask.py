@@ -0,0 +1,62 @@
+# test_partial_mask.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import partial_mask
+import polars
+
+def test_partial_mask():
+    df = polars.DataFrame({
+        'input': [
+            '4111111111111111',
+            '12345678',
+            'abc',
+            '',
+            None,
+        ],
+        'expected': [
+            '4111********1111',
+            '12345678',
+            '***',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=partial_mask('input', keep_start=4, keep_end=4))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_partial_mask_keep_short():
+    df = polars.DataFrame({'input': ['abc', 'abcdefghij']})
+    df = df.with_columns(output=partial_mask('input', keep_start=2, keep_end=2, keep_short=True))
+
+    assert df['output'].to_list() == ['abc', 'ab******ij']
+
+def test_partial_mask_graphemes():
+    df = polars.DataFrame({
+        'input': [
+            'e\u0301te\u0301s jour',
+            '日本語の文',
+        ],
+        'expected': [
+            'e\u0301\u2022\u2022\u2022\u2022\u2022\u2022ur',
+            '日\u2022\u2022の文',
+        ],
+    })
+    df = df.with_columns(output=partial_mask('input', keep_start=1, keep_end=2, mask_char='\u2022'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
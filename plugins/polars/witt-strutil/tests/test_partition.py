@@ -0,0 +1,62 @@
+# test_partition.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import partition
+import polars
+import pytest
+
+def test_partition():
+    df = polars.DataFrame({
+        'input': [
+            'key=value=more',
+            'novalue',
+            '=x',
+            None,
+        ],
+        'expected': [
+            {'before': 'key', 'separator': '=', 'after': 'value=more'},
+            {'before': 'novalue', 'separator': '', 'after': ''},
+            {'before': '', 'separator': '=', 'after': 'x'},
+            None,
+        ],
+    })
+    df = df.with_columns(output=partition('input', separator='='))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_partition_from_end():
+    df = polars.DataFrame({
+        'input': [
+            'key=value=more',
+            'novalue',
+            None,
+        ],
+        'expected': [
+            {'before': 'key=value', 'separator': '=', 'after': 'more'},
+            {'before': 'novalue', 'separator': '', 'after': ''},
+            None,
+        ],
+    })
+    df = df.with_columns(output=partition('input', separator='=', from_end=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_partition_empty_separator():
+    df = polars.DataFrame({'input': ['abc']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=partition('input', separator=''))
\ No newline at end of file
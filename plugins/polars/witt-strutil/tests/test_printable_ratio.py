@@ -0,0 +1,47 @@
+# test_printable_ratio.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import printable_ratio
+import polars
+
+def test_printable_ratio():
+    df = polars.DataFrame({
+        'input': [
+            'clean text',
+            'tabs\tand\nnewlines',
+            'café 日本',
+            '\x00\x01ab',
+            'bad � byte',
+            '\x00\x07\x1b\x7f',
+            '',
+            None,
+        ],
+        'expected': [
+            1.0,
+            1.0,
+            1.0,
+            0.5,
+            0.9,
+            0.0,
+            1.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=printable_ratio('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,60 @@
+# test_redact_between.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import redact_between
+import polars
+
+def test_redact_between():
+    df = polars.DataFrame({
+        'input': [
+            'password={{hunter2}} user={{bob}}',
+            'key={{a\nb}}',
+            'open {{never',
+            '{{}}',
+            None,
+        ],
+        'expected': [
+            'password={{*******}} user={{***}}',
+            'key={{*\n*}}',
+            'open {{never',
+            '{{}}',
+            None,
+        ],
+    })
+    df = df.with_columns(output=redact_between('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_redact_between_markers():
+    df = polars.DataFrame({
+        'input': [
+            'token: <secret>abc123</secret> ok',
+        ],
+        'expected': [
+            'token: <secret>######</secret> ok',
+        ],
+    })
+    df = df.with_columns(output=redact_between('input', start='<secret>', end='</secret>', mask_char='#'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_redact_between_keeps_length():
+    df = polars.DataFrame({'input': ['a={{secret}} b={{x}}', 'no markers']})
+    df = df.with_columns(output=redact_between('input'))
+
+    assert df['output'].str.len_chars().to_list() == df['input'].str.len_chars().to_list()
\ No newline at end of file
@@ -0,0 +1,63 @@
+# test_redact_by_regex.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import redact_by_regex
+import polars
+import pytest
+
+def test_redact_by_regex():
+    df = polars.DataFrame({
+        'input': [
+            'SSN 123456789 on file',
+            'Order 1234567890123 shipped',
+            'Order 12345678 shipped',
+            None,
+        ],
+        'expected': [
+            'SSN [REDACTED] on file',
+            'Order [REDACTED]0123 shipped',
+            'Order 12345678 shipped',
+            None,
+        ],
+    })
+    df = df.with_columns(output=redact_by_regex('input', r'\d{9}'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Matches are consumed left-to-right without overlapping
+    df = polars.DataFrame({
+        'input': [
+            'aaaa',
+            'aaa',
+        ],
+        'expected': [
+            'XX',
+            'Xa',
+        ],
+    })
+    df = df.with_columns(output=redact_by_regex('input', 'aa', replacement='X'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Replacement is inserted literally
+    df = polars.DataFrame({'input': ['id-42']})
+    df = df.with_columns(output=redact_by_regex('input', r'(\d+)', replacement='$1'))
+    assert df['output'].to_list() == ['id-$1']
+
+def test_redact_by_regex_invalid_pattern():
+    df = polars.DataFrame({'input': ['abc']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=redact_by_regex('input', '('))
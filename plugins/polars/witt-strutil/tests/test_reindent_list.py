@@ -0,0 +1,45 @@
+# test_reindent_list.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import reindent_list
+import polars
+
+def test_reindent_list():
+    df = polars.DataFrame({
+        'input': [
+            '- Fruit\n   1. Apples\n      the green ones\n   2) Pears\n\t* Conference\n- Bread',
+            'Notes:\n * one\n     * two\n   * three',
+            'Not a list',
+            None,
+        ],
+        'expected': [
+            '- Fruit\n  1. Apples\n    the green ones\n  2) Pears\n    * Conference\n- Bread',
+            'Notes:\n* one\n  * two\n  * three',
+            'Not a list',
+            None,
+        ],
+    })
+    df = df.with_columns(output=reindent_list('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_reindent_list_indent_width():
+    df = polars.DataFrame({'input': ['- a\n - b\n   - c']})
+    df = df.with_columns(output=reindent_list('input', indent_width=4))
+
+    assert df['output'].to_list() == ['- a\n    - b\n        - c']
\ No newline at end of file
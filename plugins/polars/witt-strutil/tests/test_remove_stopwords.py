@@ -0,0 +1,62 @@
+# test_remove_stopwords.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import remove_stopwords
+import polars
+import pytest
+
+def test_remove_stopwords():
+    df = polars.DataFrame({
+        'input': [
+            'the quick brown fox',
+            "I don't like THE foo",
+            'the and of',
+            '',
+            None,
+        ],
+        'expected': [
+            'quick brown fox',
+            'like foo',
+            '',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=remove_stopwords('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_remove_stopwords_custom():
+    df = polars.DataFrame({
+        'input': [
+            'the quick brown fox',
+            "I don't like THE foo",
+            None,
+        ],
+        'expected': [
+            ['quick', 'fox'],
+            ['like'],
+            None,
+        ],
+    })
+    df = df.with_columns(output=remove_stopwords('input', custom=['Brown', 'foo'], output_list=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_remove_stopwords_unknown_language():
+    df = polars.DataFrame({'input': ['the quick brown fox']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=remove_stopwords('input', language='xx'))
@@ -0,0 +1,79 @@
+# test_render_template.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import render_template
+import polars
+
+def test_render_template():
+    df = polars.DataFrame({
+        'input': [
+            'Hi {name}, you have {count} messages',
+            'Bye {name}',
+            None,
+        ],
+        'name': [
+            'Ada',
+            'Bob',
+            'Cy',
+        ],
+        'count': [
+            3,
+            1,
+            0,
+        ],
+        'expected': [
+            'Hi Ada, you have 3 messages',
+            'Bye Bob',
+            None,
+        ],
+    })
+    df = df.with_columns(output=render_template('input', name='name', count='count'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_render_template_strict():
+    df = polars.DataFrame({'input': ['Hi {name}, {unknown}'], 'name': ['Ada']})
+    df = df.select(lenient=render_template('input', name='name'),
+                   strict=render_template('input', strict=True, name='name'))
+
+    assert df.row(0) == ('Hi Ada, {unknown}', 'Hi Ada, ')
+
+def test_render_template_escaped_braces():
+    df = polars.DataFrame({
+        'input': [
+            '{{name}} is {name}',
+            '}} {',
+        ],
+        'name': [
+            'Ada',
+            'Bob',
+        ],
+        'expected': [
+            '{name} is Ada',
+            '} {',
+        ],
+    })
+    df = df.with_columns(output=render_template('input', name='name'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_render_template_values():
+    df = polars.DataFrame({'input': ['{greeting}, {name}!'], 'name': [None]}, schema={'input': polars.String, 'name': polars.String})
+    df = df.with_columns(output=render_template('input', greeting=polars.lit('Hello'), name='name'))
+
+    assert df['output'].to_list() == ['Hello, !']
\ No newline at end of file
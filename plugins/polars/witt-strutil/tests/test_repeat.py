@@ -0,0 +1,71 @@
+# test_repeat.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import repeat
+import polars
+
+def test_repeat():
+    df = polars.DataFrame({
+        'input': [
+            'ab',
+            '-',
+            '',
+            None,
+        ],
+        'expected': [
+            'ababab',
+            '---',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=repeat('input', times=3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_repeat_with_separator():
+    df = polars.DataFrame({
+        'input': [
+            'ab',
+            '',
+            None,
+        ],
+        'expected': [
+            'ab, ab, ab',
+            ', , ',
+            None,
+        ],
+    })
+    df = df.with_columns(output=repeat('input', times=3, separator=', '))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_repeat_zero_times():
+    df = polars.DataFrame({
+        'input': [
+            'ab',
+            None,
+        ],
+        'expected': [
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=repeat('input', times=0, separator=', '))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
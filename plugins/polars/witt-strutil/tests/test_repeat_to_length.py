@@ -0,0 +1,45 @@
+# test_repeat_to_length.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import repeat_to_length
+import polars
+
+def test_repeat_to_length():
+    df = polars.DataFrame({
+        'input': [
+            '-',
+            'abc',
+            '1234567890',
+            'ab',
+            'héllo',
+            '',
+            None,
+        ],
+        'expected': [
+            '--------',
+            'abcabcab',
+            '12345678',
+            'abababab',
+            'héllohél',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=repeat_to_length('input', length=8))
+
+    assert df['output'].to_list() == df['expected'].to_list()
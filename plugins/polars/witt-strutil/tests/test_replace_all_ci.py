@@ -0,0 +1,49 @@
+# test_replace_all_ci.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import replace_all_ci
+import polars
+import pytest
+
+def test_replace_all_ci():
+    df = polars.DataFrame({
+        'input': [
+            'Cat, CAT and cAt sat on the Category',
+            'no match here',
+            None,
+        ],
+        'expected': [
+            'dog, dog and dog sat on the dogegory',
+            'no match here',
+            None,
+        ],
+    })
+    df = df.with_columns(output=replace_all_ci('input', pattern='cat', replacement='dog'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_replace_all_ci_literal():
+    df = polars.DataFrame({'input': ['a.b A.B axb']})
+    df = df.with_columns(output=replace_all_ci('input', pattern='a.b', replacement='$0'))
+
+    assert df['output'].to_list() == ['$0 $0 axb']
+
+def test_replace_all_ci_empty_pattern():
+    df = polars.DataFrame({'input': ['abc']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=replace_all_ci('input', pattern='', replacement='x'))
\ No newline at end of file
@@ -0,0 +1,77 @@
+# test_replace_nth.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import replace_nth
+import polars
+
+def test_replace_nth():
+    df = polars.DataFrame({
+        'input': [
+            'a-b-c-d',
+            'a-b',
+            '',
+            None,
+        ],
+        'expected': [
+            'a-b+c-d',
+            'a-b',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=replace_nth('input', '-', '+', 2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_replace_nth_last():
+    df = polars.DataFrame({
+        'input': [
+            'a-b-c-d',
+            'a-b',
+            'abc',
+        ],
+        'expected': [
+            'a-b-c+d',
+            'a+b',
+            'abc',
+        ],
+    })
+    df = df.with_columns(output=replace_nth('input', '-', '+', -1))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_replace_nth_out_of_range():
+    df = polars.DataFrame({'input': ['a-b-c']})
+    df = df.select(zero=replace_nth('input', '-', '+', 0),
+                   after=replace_nth('input', '-', '+', 3),
+                   before=replace_nth('input', '-', '+', -3))
+
+    assert df.row(0) == ('a-b-c', 'a-b-c', 'a-b-c')
+
+def test_replace_nth_regex():
+    df = polars.DataFrame({
+        'input': [
+            'x1 y2 z3',
+        ],
+        'expected': [
+            'x1 2y z3',
+        ],
+    })
+    df = df.with_columns(output=replace_nth('input', r'(\w)(\d)', '$2$1', 2, literal=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,49 @@
+# test_replace_preserving_case.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import replace_preserving_case
+import polars
+
+def test_replace_preserving_case():
+    df = polars.DataFrame({
+        'input': [
+            'the cat sat',
+            'Cat food',
+            'CAT SCAN',
+            'a cAt',
+            'no match',
+            None,
+        ],
+        'expected': [
+            'the dog sat',
+            'Dog food',
+            'DOG SCAN',
+            'a dog',
+            'no match',
+            None,
+        ],
+    })
+    df = df.with_columns(output=replace_preserving_case('input', pattern='cat', replacement='dog'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_replace_preserving_case_single_letter():
+    df = polars.DataFrame({'input': ['A tale of a city']})
+    df = df.with_columns(output=replace_preserving_case('input', pattern='a', replacement='one'))
+
+    assert df['output'].to_list() == ['One tonele of one city']
\ No newline at end of file
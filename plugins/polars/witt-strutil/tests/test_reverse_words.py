@@ -0,0 +1,47 @@
+# test_reverse_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import reverse_words
+import polars
+
+def test_reverse_words():
+    df = polars.DataFrame({
+        'input': [
+            'hello world',
+            '  Hello,  big\tworld! ',
+            'one',
+            '',
+            None,
+        ],
+        'expected': [
+            'world hello',
+            '  world!  big\tHello, ',
+            'one',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=reverse_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_reverse_words_collapse_whitespace():
+    df = polars.DataFrame({'input': ['  Hello,  big\tworld! ']})
+    df = df.with_columns(output=reverse_words('input', preserve_whitespace=False))
+
+    assert df['output'].to_list() == ['world! big Hello,']
\ No newline at end of file
@@ -0,0 +1,70 @@
+# test_sentences_with_offsets.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import sentences_with_offsets
+import polars
+
+def test_sentences_with_offsets():
+    df = polars.DataFrame({
+        'input': [
+            'Hello world. It costs 3.14 dollars! Really?',
+            'Dr. Smith met J. Doe today. Ok',
+            'No end',
+            '   ',
+            None,
+        ],
+        'expected': [
+            [
+                {'text': 'Hello world.',           'start': 0,  'end': 12},
+                {'text': 'It costs 3.14 dollars!', 'start': 13, 'end': 35},
+                {'text': 'Really?',                'start': 36, 'end': 43},
+            ],
+            [
+                {'text': 'Dr. Smith met J. Doe today.', 'start': 0,  'end': 27},
+                {'text': 'Ok',                          'start': 28, 'end': 30},
+            ],
+            [
+                {'text': 'No end', 'start': 0, 'end': 6},
+            ],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=sentences_with_offsets('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_sentences_with_offsets_multibyte():
+    text = '  Café 東京. Naïve “quoted.” Done...  '
+    df = polars.DataFrame({'input': [text]})
+    df = df.with_columns(output=sentences_with_offsets('input'))
+    sentences = df['output'].to_list()[0]
+    assert [s['text'] for s in sentences] == ['Café 東京.', 'Naïve “quoted.”', 'Done...']
+    assert all(text[s['start']:s['end']] == s['text'] for s in sentences)
+
+def test_sentences_with_offsets_custom_abbreviations():
+    text = 'We live on Main Ave. Springfield is nice. See Fig. 3 too.'
+    df = polars.DataFrame({'input': [text]})
+
+    df = df.with_columns(output=sentences_with_offsets('input'))
+    sentences = df['output'].to_list()[0]
+    assert [s['text'] for s in sentences] == ['We live on Main Ave.', 'Springfield is nice.', 'See Fig. 3 too.']
+
+    # Custom abbreviations are combined with the built-in ones
+    df = df.with_columns(output=sentences_with_offsets('input', abbreviations=['Ave.']))
+    sentences = df['output'].to_list()[0]
+    assert [s['text'] for s in sentences] == ['We live on Main Ave. Springfield is nice.', 'See Fig. 3 too.']
\ No newline at end of file
@@ -0,0 +1,53 @@
+# test_shannon_entropy.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import shannon_entropy
+import polars
+import pytest
+
+def test_shannon_entropy():
+    df = polars.DataFrame({
+        'input': [
+            'abcd',
+            'aaaa',
+            'aab',
+            '',
+            None,
+        ],
+        'expected': [
+            2.0,
+            0.0,
+            0.918296,
+            0.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=shannon_entropy('input').round(6))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_shannon_entropy_base():
+    df = polars.DataFrame({'input': ['abcd']})
+    df = df.with_columns(output=shannon_entropy('input', base=4).round(6))
+
+    assert df['output'].to_list() == [1.0]
+
+def test_shannon_entropy_invalid_base():
+    df = polars.DataFrame({'input': ['abcd']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=shannon_entropy('input', base=1))
\ No newline at end of file
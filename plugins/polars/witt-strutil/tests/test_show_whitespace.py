@@ -0,0 +1,60 @@
+# test_show_whitespace.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import show_whitespace
+import polars
+
+def test_show_whitespace():
+    df = polars.DataFrame({
+        'input': [
+            'lorem ipsum',
+            'lorem\tipsum',
+            'lorem\nipsum',
+            ' \t\n ',
+            'lorem',
+            None,
+        ],
+        'expected': [
+            'lorem·ipsum',
+            'lorem→ipsum',
+            'lorem¶ipsum',
+            '·→¶·',
+            'lorem',
+            None,
+        ],
+    })
+    df = df.with_columns(output=show_whitespace('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_show_whitespace_custom_glyphs():
+    df = polars.DataFrame({
+        'input': [
+            'a b',
+            'a\tb',
+            'a\nb',
+        ],
+        'expected': [
+            'a_b',
+            'a\\tb',
+            'a$\nb',
+        ],
+    })
+    df = df.with_columns(output=show_whitespace('input', space='_', tab='\\t', newline='$\n'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,60 @@
+# test_smart_title.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import smart_title
+import polars
+
+def test_smart_title():
+    df = polars.DataFrame({
+        'input': [
+            'the NASA report',
+            'an FBI  and CIA\tbriefing',
+            'hello (world) U.S.A.',
+            'i am A cat',
+            'mIxEd cAsE',
+            '',
+            None,
+        ],
+        'expected': [
+            'The NASA Report',
+            'An FBI  And CIA\tBriefing',
+            'Hello (World) U.S.A.',
+            'I Am A Cat',
+            'Mixed Case',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=smart_title('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Only longer acronyms are preserved
+    df = polars.DataFrame({
+        'input': [
+            'the NASA report',
+            'an FBI briefing',
+            'UK office',
+        ],
+        'expected': [
+            'The NASA Report',
+            'An Fbi Briefing',
+            'Uk Office',
+        ],
+    })
+    df = df.with_columns(output=smart_title('input', min_acronym_length=4))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,61 @@
+# test_sort_chars.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import sort_chars
+import polars
+
+def test_sort_chars():
+    df = polars.DataFrame({
+        'input': [
+            'dcba',
+            'banana',
+            'éba',
+            '',
+            None,
+        ],
+        'expected': [
+            'abcd',
+            'aaabnn',
+            'abé',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=sort_chars('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_sort_chars_descending():
+    df = polars.DataFrame({
+        'input': [
+            'abcd',
+        ],
+        'expected': [
+            'dcba',
+        ],
+    })
+    df = df.with_columns(output=sort_chars('input', descending=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_sort_chars_anagrams():
+    df = polars.DataFrame({'input': ['listen', 'silent', 'Dormitory', 'dirty room']})
+    df = df.with_columns(output=sort_chars('input', case_insensitive=True, ignore_whitespace=True))
+
+    assert df['output'][0] == df['output'][1]
+    assert df['output'][2] == df['output'][3]
\ No newline at end of file
@@ -0,0 +1,47 @@
+# test_split_by_script_change.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import split_by_script_change
+import polars
+
+def test_split_by_script_change():
+    df = polars.DataFrame({
+        'input': [
+            'Hello 世界, this is 中文测试。OK',
+            'iPhone 15 是苹果的手机',
+            '東京はtokyo',
+            'Москва is Moscow',
+            'café, naïve!',
+            '123 !!',
+            '',
+            None,
+        ],
+        'expected': [
+            ['Hello', '世界,', 'this is', '中文测试。', 'OK'],
+            ['iPhone 15', '是苹果的手机'],
+            ['東京は', 'tokyo'],
+            ['Москва', 'is Moscow'],
+            ['café, naïve!'],
+            ['123 !!'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=split_by_script_change('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
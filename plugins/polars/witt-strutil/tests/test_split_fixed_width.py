@@ -0,0 +1,60 @@
+# test_split_fixed_width.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import split_fixed_width
+import polars
+import pytest
+
+def test_split_fixed_width():
+    df = polars.DataFrame({
+        'input': [
+            'ABCDEFGHIJ',
+            'ABCDEF',
+            'ABCDEFGHIJKL',
+            '',
+            None,
+        ],
+        'expected': [
+            ['ABC', 'DEFG', 'HIJ'],
+            ['ABC', 'DEF', ''],
+            ['ABC', 'DEFG', 'HIJ'],
+            ['', '', ''],
+            None,
+        ],
+    })
+    df = df.with_columns(output=split_fixed_width('input', widths=[3, 4, 3]))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_split_fixed_width_options():
+    df = polars.DataFrame({'input': ['Alice   30   Paris, France']})
+    df = df.select(raw=split_fixed_width('input', widths=[8, 5, 5]),
+                   trimmed=split_fixed_width('input', widths=[8, 5, 5], trim=True, keep_remainder=True))
+
+    assert df.row(0) == (['Alice   ', '30   ', 'Paris'], ['Alice', '30', 'Paris', ', France'])
+
+def test_split_fixed_width_graphemes():
+    df = polars.DataFrame({'input': ['Zoe\u0301Lodz']})
+    df = df.with_columns(output=split_fixed_width('input', widths=[3, 4]))
+
+    assert df['output'].to_list() == [['Zoe\u0301', 'Lodz']]
+
+def test_split_fixed_width_empty_widths():
+    df = polars.DataFrame({'input': ['lorem']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=split_fixed_width('input', widths=[]))
\ No newline at end of file
@@ -0,0 +1,54 @@
+# test_split_respecting_quotes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import split_respecting_quotes
+import polars
+
+def test_split_respecting_quotes():
+    df = polars.DataFrame({
+        'input': [
+            'a,"b,c",d',
+            'key="x,y",z',
+            '"say ""hi"", ok",2',
+            'a,,b',
+            None,
+        ],
+        'expected': [
+            ['a', 'b,c', 'd'],
+            ['key=x,y', 'z'],
+            ['say "hi", ok', '2'],
+            ['a', '', 'b'],
+            None,
+        ],
+    })
+    df = df.with_columns(output=split_respecting_quotes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_split_respecting_quotes_custom():
+    df = polars.DataFrame({
+        'input': [
+            "name|'a|b'|c",
+        ],
+        'expected': [
+            ['name', 'a|b', 'c'],
+        ],
+    })
+    df = df.with_columns(output=split_respecting_quotes('input', delimiter='|', quote="'"))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
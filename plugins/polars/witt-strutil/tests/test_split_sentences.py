@@ -0,0 +1,54 @@
+# test_split_sentences.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import split_sentences
+import polars
+
+def test_split_sentences():
+    df = polars.DataFrame({
+        'input': [
+            'Dr. Smith went home. He slept.',
+            'Pi is 3.14, e.g. roughly. Yes!',
+            'He paused... then left. Wait... What?',
+            '',
+            None,
+        ],
+        'expected': [
+            ['Dr. Smith went home.', 'He slept.'],
+            ['Pi is 3.14, e.g. roughly.', 'Yes!'],
+            ['He paused... then left.', 'Wait...', 'What?'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=split_sentences('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_split_sentences_abbreviations():
+    df = polars.DataFrame({
+        'input': [
+            'Meet at 5th Ave. Corner shop. Bring cash.',
+        ],
+        'expected': [
+            ['Meet at 5th Ave. Corner shop.', 'Bring cash.'],
+        ],
+    })
+    df = df.with_columns(output=split_sentences('input', abbreviations=['Ave.']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
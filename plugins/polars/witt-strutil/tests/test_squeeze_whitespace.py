@@ -0,0 +1,54 @@
+# test_squeeze_whitespace.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import squeeze_whitespace
+import polars
+
+def test_squeeze_whitespace():
+    df = polars.DataFrame({
+        'input': [
+            '  a\t\tb\u00a0\u00a0c  ',
+            'line one  \n\n\n  line two\r\nthree',
+            '   ',
+            '',
+            None,
+        ],
+        'expected': [
+            'a b c',
+            'line one line two three',
+            '',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=squeeze_whitespace('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_squeeze_whitespace_preserve_newlines():
+    df = polars.DataFrame({
+        'input': [
+            '  a\t\tb\u00a0\u00a0c  ',
+            'line one  \n\n\n  line two\r\nthree',
+        ],
+        'expected': [
+            'a b c',
+            'line one\nline two\nthree',
+        ],
+    })
+    df = df.with_columns(output=squeeze_whitespace('input', preserve_newlines=True))
+    assert df['output'].to_list() == df['expected'].to_list()
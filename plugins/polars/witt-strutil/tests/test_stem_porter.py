@@ -0,0 +1,64 @@
+# test_stem_porter.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import stem_porter
+import polars
+
+def test_stem_porter():
+    df = polars.DataFrame({
+        'input': [
+            'running runner runs',
+            'caresses ponies ties cats',
+            'agreed plastered motoring sized hopping',
+            'relational conditional generalizations',
+            'Hopefulness, goodness!',
+            'belle gazelle controlled Michelle',
+            'in 2024',
+            '',
+            None,
+        ],
+        'expected': [
+            'run runner run',
+            'caress poni ti cat',
+            'agre plaster motor size hop',
+            'relat condit gener',
+            'hope, good!',
+            'bell gazel control michel',
+            'in 2024',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=stem_porter('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_stem_porter_list():
+    df = polars.DataFrame({
+        'input': [
+            'running runner runs',
+            '',
+            None,
+        ],
+        'expected': [
+            ['run', 'runner', 'run'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=stem_porter('input', output_list=True))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,98 @@
+# test_straighten_quotes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import smart_quotes
+from witt_strutil import straighten_quotes
+import polars
+
+def test_straighten_quotes():
+    df = polars.DataFrame({
+        'input': [
+            '\u201cIt\u2019s fine,\u201d she said \u2013 \u2018really\u2019',
+            'plain "text"',
+            None,
+        ],
+        'expected': [
+            '"It\'s fine," she said \u2013 \'really\'',
+            'plain "text"',
+            None,
+        ],
+    })
+    df = df.with_columns(output=straighten_quotes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_straighten_quotes_dashes():
+    df = polars.DataFrame({
+        'input': [
+            'pages 1\u20135\u2014done',
+        ],
+        'expected': [
+            'pages 1-5-done',
+        ],
+    })
+    df = df.with_columns(output=straighten_quotes('input', convert_dashes=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_smart_quotes():
+    df = polars.DataFrame({
+        'input': [
+            '"It\'s fine," she said.',
+            '"He told me \'no\' twice."',
+            '(\'quoted\')',
+            None,
+        ],
+        'expected': [
+            '\u201cIt\u2019s fine,\u201d she said.',
+            '\u201cHe told me \u2018no\u2019 twice.\u201d',
+            '(\u2018quoted\u2019)',
+            None,
+        ],
+    })
+    df = df.with_columns(output=smart_quotes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_smart_quotes_nested():
+    df = polars.DataFrame({
+        'input': [
+            '"\'Hi,\' she said."',
+        ],
+        'expected': [
+            '\u201c\u2018Hi,\u2019 she said.\u201d',
+        ],
+    })
+    df = df.with_columns(output=smart_quotes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_smart_quotes_dashes():
+    df = polars.DataFrame({
+        'input': [
+            'wait -- what',
+            'a - b',
+        ],
+        'expected': [
+            'wait \u2014 what',
+            'a \u2013 b',
+        ],
+    })
+    df = df.with_columns(output=smart_quotes('input', convert_dashes=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
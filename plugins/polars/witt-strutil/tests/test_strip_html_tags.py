@@ -0,0 +1,60 @@
+# test_strip_html_tags.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import strip_html_tags
+import polars
+
+def test_strip_html_tags():
+    df = polars.DataFrame({
+        'input': [
+            '<div><p>Hello <b>world</b></p></div>',
+            'line<br/>break<hr />end',
+            '<img src="a>b.png" alt=\'x\'>caption',
+            'text <!-- a > b --> more',
+            'a < b and c > d',
+            'Hello <b>world</b> <a href=',
+            'Tom &amp; Jerry &lt;3 &#39;hi&#39; a&#x2F;b',
+            None,
+        ],
+        'expected': [
+            'Hello world',
+            'linebreakend',
+            'caption',
+            'text  more',
+            'a < b and c > d',
+            'Hello world <a href=',
+            "Tom & Jerry <3 'hi' a/b",
+            None,
+        ],
+    })
+    df = df.with_columns(output=strip_html_tags('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Keep entities and collapse whitespace
+    df = polars.DataFrame({
+        'input': [
+            '<p>\n  Tom &amp;   Jerry\n</p>',
+            '&lt;b&gt;bold&lt;/b&gt;',
+        ],
+        'expected': [
+            'Tom &amp; Jerry',
+            '&lt;b&gt;bold&lt;/b&gt;',
+        ],
+    })
+    df = df.with_columns(output=strip_html_tags('input', decode_entities=False, collapse_whitespace=True))
+    assert df['output'].to_list() == df['expected'].to_list()
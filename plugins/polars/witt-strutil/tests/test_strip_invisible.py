@@ -0,0 +1,52 @@
+# test_strip_invisible.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import strip_invisible
+import polars
+
+def test_strip_invisible():
+    df = polars.DataFrame({
+        'input': [
+            '\ufeffid,name',
+            'ab\u200dcd',
+            '\U0001f468\u200d\U0001f469',
+            '\u202eolleh\u202c \u200ex',
+            'a\tb\r\nc\x00\x85\x7fd',
+            'Plain text.',
+            None,
+        ],
+        'expected': [
+            'id,name',
+            'abcd',
+            '\U0001f468\u200d\U0001f469',
+            'olleh x',
+            'a\tb\r\ncd',
+            'Plain text.',
+            None,
+        ],
+    })
+    df = df.with_columns(output=strip_invisible('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_strip_invisible_newlines_and_tabs():
+    df = polars.DataFrame({'input': ['a\tb\r\nc']})
+    df = df.select(no_newlines=strip_invisible('input', keep_newlines=False),
+                   no_tabs=strip_invisible('input', keep_tabs=False))
+
+    assert df.row(0) == ('a\tbc', 'ab\r\nc')
\ No newline at end of file
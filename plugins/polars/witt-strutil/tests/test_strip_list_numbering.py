@@ -0,0 +1,72 @@
+# test_strip_list_numbering.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import strip_list_numbering
+import polars
+
+def test_strip_list_numbering():
+    df = polars.DataFrame({
+        'input': [
+            '1. First',
+            '12) Twelfth',
+            'a) Alpha',
+            '- Dash',
+            '* Star',
+            '  • Indented bullet',
+            '1.5 litres',
+            '-5 degrees',
+            'No marker',
+            None,
+        ],
+        'expected': [
+            'First',
+            'Twelfth',
+            'Alpha',
+            'Dash',
+            'Star',
+            'Indented bullet',
+            '1.5 litres',
+            '-5 degrees',
+            'No marker',
+            None,
+        ],
+    })
+    df = df.with_columns(output=strip_list_numbering('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_strip_list_numbering_lines():
+    df = polars.DataFrame({'input': ['1. One\n2. Two\nplain']})
+    df = df.with_columns(output=strip_list_numbering('input'))
+
+    assert df['output'].to_list() == ['One\nTwo\nplain']
+
+def test_strip_list_numbering_return_marker():
+    df = polars.DataFrame({
+        'input': [
+            '1. First',
+            'b) Second',
+            '* Third',
+            'Fourth',
+        ],
+    })
+    df = df.with_columns(output=strip_list_numbering('input', return_marker=True))
+    df = df.select(polars.col('output').struct.unnest())
+
+    assert df['text'].to_list() == ['First', 'Second', 'Third', 'Fourth']
+    assert df['marker'].to_list() == ['1.', 'b)', '*', None]
\ No newline at end of file
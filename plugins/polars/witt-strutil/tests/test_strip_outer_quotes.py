@@ -0,0 +1,66 @@
+# test_strip_outer_quotes.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import strip_outer_quotes
+import polars
+
+def test_strip_outer_quotes():
+    df = polars.DataFrame({
+        'input': [
+            '"quoted"',
+            "'single'",
+            '"say ""hi"""',
+            'unquoted',
+            '"mismatched\'',
+            '\'mismatched"',
+            '"unterminated',
+            '"',
+            '""',
+            None,
+        ],
+        'expected': [
+            'quoted',
+            'single',
+            'say ""hi""',
+            'unquoted',
+            '"mismatched\'',
+            '\'mismatched"',
+            '"unterminated',
+            '"',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=strip_outer_quotes('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Unescape doubled inner quotes
+    df = polars.DataFrame({
+        'input': [
+            '"say ""hi"""',
+            "'it''s'",
+            'not ""quoted""',
+        ],
+        'expected': [
+            'say "hi"',
+            "it's",
+            'not ""quoted""',
+        ],
+    })
+    df = df.with_columns(output=strip_outer_quotes('input', unescape_doubled=True))
+    assert df['output'].to_list() == df['expected'].to_list()
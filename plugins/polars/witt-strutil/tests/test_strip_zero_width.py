@@ -0,0 +1,54 @@
+# test_strip_zero_width.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import strip_zero_width
+import polars
+
+def test_strip_zero_width():
+    df = polars.DataFrame({
+        'input': [
+            'ab\u200bc\ufeffd\u200ce\u2060',
+            '\U0001f468\u200d\U0001f469\u200d\U0001f467',
+            '\U0001f469\U0001f3fd\u200d\U0001f4bb',
+            'x\u200dy',
+            '',
+            None,
+        ],
+        'expected': [
+            'abcde',
+            '\U0001f468\u200d\U0001f469\u200d\U0001f467',
+            '\U0001f469\U0001f3fd\u200d\U0001f4bb',
+            'xy',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=strip_zero_width('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_strip_zero_width_emoji_joiners():
+    df = polars.DataFrame({
+        'input': [
+            '\U0001f468\u200d\U0001f469\u200d\U0001f467',
+        ],
+        'expected': [
+            '\U0001f468\U0001f469\U0001f467',
+        ],
+    })
+    df = df.with_columns(output=strip_zero_width('input', keep_emoji_joiners=False))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,43 @@
+# test_swap_case.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import swap_case
+import polars
+
+def test_swap_case():
+    df = polars.DataFrame({
+        'input': [
+            'Hello World, 123!',
+            'straße',
+            'İstanbul ıI',
+            '\ufb01ne',
+            '',
+            None,
+        ],
+        'expected': [
+            'hELLO wORLD, 123!',
+            'STRASSE',
+            'i\u0307STANBUL Ii',
+            'FINE',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=swap_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
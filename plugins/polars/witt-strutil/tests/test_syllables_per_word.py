@@ -0,0 +1,43 @@
+# test_syllables_per_word.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import syllables_per_word
+import polars
+
+def test_syllables_per_word():
+    df = polars.DataFrame({
+        'input': [
+            'The cat sat on the mat',
+            'Beautiful (readability), simple!',
+            'She jumped; he wanted boxes.',
+            'Make a table',
+            '',
+            None,
+        ],
+        'expected': [
+            [1, 1, 1, 1, 1, 1],
+            [3, 5, 2],
+            [1, 1, 1, 2, 2],
+            [1, 1, 2],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=syllables_per_word('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
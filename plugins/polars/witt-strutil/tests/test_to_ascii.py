@@ -0,0 +1,60 @@
+# test_to_ascii.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import to_ascii
+import polars
+
+def test_to_ascii():
+    df = polars.DataFrame({
+        'input': [
+            'Crème brûlée at 東京 café',
+            'Straße, Łódź, Ærø',
+            'Москва',
+            '“quoted” — dash…',
+            'plain ascii',
+            '',
+            None,
+        ],
+        'expected': [
+            'Creme brulee at  cafe',
+            'Strasse, Lodz, AEro',
+            'Moskva',
+            '"quoted" - dash...',
+            'plain ascii',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=to_ascii('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_to_ascii_replacement():
+    df = polars.DataFrame({
+        'input': [
+            'naïve 日本語 text',
+            'été',
+            'e\u0301te\u0301',
+        ],
+        'expected': [
+            'naive ??? text',
+            'ete',
+            'ete',
+        ],
+    })
+    df = df.with_columns(output=to_ascii('input', replacement='?'))
+    assert df['output'].to_list() == df['expected'].to_list()
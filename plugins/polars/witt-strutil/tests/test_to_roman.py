@@ -0,0 +1,93 @@
+# test_to_roman.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import from_roman
+from witt_strutil import to_roman
+import polars
+
+def test_to_roman():
+    df = polars.DataFrame({
+        'input': [
+            1,
+            4,
+            9,
+            14,
+            40,
+            90,
+            400,
+            1994,
+            2024,
+            3999,
+            0,
+            4000,
+            None,
+        ],
+        'expected': [
+            'I',
+            'IV',
+            'IX',
+            'XIV',
+            'XL',
+            'XC',
+            'CD',
+            'MCMXCIV',
+            'MMXXIV',
+            'MMMCMXCIX',
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=to_roman('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = df.with_columns(output=to_roman('input', lowercase=True))
+    assert df['output'].to_list() == [e.lower() if e else e for e in df['expected']]
+
+def test_roman_round_trip():
+    df = polars.DataFrame({'input': [1, 4, 9, 14, 40, 90, 400, 1994, 2024, 3999]})
+    df = df.with_columns(output=from_roman(to_roman('input')))
+    assert df['output'].to_list() == df['input'].to_list()
+
+    df = df.with_columns(output=from_roman(to_roman('input', lowercase=True)))
+    assert df['output'].to_list() == df['input'].to_list()
+
+def test_from_roman_invalid():
+    df = polars.DataFrame({
+        'input': [
+            'IIII',
+            'IC',
+            'MMMM',
+            'ABC',
+            '',
+            None,
+        ],
+        'expected': [
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=from_roman('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    df = df.with_columns(output=from_roman('input', strict=False))
+    assert df['output'].to_list() == [4, 99, None, None, None, None]
\ No newline at end of file
@@ -42,8 +42,8 @@ def test_to_sentence_case():
             'Lowercase',
             'Kebab-case',
             'Snake_case',
-            'Camel case',
-            'Pascal case',
+            'camelCase',
+            'PascalCase',
             'Constant_case',
             'Dot.case',
             'Sentence case',
@@ -52,4 +52,48 @@ def test_to_sentence_case():
     })
     df = df.with_columns(output=to_sentence_case('input'))
 
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_to_sentence_case_keeps_mixed_case_words_by_default():
+    df = polars.DataFrame({
+        'input': [
+            'iPhone is great. buy one!',
+            'buy an iPhone. it works',
+            'HELLO WORLD',
+            'HTTPServer',
+        ],
+        'expected': [
+            'iPhone is great. Buy one!',
+            'Buy an iPhone. It works',
+            'Hello world',
+            'Httpserver',
+        ],
+    })
+    df = df.with_columns(output=to_sentence_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_to_sentence_case_split_camel_case():
+    df = polars.DataFrame({
+        'input': [
+            'camelCase',
+            'PascalCase',
+            'HTTPServer',
+            'getHTTPResponseCode',
+            'iPhone',
+            'snake_case',
+            None,
+        ],
+        'expected': [
+            'Camel case',
+            'Pascal case',
+            'Http server',
+            'Get http response code',
+            'I phone',
+            'Snake_case',
+            None,
+        ],
+    })
+    df = df.with_columns(output=to_sentence_case('input', split_camel_case=True))
+
     assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,77 @@
+# test_token_overlap.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import token_overlap_count
+from witt_strutil import token_overlap_ratio
+import polars
+
+def test_token_overlap_count():
+    df = polars.DataFrame({
+        'left': [
+            'Acme Widgets Inc',
+            'acme widgets inc',
+            'Acme Corp',
+            'red red apple',
+            None,
+        ],
+        'right': [
+            'inc widgets ACME',
+            'Acme Gadgets Inc',
+            'Globex',
+            'apple red',
+            'Acme',
+        ],
+        'expected': [
+            3,
+            2,
+            0,
+            2,
+            None,
+        ],
+    })
+    df = df.with_columns(output=token_overlap_count('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_token_overlap_ratio():
+    df = polars.DataFrame({
+        'left': [
+            'Acme Widgets Inc',
+            'acme widgets inc',
+            'Acme Corp',
+            '',
+            None,
+        ],
+        'right': [
+            'inc widgets ACME',
+            'Acme Gadgets Inc',
+            'Globex',
+            '',
+            'Acme',
+        ],
+        'expected': [
+            1.0,
+            0.5,
+            0.0,
+            1.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=token_overlap_ratio('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,61 @@
+# test_token_sort_ratio.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import token_sort_ratio
+import polars
+
+def test_token_sort_ratio():
+    df = polars.DataFrame({
+        'left': [
+            'new york mets',
+            'New York Mets',
+            'fuzzy wuzzy was a bear',
+            'abc',
+            '',
+            None,
+        ],
+        'right': [
+            'mets new york',
+            'mets  new york',
+            'wuzzy fuzzy was a bear',
+            'xyz',
+            '',
+            'new york mets',
+        ],
+        'expected': [
+            100.0,
+            100.0,
+            100.0,
+            0.0,
+            100.0,
+            None,
+        ],
+    })
+    df = df.with_columns(output=token_sort_ratio('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Case-sensitive comparison penalizes differently-cased tokens
+    df = polars.DataFrame({
+        'left': ['New York Mets', 'new york mets'],
+        'right': ['mets new york', 'mets new york'],
+    })
+    df = df.with_columns(output=token_sort_ratio('left', 'right', case_insensitive=False))
+
+    assert df['output'][0] < 100.0
+    assert df['output'][1] == 100.0
@@ -0,0 +1,58 @@
+# test_tokenize_words.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import tokenize_words
+import polars
+
+def test_tokenize_words():
+    df = polars.DataFrame({
+        'input': [
+            "Don't stop, it's well-known!",
+            '東京は大きい',
+            'pi is 3.14',
+            '',
+            None,
+        ],
+        'expected': [
+            ["Don't", 'stop', "it's", 'well', 'known'],
+            ['東', '京', 'は', '大', 'き', 'い'],
+            ['pi', 'is', '3.14'],
+            [],
+            None,
+        ],
+    })
+    df = df.with_columns(output=tokenize_words('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_tokenize_words_options():
+    df = polars.DataFrame({
+        'input': [
+            "Don't stop, it's well-known!",
+            'I am a fan',
+        ],
+        'expected': [
+            ["don't", 'stop', ',', "it's", 'well', '-', 'known', '!'],
+            ['i', 'am', 'a', 'fan'],
+        ],
+    })
+    df = df.with_columns(output=tokenize_words('input', lowercase=True, keep_punctuation=True))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Drop short tokens
+    df = df.with_columns(output=tokenize_words('input', min_length=3))
+    assert df['output'].to_list() == [["Don't", 'stop', "it's", 'well', 'known'], ['fan']]
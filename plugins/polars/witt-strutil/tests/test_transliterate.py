@@ -0,0 +1,100 @@
+# test_transliterate.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import transliterate
+import polars
+import pytest
+
+def test_transliterate_cyrillic_to_latin():
+    df = polars.DataFrame({
+        'input': [
+            'Москва',
+            'Щукин',
+            'Hello 123',
+            None,
+        ],
+        'expected': [
+            'Moskva',
+            'Shchukin',
+            'Hello 123',
+            None,
+        ],
+    })
+    df = df.with_columns(output=transliterate('input', 'cyrillic', 'latin'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_transliterate_greek_to_latin():
+    df = polars.DataFrame({
+        'input': [
+            'Αθήνα',
+            'Ελλάδα',
+            'αυτό ευαγγέλιο',
+            'ΚΑΛΗΜΕΡΑ',
+            None,
+        ],
+        'expected': [
+            'Athina',
+            'Ellada',
+            'afto evangelio',
+            'KALIMERA',
+            None,
+        ],
+    })
+    df = df.with_columns(output=transliterate('input', 'greek', 'latin'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_transliterate_latin_to_cyrillic():
+    df = polars.DataFrame({
+        'input': [
+            'Moskva',
+            'Shchukin',
+            'ZHUKOV',
+        ],
+        'expected': [
+            'Москва',
+            'Щукин',
+            'ЖУКОВ',
+        ],
+    })
+    df = df.with_columns(output=transliterate('input', 'latin', 'cyrillic'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_transliterate_latin_to_greek():
+    df = polars.DataFrame({
+        'input': [
+            'Athina',
+            'Thessaloniki',
+            'PAPADOPOULOS',
+        ],
+        'expected': [
+            'Αθινα',
+            'Θεσσαλονικι',
+            'ΠΑΠΑΔΟΠΟΥΛΟΣ',
+        ],
+    })
+    df = df.with_columns(output=transliterate('input', 'latin', 'greek'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_transliterate_unsupported_pair():
+    df = polars.DataFrame({'input': ['lorem']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=transliterate('input', 'latin', 'klingon'))
\ No newline at end of file
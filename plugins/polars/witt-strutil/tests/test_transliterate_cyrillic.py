@@ -0,0 +1,79 @@
+# test_transliterate_cyrillic.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import transliterate_cyrillic
+import polars
+
+def test_transliterate_cyrillic():
+    # GOST 7.79-2000, system B
+    df = polars.DataFrame({
+        'input': [
+            'Москва',
+            'Щука',
+            'Хабаровск',
+            'Цирк',
+            'Объезд',
+            'Большой театр',
+            'МОСКВА ЩИ',
+            'Hello, мир!',
+            None,
+        ],
+        'expected': [
+            'Moskva',
+            'Shhuka',
+            'Xabarovsk',
+            'Cirk',
+            'Ob``ezd',
+            'Bol`shoj teatr',
+            'MOSKVA SHHI',
+            'Hello, mir!',
+            None,
+        ],
+    })
+    df = df.with_columns(output=transliterate_cyrillic('input', scheme='gost'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # BGN/PCGN
+    df = polars.DataFrame({
+        'input': [
+            'Москва',
+            'Щука',
+            'Хабаровск',
+            'Цирк',
+            'Объезд',
+            'Ельцин',
+            'Большой театр',
+            'МОСКВА ЩИ',
+            'Hello, мир!',
+            None,
+        ],
+        'expected': [
+            'Moskva',
+            'Shchuka',
+            'Khabarovsk',
+            'Tsirk',
+            'Obʺyezd',
+            'Yelʹtsin',
+            'Bolʹshoy teatr',
+            'MOSKVA SHCHI',
+            'Hello, mir!',
+            None,
+        ],
+    })
+    df = df.with_columns(output=transliterate_cyrillic('input', scheme='bgn'))
+    assert df['output'].to_list() == df['expected'].to_list()
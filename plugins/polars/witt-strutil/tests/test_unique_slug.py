@@ -0,0 +1,57 @@
+# test_unique_slug.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import unique_slug
+import polars
+
+def test_unique_slug():
+    df = polars.DataFrame({
+        'input': [
+            'Hello, World!',
+            'Crème Brûlée',
+            'hello world',
+            None,
+            'Héllo   World',
+            'hello-world-1',
+        ],
+        'expected': [
+            'hello-world',
+            'creme-brulee',
+            'hello-world-1',
+            None,
+            'hello-world-2',
+            'hello-world-1-1',
+        ],
+    })
+    df = df.with_columns(output=unique_slug('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert df['output'].drop_nulls().is_unique().all()
+
+def test_unique_slug_separator():
+    df = polars.DataFrame({
+        'input': [
+            'My Title',
+            'my title',
+        ],
+        'expected': [
+            'my_title',
+            'my_title_1',
+        ],
+    })
+    df = df.with_columns(output=unique_slug('input', separator='_'))
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,58 @@
+# test_unleet.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import unleet
+import polars
+
+def test_unleet():
+    df = polars.DataFrame({
+        'input': [
+            'l33t h4x0r',
+            '@dmin $ales',
+            'in 2024',
+            '',
+            None,
+        ],
+        'expected': [
+            'leet haxor',
+            'admin sales',
+            'in 2024',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=unleet('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_unleet_dictionary():
+    df = polars.DataFrame({
+        'input': [
+            'h3110 w0r1d!',
+            'l33t',
+        ],
+        'expected': [
+            'hello world!',
+            'leet',
+        ],
+    })
+    df = df.with_columns(output=unleet('input', dictionary=['hello', 'world']))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Without a dictionary, the preferred reading is used
+    df = df.with_columns(output=unleet('input'))
+    assert df['output'].to_list() == ['heiio worid!', 'leet']
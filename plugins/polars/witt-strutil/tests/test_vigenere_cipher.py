@@ -0,0 +1,51 @@
+# test_vigenere_cipher.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import vigenere_cipher
+import polars
+import pytest
+
+def test_vigenere_cipher():
+    df = polars.DataFrame({
+        'input': [
+            'ATTACKATDAWN',
+            'Attack at dawn!',
+            '',
+            None,
+        ],
+        'expected': [
+            'LXFOPVEFRNHR',
+            'Lxfopv ef rnhr!',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=vigenere_cipher('input', 'LEMON'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_vigenere_cipher_round_trip():
+    df = polars.DataFrame({'input': ['Attack at dawn!', 'café 123', 'Hello, World']})
+    df = df.with_columns(output=vigenere_cipher(vigenere_cipher('input', 'secret'), 'secret', decrypt=True))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+def test_vigenere_cipher_invalid_key():
+    df = polars.DataFrame({'input': ['text']})
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=vigenere_cipher('input', '123'))
\ No newline at end of file
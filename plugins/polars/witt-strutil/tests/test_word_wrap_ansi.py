@@ -0,0 +1,59 @@
+# test_word_wrap_ansi.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import word_wrap_ansi
+import polars
+import re
+
+ANSI_ESCAPE = re.compile(r'\x1b\[[0-?]*[ -/]*[@-~]')
+
+def test_word_wrap_ansi():
+    df = polars.DataFrame({
+        'input': [
+            '\x1b[31mred\x1b[0m \x1b[1;32mgreen text\x1b[0m here and there',
+            'plain words wrap here',
+            '',
+            None,
+        ],
+        'expected': [
+            '\x1b[31mred\x1b[0m\n\x1b[1;32mgreen\ntext\x1b[0m\nhere and\nthere',
+            'plain\nwords\nwrap\nhere',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=word_wrap_ansi('input', width=8))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+    # Escapes take up no width and are all kept intact
+    for text, wrapped in zip(df['input'][:2], df['output'][:2]):
+        assert all(len(ANSI_ESCAPE.sub('', line)) <= 8 for line in wrapped.split('\n'))
+        assert ANSI_ESCAPE.findall(wrapped) == ANSI_ESCAPE.findall(text)
+
+def test_word_wrap_ansi_long_word():
+    df = polars.DataFrame({
+        'input': [
+            '\x1b[31mabcdefghij\x1b[0m',
+        ],
+        'expected': [
+            '\x1b[31mabcdefgh\nij\x1b[0m',
+        ],
+    })
+    df = df.with_columns(output=word_wrap_ansi('input', width=8))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
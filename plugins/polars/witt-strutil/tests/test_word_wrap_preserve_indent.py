@@ -0,0 +1,56 @@
+# test_word_wrap_preserve_indent.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import word_wrap_preserve_indent
+import polars
+
+def test_word_wrap_preserve_indent():
+    df = polars.DataFrame({
+        'input': [
+            '    The quick brown fox jumps over the lazy dog',
+            'no indent here at all folks',
+            '\t- tabbed item that wraps around',
+            '        abcdefghijklmnop',
+            '',
+            None,
+        ],
+        'expected': [
+            '    The quick brown\n    fox jumps over\n    the lazy dog',
+            'no indent here at\nall folks',
+            '\t- tabbed item\n\tthat wraps\n\taround',
+            '        abcdefghijkl\n        mnop',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=word_wrap_preserve_indent('input', width=20))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_word_wrap_preserve_indent_per_line():
+    df = polars.DataFrame({
+        'input': [
+            '  keep this comment aligned\n\n  second comment',
+        ],
+        'expected': [
+            '  keep this comment\n  aligned\n\n  second comment',
+        ],
+    })
+    df = df.with_columns(output=word_wrap_preserve_indent('input', width=20))
+
+    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
@@ -0,0 +1,63 @@
+# test_words_to_number.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import number_to_words
+from witt_strutil import words_to_number
+import polars
+
+def test_words_to_number():
+    df = polars.DataFrame({
+        'input': [
+            'one thousand two hundred thirty-four',
+            'One Thousand, Two Hundred and Thirty-Four',
+            'ninety five',
+            'fifteen hundred',
+            'negative seven',
+            'zero',
+            'two three',
+            'one hundred hundred',
+            'hello world',
+            '',
+            None,
+        ],
+        'expected': [
+            1234,
+            1234,
+            95,
+            1500,
+            -7,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+    })
+    df = df.with_columns(output=words_to_number('input'))
+    assert df['output'].to_list() == df['expected'].to_list()
+
+def test_words_to_number_round_trip():
+    df = polars.DataFrame({
+        'input': [0, 7, 42, 101, 1001, 1234, 1000000, 2000021, 123456789, -999],
+    })
+    df = df.with_columns(output=words_to_number(number_to_words('input')))
+    assert df['output'].to_list() == df['input'].to_list()
+
+    df = df.with_columns(output=words_to_number(number_to_words('input', and_connector=True)))
+    assert df['output'].to_list() == df['input'].to_list()
@@ -0,0 +1,57 @@
+# test_wrap_text.py
+#
+# Copyright (c) 2025 Naufan Rusyda Faikar <hello@naruaika.me>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+# 	http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+
+from witt_strutil import wrap_text
+import polars
+
+def test_wrap_text():
+    df = polars.DataFrame({
+        'input': [
+            'The quick brown fox jumps over the lazy dog and keeps running far away',
+            'line one\n\nline two is longer than twenty',
+            'short',
+            '',
+            None,
+        ],
+        'expected': [
+            'The quick brown fox\njumps over the lazy\ndog and keeps\nrunning far away',
+            'line one\n\nline two is longer\nthan twenty',
+            'short',
+            '',
+            None,
+        ],
+    })
+    df = df.with_columns(output=wrap_text('input', width=20))
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert all(len(line) <= 20 for value in df['output'].drop_nulls() for line in value.split('\n'))
+
+    # Newlines are treated as regular whitespace
+    df = df.with_columns(output=wrap_text('input', width=20, preserve_newlines=False))
+    assert df['output'][1] == 'line one line two is\nlonger than twenty'
+
+def test_wrap_text_long_words():
+    df = polars.DataFrame({
+        'input': [
+            'supercalifragilisticexpialidocious word',
+        ],
+    })
+    df = df.with_columns(output=wrap_text('input', width=20, break_long_words=True))
+    assert df['output'].to_list() == ['supercalifragilistic\nexpialidocious word']
+
+    df = df.with_columns(output=wrap_text('input', width=20, break_long_words=False))
+    assert df['output'].to_list() == ['supercalifragilisticexpialidocious\nword']
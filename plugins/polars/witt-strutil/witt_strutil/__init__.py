@@ -1,9 +1,9 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
-from polars import Expr
+from polars import Expr, col
 from polars.plugins import register_plugin_function
 
 from witt_strutil._internal import __version__ as __version__
@@ -31,11 +31,14 @@ def split_by_character_transition(expression: IntoExprColumn,
                                     kwargs         = {'before': before, 'after': after},
                                     is_elementwise = True)
 
-def to_sentence_case(expression: IntoExprColumn) -> Expr:
+def to_sentence_case(expression:       IntoExprColumn,
+                     split_camel_case: bool = False,
+                     ) ->              Expr:
     """"""
     return register_plugin_function(plugin_path    = LIB,
                                     function_name  = 'to_sentence_case',
                                     args           = [expression],
+                                    kwargs         = {'split_camel_case': split_camel_case},
                                     is_elementwise = True)
 
 def to_sponge_case(expression: IntoExprColumn) -> Expr:
@@ -43,4 +46,1650 @@ def to_sponge_case(expression: IntoExprColumn) -> Expr:
     return register_plugin_function(plugin_path    = LIB,
                                     function_name  = 'to_sponge_case',
                                     args           = [expression],
+                                    is_elementwise = True)
+
+def mask_ip(expression:    IntoExprColumn,
+            keep_segments: int = 1,
+            mask:          str = '*',
+            ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'mask_ip',
+                                    args           = [expression],
+                                    kwargs         = {'keep_segments': keep_segments, 'mask': mask},
+                                    is_elementwise = True)
+
+def is_palindrome(expression:              IntoExprColumn,
+                  ignore_case:             bool = True,
+                  ignore_non_alphanumeric: bool = True,
+                  ) ->                     Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_palindrome',
+                                    args           = [expression],
+                                    kwargs         = {'ignore_case':             ignore_case,
+                                                      'ignore_non_alphanumeric': ignore_non_alphanumeric},
+                                    is_elementwise = True)
+
+def is_valid_ip(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_valid_ip',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def ip_version(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ip_version',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def token_sort_ratio(expression:       IntoExprColumn,
+                     other:            IntoExprColumn,
+                     case_insensitive: bool = True,
+                     ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'token_sort_ratio',
+                                    args           = [expression, other],
+                                    kwargs         = {'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def ipv4_to_int(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ipv4_to_int',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def int_to_ipv4(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'int_to_ipv4',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def ngrams(expression: IntoExprColumn,
+           n:          int = 2,
+           mode:       Literal['word', 'char'] = 'word',
+           ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ngrams',
+                                    args           = [expression],
+                                    kwargs         = {'n': n, 'mode': mode},
+                                    is_elementwise = True)
+
+def cidr_contains(expression: IntoExprColumn,
+                  network:    IntoExprColumn,
+                  ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'cidr_contains',
+                                    args           = [expression, network],
+                                    is_elementwise = True)
+
+def normalize_mac(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_mac',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def strip_html_tags(expression:          IntoExprColumn,
+                    decode_entities:     bool = True,
+                    collapse_whitespace: bool = False,
+                    ) ->                 Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'strip_html_tags',
+                                    args           = [expression],
+                                    kwargs         = {'decode_entities':     decode_entities,
+                                                      'collapse_whitespace': collapse_whitespace},
+                                    is_elementwise = True)
+
+def smart_title(expression:         IntoExprColumn,
+                min_acronym_length: int = 2,
+                ) ->                Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'smart_title',
+                                    args           = [expression],
+                                    kwargs         = {'min_acronym_length': min_acronym_length},
+                                    is_elementwise = True)
+
+def extract_emails(expression: IntoExprColumn,
+                   lowercase:  bool = False,
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'extract_emails',
+                                    args           = [expression],
+                                    kwargs         = {'lowercase': lowercase},
+                                    is_elementwise = True)
+
+def balance_quotes(expression: IntoExprColumn,
+                   strategy:   Literal['close', 'remove'] = 'close',
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'balance_quotes',
+                                    args           = [expression],
+                                    kwargs         = {'strategy': strategy},
+                                    is_elementwise = True)
+
+def extract_urls(expression: IntoExprColumn,
+                 schemes:    list[str] | None = None,
+                 ) ->        Expr:
+    """"""
+    if schemes is None:
+        schemes = ['http', 'https']
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'extract_urls',
+                                    args           = [expression],
+                                    kwargs         = {'schemes': schemes},
+                                    is_elementwise = True)
+
+def ascii_ratio(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ascii_ratio',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def is_ascii(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_ascii',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def non_ascii_ratio(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'non_ascii_ratio',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def printable_ratio(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'printable_ratio',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def mask_pii(expression:  IntoExprColumn,
+             mask_char:   str = '*',
+             mask_emails: bool = True,
+             mask_phones: bool = True,
+             ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'mask_pii',
+                                    args           = [expression],
+                                    kwargs         = {'mask_char':   mask_char,
+                                                      'mask_emails': mask_emails,
+                                                      'mask_phones': mask_phones},
+                                    is_elementwise = True)
+
+def max_bracket_depth(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'max_bracket_depth',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def redact_by_regex(expression:  IntoExprColumn,
+                    pattern:     str,
+                    replacement: str = '[REDACTED]',
+                    ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'redact_by_regex',
+                                    args           = [expression],
+                                    kwargs         = {'pattern': pattern, 'replacement': replacement},
+                                    is_elementwise = True)
+
+def strip_outer_quotes(expression:       IntoExprColumn,
+                       unescape_doubled: bool = False,
+                       ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'strip_outer_quotes',
+                                    args           = [expression],
+                                    kwargs         = {'unescape_doubled': unescape_doubled},
+                                    is_elementwise = True)
+
+def jaccard_similarity(expression:       IntoExprColumn,
+                       other:            IntoExprColumn,
+                       mode:             Literal['word', 'char'] = 'word',
+                       shingle_size:     int = 1,
+                       case_insensitive: bool = True,
+                       ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'jaccard_similarity',
+                                    args           = [expression, other],
+                                    kwargs         = {'mode':             mode,
+                                                      'shingle_size':     shingle_size,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def repeat_to_length(expression: IntoExprColumn,
+                     length:     int,
+                     ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'repeat_to_length',
+                                    args           = [expression],
+                                    kwargs         = {'length': length},
+                                    is_elementwise = True)
+
+def repeat(expression: IntoExprColumn,
+           times:      int,
+           separator:  str = '',
+           ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'repeat',
+                                    args           = [expression],
+                                    kwargs         = {'times':     times,
+                                                      'separator': separator},
+                                    is_elementwise = True)
+
+def hamming_distance(expression:   IntoExprColumn,
+                     other:        IntoExprColumn,
+                     pad_mismatch: bool = False,
+                     ) ->          Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'hamming_distance',
+                                    args           = [expression, other],
+                                    kwargs         = {'pad_mismatch': pad_mismatch},
+                                    is_elementwise = True)
+
+def expand_ranges(expression:      IntoExprColumn,
+                  range_separator: str = '-',
+                  list_separator:  str = ',',
+                  skip_invalid:    bool = False,
+                  max_items:       int = 100_000,
+                  ) ->             Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'expand_ranges',
+                                    args           = [expression],
+                                    kwargs         = {'range_separator': range_separator,
+                                                      'list_separator':  list_separator,
+                                                      'skip_invalid':    skip_invalid,
+                                                      'max_items':       max_items},
+                                    is_elementwise = True)
+
+def detect_language(expression: IntoExprColumn,
+                    candidates: list[str] | None = None,
+                    min_length: int = 10,
+                    ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'detect_language',
+                                    args           = [expression],
+                                    kwargs         = {'candidates': candidates or [], 'min_length': min_length},
+                                    is_elementwise = True)
+
+def compress_ranges(expression:      IntoExprColumn,
+                    range_separator: str = '-',
+                    list_separator:  str = ',',
+                    ) ->             Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'compress_ranges',
+                                    args           = [expression],
+                                    kwargs         = {'range_separator': range_separator,
+                                                      'list_separator':  list_separator},
+                                    is_elementwise = True)
+
+def transliterate_cyrillic(expression: IntoExprColumn,
+                           scheme:     Literal['gost', 'bgn'] = 'gost',
+                           ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'transliterate_cyrillic',
+                                    args           = [expression],
+                                    kwargs         = {'scheme': scheme},
+                                    is_elementwise = True)
+
+def kwic(expression: IntoExprColumn,
+         keyword:    str,
+         window:     int = 3,
+         ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'kwic',
+                                    args           = [expression],
+                                    kwargs         = {'keyword': keyword, 'window': window},
+                                    is_elementwise = True)
+
+def to_ascii(expression:  IntoExprColumn,
+             replacement: str = '',
+             ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'to_ascii',
+                                    args           = [expression],
+                                    kwargs         = {'replacement': replacement},
+                                    is_elementwise = True)
+
+def pad_list_elements(expression: IntoExprColumn,
+                      scope:      Literal['row', 'column'] = 'row',
+                      ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'pad_list_elements',
+                                    args           = [expression],
+                                    kwargs         = {'scope': scope},
+                                    is_elementwise = scope == 'row')
+
+def normalize_confusables(expression:      IntoExprColumn,
+                          fold_whitespace: bool = True,
+                          ) ->             Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_confusables',
+                                    args           = [expression],
+                                    kwargs         = {'fold_whitespace': fold_whitespace},
+                                    is_elementwise = True)
+
+def has_repeated_word(expression:       IntoExprColumn,
+                      case_insensitive: bool = True,
+                      ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'has_repeated_word',
+                                    args           = [expression],
+                                    kwargs         = {'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def first_repeated_word(expression:       IntoExprColumn,
+                        case_insensitive: bool = True,
+                        ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'first_repeated_word',
+                                    args           = [expression],
+                                    kwargs         = {'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def expand_contractions(expression: IntoExprColumn,
+                        extra:      dict[str, str] | None = None,
+                        ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'expand_contractions',
+                                    args           = [expression],
+                                    kwargs         = {'extra': list((extra or {}).items())},
+                                    is_elementwise = True)
+
+def normalize_acronym_spacing(expression:       IntoExprColumn,
+                              max_token_length: int = 1,
+                              ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_acronym_spacing',
+                                    args           = [expression],
+                                    kwargs         = {'max_token_length': max_token_length},
+                                    is_elementwise = True)
+
+def tokenize_words(expression:       IntoExprColumn,
+                   lowercase:        bool = False,
+                   keep_punctuation: bool = False,
+                   min_length:       int = 1,
+                   ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'tokenize_words',
+                                    args           = [expression],
+                                    kwargs         = {'lowercase':        lowercase,
+                                                      'keep_punctuation': keep_punctuation,
+                                                      'min_length':       min_length},
+                                    is_elementwise = True)
+
+def fix_rtl_number_order(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'fix_rtl_number_order',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def remove_stopwords(expression:  IntoExprColumn,
+                     language:    str = 'en',
+                     custom:      list[str] | None = None,
+                     output_list: bool = False,
+                     ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'remove_stopwords',
+                                    args           = [expression],
+                                    kwargs         = {'language':    language,
+                                                      'custom':      custom or [],
+                                                      'output_list': output_list},
+                                    is_elementwise = True)
+
+def unleet(expression: IntoExprColumn,
+           dictionary: list[str] | None = None,
+           ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'unleet',
+                                    args           = [expression],
+                                    kwargs         = {'dictionary': dictionary or []},
+                                    is_elementwise = True)
+
+def stem_porter(expression:  IntoExprColumn,
+                output_list: bool = False,
+                ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'stem_porter',
+                                    args           = [expression],
+                                    kwargs         = {'output_list': output_list},
+                                    is_elementwise = True)
+
+def sentences_with_offsets(expression:    IntoExprColumn,
+                           abbreviations: list[str] | None = None,
+                           ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'sentences_with_offsets',
+                                    args           = [expression],
+                                    kwargs         = {'abbreviations': abbreviations or []},
+                                    is_elementwise = True)
+
+def normalize_phone(expression:     IntoExprColumn,
+                    default_region: str = 'US',
+                    ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_phone',
+                                    args           = [expression],
+                                    kwargs         = {'default_region': default_region},
+                                    is_elementwise = True)
+
+def make_markdown_link(expression: IntoExprColumn,
+                       other:      IntoExprColumn,
+                       ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'make_markdown_link',
+                                    args           = [expression, other],
+                                    is_elementwise = True)
+
+def parse_markdown_link(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'parse_markdown_link',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def parse_number(expression:     IntoExprColumn,
+                 thousands:      str = ',',
+                 decimal:        str = '.',
+                 scale_suffixes: bool = False,
+                 ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'parse_number',
+                                    args           = [expression],
+                                    kwargs         = {'thousands':      thousands,
+                                                      'decimal':        decimal,
+                                                      'scale_suffixes': scale_suffixes},
+                                    is_elementwise = True)
+
+def strip_zero_width(expression:         IntoExprColumn,
+                     keep_emoji_joiners: bool = True,
+                     ) ->                Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'strip_zero_width',
+                                    args           = [expression],
+                                    kwargs         = {'keep_emoji_joiners': keep_emoji_joiners},
+                                    is_elementwise = True)
+
+def number_to_words(expression:    IntoExprColumn,
+                    ordinal:       bool = False,
+                    and_connector: bool = False,
+                    ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'number_to_words',
+                                    args           = [expression],
+                                    kwargs         = {'ordinal':       ordinal,
+                                                      'and_connector': and_connector},
+                                    is_elementwise = True)
+
+def normalize_join_key(expression:          IntoExprColumn,
+                       trim:                bool = True,
+                       casefold:            bool = True,
+                       nfkc:                bool = True,
+                       collapse_whitespace: bool = True,
+                       strip_zero_width:    bool = True,
+                       ) ->                 Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_join_key',
+                                    args           = [expression],
+                                    kwargs         = {'trim':                trim,
+                                                      'casefold':            casefold,
+                                                      'nfkc':                nfkc,
+                                                      'collapse_whitespace': collapse_whitespace,
+                                                      'strip_zero_width':    strip_zero_width},
+                                    is_elementwise = True)
+
+def words_to_number(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'words_to_number',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def unique_slug(expression: IntoExprColumn,
+                separator:  str = '-',
+                ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'unique_slug',
+                                    args           = [expression],
+                                    kwargs         = {'separator': separator},
+                                    is_elementwise = False)
+
+def wrap_text(expression:        IntoExprColumn,
+              width:             int = 80,
+              break_long_words:  bool = True,
+              preserve_newlines: bool = True,
+              ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'wrap_text',
+                                    args           = [expression],
+                                    kwargs         = {'width':             width,
+                                                      'break_long_words':  break_long_words,
+                                                      'preserve_newlines': preserve_newlines},
+                                    is_elementwise = True)
+
+def insert_soft_hyphens(expression:      IntoExprColumn,
+                        min_word_length: int = 6,
+                        ) ->             Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'insert_soft_hyphens',
+                                    args           = [expression],
+                                    kwargs         = {'min_word_length': min_word_length},
+                                    is_elementwise = True)
+
+def dedent(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'dedent',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def indent(expression:       IntoExprColumn,
+           prefix:           str,
+           skip_empty_lines: bool = True,
+           ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'indent',
+                                    args           = [expression],
+                                    kwargs         = {'prefix':           prefix,
+                                                      'skip_empty_lines': skip_empty_lines},
+                                    is_elementwise = True)
+
+def extract_code_blocks(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'extract_code_blocks',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def squeeze_whitespace(expression:        IntoExprColumn,
+                       preserve_newlines: bool = False,
+                       ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'squeeze_whitespace',
+                                    args           = [expression],
+                                    kwargs         = {'preserve_newlines': preserve_newlines},
+                                    is_elementwise = True)
+
+def markdown_links_to_urls(expression: IntoExprColumn,
+                           keep:       Literal['url', 'text'] = 'url',
+                           ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'markdown_links_to_urls',
+                                    args           = [expression],
+                                    kwargs         = {'keep': keep},
+                                    is_elementwise = True)
+
+def code_fence(expression: IntoExprColumn,
+               language:   str | None = None,
+               ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'code_fence',
+                                    args           = [expression],
+                                    kwargs         = {'language': language},
+                                    is_elementwise = True)
+
+def common_prefix(expression: IntoExprColumn,
+                  other:      IntoExprColumn,
+                  ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'common_prefix',
+                                    args           = [expression, other],
+                                    is_elementwise = True)
+
+def common_suffix(expression: IntoExprColumn,
+                  other:      IntoExprColumn,
+                  ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'common_suffix',
+                                    args           = [expression, other],
+                                    is_elementwise = True)
+
+def infer_columns(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'infer_columns',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def longest_common_substring(expression:       IntoExprColumn,
+                             other:            IntoExprColumn,
+                             case_insensitive: bool = False,
+                             ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'longest_common_substring',
+                                    args           = [expression, other],
+                                    kwargs         = {'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def parse_fixed_width(expression: IntoExprColumn,
+                      widths:     list[int],
+                      ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'parse_fixed_width',
+                                    args           = [expression],
+                                    kwargs         = {'widths': widths},
+                                    is_elementwise = True)
+
+def fuzzy_contains(expression:       IntoExprColumn,
+                   needle:           IntoExprColumn,
+                   max_edits:        int = 1,
+                   case_insensitive: bool = True,
+                   ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'fuzzy_contains',
+                                    args           = [expression, needle],
+                                    kwargs         = {'max_edits':        max_edits,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def mojibake_score(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'mojibake_score',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def align(expression: IntoExprColumn,
+          width:      int,
+          alignment:  Literal['left', 'right', 'center'] = 'left',
+          fill:       str = ' ',
+          truncate:   bool = False,
+          ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'align',
+                                    args           = [expression],
+                                    kwargs         = {'width':     width,
+                                                      'alignment': alignment,
+                                                      'fill':      fill,
+                                                      'truncate':  truncate},
+                                    is_elementwise = True)
+
+def emoticon_to_emoji(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'emoticon_to_emoji',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def emoji_to_emoticon(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'emoji_to_emoticon',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def show_whitespace(expression: IntoExprColumn,
+                    space:      str = '·',
+                    tab:        str = '→',
+                    newline:    str = '¶',
+                    ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'show_whitespace',
+                                    args           = [expression],
+                                    kwargs         = {'space':   space,
+                                                      'tab':     tab,
+                                                      'newline': newline},
+                                    is_elementwise = True)
+
+def ordinal(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ordinal',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def split_by_script_change(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'split_by_script_change',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def to_roman(expression: IntoExprColumn,
+             lowercase:  bool = False,
+             ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'to_roman',
+                                    args           = [expression],
+                                    kwargs         = {'lowercase': lowercase},
+                                    is_elementwise = True)
+
+def from_roman(expression: IntoExprColumn,
+               strict:     bool = True,
+               ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'from_roman',
+                                    args           = [expression],
+                                    kwargs         = {'strict': strict},
+                                    is_elementwise = True)
+
+def transliterate(expression: IntoExprColumn,
+                  from_:      Literal['latin', 'cyrillic', 'greek'],
+                  to:         Literal['latin', 'cyrillic', 'greek'] = 'latin',
+                  ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'transliterate',
+                                    args           = [expression],
+                                    kwargs         = {'from': from_,
+                                                      'to':   to},
+                                    is_elementwise = True)
+
+def luhn_check(expression: IntoExprColumn,
+               strict:     bool = False,
+               ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'luhn_check',
+                                    args           = [expression],
+                                    kwargs         = {'strict': strict},
+                                    is_elementwise = True)
+
+def fix_apostrophes(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'fix_apostrophes',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def hash_string(expression: IntoExprColumn,
+                algorithm:  Literal['md5', 'sha1', 'sha256'] = 'sha256',
+                output:     Literal['hex', 'base64'] = 'hex',
+                ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'hash_string',
+                                    args           = [expression],
+                                    kwargs         = {'algorithm': algorithm,
+                                                      'output':    output},
+                                    is_elementwise = True)
+
+def longest_words(expression: IntoExprColumn,
+                  n:          int = 1,
+                  ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'longest_words',
+                                    args           = [expression],
+                                    kwargs         = {'n': n},
+                                    is_elementwise = True)
+
+def shortest_words(expression: IntoExprColumn,
+                   n:          int = 1,
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'shortest_words',
+                                    args           = [expression],
+                                    kwargs         = {'n': n},
+                                    is_elementwise = True)
+
+def hash_u64(expression: IntoExprColumn,
+             seed:       int = 0,
+             ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'hash_u64',
+                                    args           = [expression],
+                                    kwargs         = {'seed': seed},
+                                    is_elementwise = True)
+
+def avg_word_length(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'avg_word_length',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def unique_word_ratio(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'unique_word_ratio',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def minhash(expression:   IntoExprColumn,
+            num_hashes:   int = 128,
+            shingle_size: int = 3,
+            mode:         Literal['word', 'char'] = 'char',
+            seed:         int = 0,
+            ) ->          Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'minhash',
+                                    args           = [expression],
+                                    kwargs         = {'num_hashes':   num_hashes,
+                                                      'shingle_size': shingle_size,
+                                                      'mode':         mode,
+                                                      'seed':         seed},
+                                    is_elementwise = True)
+
+def parse_csv_line(expression: IntoExprColumn,
+                   delimiter:  str = ',',
+                   quote:      str = '"',
+                   trim:       bool = False,
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'parse_csv_line',
+                                    args           = [expression],
+                                    kwargs         = {'delimiter': delimiter,
+                                                      'quote':     quote,
+                                                      'trim':      trim},
+                                    is_elementwise = True)
+
+def word_wrap_ansi(expression:        IntoExprColumn,
+                   width:             int = 80,
+                   break_long_words:  bool = True,
+                   preserve_newlines: bool = True,
+                   ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'word_wrap_ansi',
+                                    args           = [expression],
+                                    kwargs         = {'width':             width,
+                                                      'break_long_words':  break_long_words,
+                                                      'preserve_newlines': preserve_newlines},
+                                    is_elementwise = True)
+
+def json_extract(expression: IntoExprColumn,
+                 path:       str,
+                 ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'json_extract',
+                                    args           = [expression],
+                                    kwargs         = {'path': path},
+                                    is_elementwise = True)
+
+def infer_value_type(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'infer_value_type',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+
+def render_template(expression: IntoExprColumn,
+                    strict:     bool = False,
+                    **values:   IntoExprColumn,
+                    ) ->        Expr:
+    """"""
+    values = [(col(value) if isinstance(value, str) else value).alias(name)
+              for name, value in values.items()]
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'render_template',
+                                    args           = [expression, *values],
+                                    kwargs         = {'strict': strict},
+                                    is_elementwise = True)
+
+def normalize_to_majority_case(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_to_majority_case',
+                                    args           = [expression],
+                                    is_elementwise = False)
+
+def detect_case(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'detect_case',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def strip_list_numbering(expression:    IntoExprColumn,
+                         return_marker: bool = False,
+                         ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'strip_list_numbering',
+                                    args           = [expression],
+                                    kwargs         = {'return_marker': return_marker},
+                                    is_elementwise = True)
+
+def char_frequency(expression:        IntoExprColumn,
+                   case_insensitive:  bool = False,
+                   ignore_whitespace: bool = False,
+                   ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'char_frequency',
+                                    args           = [expression],
+                                    kwargs         = {'case_insensitive':  case_insensitive,
+                                                      'ignore_whitespace': ignore_whitespace},
+                                    is_elementwise = True)
+
+def extract_list_items(expression:     IntoExprColumn,
+                       preserve_depth: bool = False,
+                       ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'extract_list_items',
+                                    args           = [expression],
+                                    kwargs         = {'preserve_depth': preserve_depth},
+                                    is_elementwise = True)
+
+def shannon_entropy(expression: IntoExprColumn,
+                    base:       float = 2.0,
+                    ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'shannon_entropy',
+                                    args           = [expression],
+                                    kwargs         = {'base': base},
+                                    is_elementwise = True)
+
+def replace_all_ci(expression:  IntoExprColumn,
+                   pattern:     str,
+                   replacement: str,
+                   ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'replace_all_ci',
+                                    args           = [expression],
+                                    kwargs         = {'pattern': pattern, 'replacement': replacement},
+                                    is_elementwise = True)
+
+def is_valid_email(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_valid_email',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def is_valid_url(expression:      IntoExprColumn,
+                 allowed_schemes: list[str] | None = None,
+                 ) ->             Expr:
+    """"""
+    if allowed_schemes is None:
+        allowed_schemes = ['http', 'https']
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_valid_url',
+                                    args           = [expression],
+                                    kwargs         = {'allowed_schemes': allowed_schemes},
+                                    is_elementwise = True)
+
+def replace_preserving_case(expression:  IntoExprColumn,
+                            pattern:     str,
+                            replacement: str,
+                            ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'replace_preserving_case',
+                                    args           = [expression],
+                                    kwargs         = {'pattern': pattern, 'replacement': replacement},
+                                    is_elementwise = True)
+
+def partial_mask(expression: IntoExprColumn,
+                 keep_start: int = 4,
+                 keep_end:   int = 4,
+                 mask_char:  str = '*',
+                 keep_short: bool = False,
+                 ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'partial_mask',
+                                    args           = [expression],
+                                    kwargs         = {'keep_start': keep_start,
+                                                      'keep_end':   keep_end,
+                                                      'mask_char':  mask_char,
+                                                      'keep_short': keep_short},
+                                    is_elementwise = True)
+
+def reindent_list(expression:   IntoExprColumn,
+                  indent_width: int = 2,
+                  ) ->          Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'reindent_list',
+                                    args           = [expression],
+                                    kwargs         = {'indent_width': indent_width},
+                                    is_elementwise = True)
+
+def swap_case(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'swap_case',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def normalize_currency(expression: IntoExprColumn,
+                       direction:  Literal['to_symbol', 'to_code'] = 'to_symbol',
+                       ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_currency',
+                                    args           = [expression],
+                                    kwargs         = {'direction': direction},
+                                    is_elementwise = True)
+
+def capitalize_words(expression:        IntoExprColumn,
+                     only_first_letter: bool = False,
+                     ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'capitalize_words',
+                                    args           = [expression],
+                                    kwargs         = {'only_first_letter': only_first_letter},
+                                    is_elementwise = True)
+
+def syllables_per_word(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'syllables_per_word',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def split_fixed_width(expression:     IntoExprColumn,
+                      widths:         list[int],
+                      trim:           bool = False,
+                      keep_remainder: bool = False,
+                      ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'split_fixed_width',
+                                    args           = [expression],
+                                    kwargs         = {'widths':         widths,
+                                                      'trim':           trim,
+                                                      'keep_remainder': keep_remainder},
+                                    is_elementwise = True)
+
+def reverse_words(expression:          IntoExprColumn,
+                  preserve_whitespace: bool = True,
+                  ) ->                 Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'reverse_words',
+                                    args           = [expression],
+                                    kwargs         = {'preserve_whitespace': preserve_whitespace},
+                                    is_elementwise = True)
+
+def partition(expression: IntoExprColumn,
+              separator:  str,
+              from_end:   bool = False,
+              ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'partition',
+                                    args           = [expression],
+                                    kwargs         = {'separator': separator, 'from_end': from_end},
+                                    is_elementwise = True)
+
+def alternating_case(expression:  IntoExprColumn,
+                     start_upper: bool = False,
+                     non_letters: Literal['skip', 'advance', 'reset'] = 'skip',
+                     ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'alternating_case',
+                                    args           = [expression],
+                                    kwargs         = {'start_upper': start_upper, 'non_letters': non_letters},
+                                    is_elementwise = True)
+
+def strip_invisible(expression:    IntoExprColumn,
+                    keep_newlines: bool = True,
+                    keep_tabs:     bool = True,
+                    ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'strip_invisible',
+                                    args           = [expression],
+                                    kwargs         = {'keep_newlines': keep_newlines, 'keep_tabs': keep_tabs},
+                                    is_elementwise = True)
+
+def lowercase_except_proper(expression:   IntoExprColumn,
+                            proper_nouns: list[str] | None = None,
+                            ) ->          Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'lowercase_except_proper',
+                                    args           = [expression],
+                                    kwargs         = {'proper_nouns': proper_nouns or []},
+                                    is_elementwise = True)
+
+def detect_script(expression:     IntoExprColumn,
+                  include_common: bool = False,
+                  ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'detect_script',
+                                    args           = [expression],
+                                    kwargs         = {'include_common': include_common},
+                                    is_elementwise = True)
+
+def dehyphenate(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'dehyphenate',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def ocr_cleanup(expression:              IntoExprColumn,
+                dehyphenate:             bool = True,
+                collapse_spaced_letters: bool = True,
+                normalize_quotes:        bool = True,
+                fix_confusions:          bool = True,
+                dictionary:              list[str] | None = None,
+                ) ->                     Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'ocr_cleanup',
+                                    args           = [expression],
+                                    kwargs         = {'dehyphenate':             dehyphenate,
+                                                      'collapse_spaced_letters': collapse_spaced_letters,
+                                                      'normalize_quotes':        normalize_quotes,
+                                                      'fix_confusions':          fix_confusions,
+                                                      'dictionary':              dictionary or []},
+                                    is_elementwise = True)
+
+def grapheme_length(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'grapheme_length',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def display_width(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'display_width',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def word_wrap_preserve_indent(expression:       IntoExprColumn,
+                              width:            int = 80,
+                              break_long_words: bool = True,
+                              ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'word_wrap_preserve_indent',
+                                    args           = [expression],
+                                    kwargs         = {'width':            width,
+                                                      'break_long_words': break_long_words},
+                                    is_elementwise = True)
+
+def chunk(expression: IntoExprColumn,
+          size:       int,
+          unit:       Literal['grapheme', 'char', 'byte'] = 'grapheme',
+          strict:     bool = False,
+          ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'chunk',
+                                    args           = [expression],
+                                    kwargs         = {'size':   size,
+                                                      'unit':   unit,
+                                                      'strict': strict},
+                                    is_elementwise = True)
+
+def highlight_keywords(expression:       IntoExprColumn,
+                       keywords:         list[str],
+                       prefix:           str = '**',
+                       suffix:           str = '**',
+                       case_insensitive: bool = False,
+                       ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'highlight_keywords',
+                                    args           = [expression],
+                                    kwargs         = {'keywords':         keywords,
+                                                      'prefix':           prefix,
+                                                      'suffix':           suffix,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def humanize_bytes(expression: IntoExprColumn,
+                   binary:     bool = True,
+                   precision:  int = 1,
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'humanize_bytes',
+                                    args           = [expression],
+                                    kwargs         = {'binary':    binary,
+                                                      'precision': precision},
+                                    is_elementwise = True)
+
+def factorize(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'factorize',
+                                    args           = [expression],
+                                    is_elementwise = False)
+
+def humanize_duration(expression: IntoExprColumn,
+                      unit:       Literal['seconds', 'milliseconds'] = 'seconds',
+                      compact:    bool = True,
+                      ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'humanize_duration',
+                                    args           = [expression],
+                                    kwargs         = {'unit':    unit,
+                                                      'compact': compact},
+                                    is_elementwise = True)
+
+def parse_duration(expression: IntoExprColumn,
+                   unit:       Literal['seconds', 'milliseconds'] = 'seconds',
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'parse_duration',
+                                    args           = [expression],
+                                    kwargs         = {'unit': unit},
+                                    is_elementwise = True)
+
+def map_values(expression: IntoExprColumn,
+               mapping:    dict[str, str],
+               default:    str | None = None,
+               ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'map_values',
+                                    args           = [expression],
+                                    kwargs         = {'mapping': list(mapping.items()),
+                                                      'default': default},
+                                    is_elementwise = True)
+
+def detect_delimiter(expression: IntoExprColumn,
+                     candidates: list[str] | None = None,
+                     ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'detect_delimiter',
+                                    args           = [expression],
+                                    kwargs         = {'candidates': candidates or [',', ';', '\t', '|']},
+                                    is_elementwise = True)
+
+def length_zscore(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'length_zscore',
+                                    args           = [expression],
+                                    is_elementwise = False)
+
+def join_columns(*expressions:    IntoExprColumn,
+                 separator:        str = ' ',
+                 null_behavior:    Literal['propagate', 'skip', 'replace'] = 'skip',
+                 null_replacement: str = '',
+                 skip_empty:       bool = False,
+                 ) ->              Expr:
+    """"""
+    if null_behavior == 'replace':
+        null_behavior = {'replace': null_replacement}
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'join_columns',
+                                    args           = list(expressions),
+                                    kwargs         = {'separator':     separator,
+                                                      'null_behavior': null_behavior,
+                                                      'skip_empty':    skip_empty},
+                                    is_elementwise = True)
+
+def token_overlap_count(expression: IntoExprColumn,
+                        other:      IntoExprColumn,
+                        ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'token_overlap_count',
+                                    args           = [expression, other],
+                                    is_elementwise = True)
+
+def token_overlap_ratio(expression: IntoExprColumn,
+                        other:      IntoExprColumn,
+                        ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'token_overlap_ratio',
+                                    args           = [expression, other],
+                                    is_elementwise = True)
+
+def dedup_words(expression:       IntoExprColumn,
+                consecutive_only: bool = True,
+                case_insensitive: bool = False,
+                ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'dedup_words',
+                                    args           = [expression],
+                                    kwargs         = {'consecutive_only': consecutive_only,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def avatar_initials(expression: IntoExprColumn) -> Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'avatar_initials',
+                                    args           = [expression],
+                                    is_elementwise = True)
+
+def find_all(expression:       IntoExprColumn,
+             needle:           IntoExprColumn,
+             overlapping:      bool = False,
+             case_insensitive: bool = False,
+             ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'find_all',
+                                    args           = [expression, needle],
+                                    kwargs         = {'overlapping':      overlapping,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def redact_between(expression: IntoExprColumn,
+                   start:      str = '{{',
+                   end:        str = '}}',
+                   mask_char:  str = '*',
+                   ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'redact_between',
+                                    args           = [expression],
+                                    kwargs         = {'start':     start,
+                                                      'end':       end,
+                                                      'mask_char': mask_char},
+                                    is_elementwise = True)
+
+def replace_nth(expression:  IntoExprColumn,
+                pattern:     str,
+                replacement: str,
+                n:           int,
+                literal:     bool = True,
+                ) ->         Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'replace_nth',
+                                    args           = [expression],
+                                    kwargs         = {'pattern':     pattern,
+                                                      'replacement': replacement,
+                                                      'n':           n,
+                                                      'literal':     literal},
+                                    is_elementwise = True)
+
+def split_respecting_quotes(expression: IntoExprColumn,
+                            delimiter:  str = ',',
+                            quote:      str = '"',
+                            ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'split_respecting_quotes',
+                                    args           = [expression],
+                                    kwargs         = {'delimiter': delimiter,
+                                                      'quote':     quote},
+                                    is_elementwise = True)
+
+def straighten_quotes(expression:     IntoExprColumn,
+                      convert_dashes: bool = False,
+                      ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'straighten_quotes',
+                                    args           = [expression],
+                                    kwargs         = {'convert_dashes': convert_dashes},
+                                    is_elementwise = True)
+
+def smart_quotes(expression:     IntoExprColumn,
+                 convert_dashes: bool = False,
+                 ) ->            Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'smart_quotes',
+                                    args           = [expression],
+                                    kwargs         = {'convert_dashes': convert_dashes},
+                                    is_elementwise = True)
+
+def highlight(expression:       IntoExprColumn,
+              needle:           IntoExprColumn,
+              prefix:           str = '**',
+              suffix:           str = '**',
+              case_insensitive: bool = False,
+              ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'highlight',
+                                    args           = [expression, needle],
+                                    kwargs         = {'prefix':           prefix,
+                                                      'suffix':           suffix,
+                                                      'case_insensitive': case_insensitive},
+                                    is_elementwise = True)
+
+def fix_mojibake(expression: IntoExprColumn,
+                 aggressive: bool = False,
+                 ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'fix_mojibake',
+                                    args           = [expression],
+                                    kwargs         = {'aggressive': aggressive},
+                                    is_elementwise = True)
+
+def keyboard_distance(expression: IntoExprColumn,
+                      other:      IntoExprColumn,
+                      layout:     Literal['qwerty', 'azerty'] = 'qwerty',
+                      ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'keyboard_distance',
+                                    args           = [expression, other],
+                                    kwargs         = {'layout': layout},
+                                    is_elementwise = True)
+
+def sort_chars(expression:        IntoExprColumn,
+               descending:        bool = False,
+               case_insensitive:  bool = False,
+               ignore_whitespace: bool = False,
+               ) ->               Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'sort_chars',
+                                    args           = [expression],
+                                    kwargs         = {'descending':        descending,
+                                                      'case_insensitive':  case_insensitive,
+                                                      'ignore_whitespace': ignore_whitespace},
+                                    is_elementwise = True)
+
+def is_anagram(expression:         IntoExprColumn,
+               other:              IntoExprColumn,
+               ignore_case:        bool = False,
+               ignore_whitespace:  bool = False,
+               ignore_punctuation: bool = False,
+               ) ->                Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'is_anagram',
+                                    args           = [expression, other],
+                                    kwargs         = {'ignore_case':        ignore_case,
+                                                      'ignore_whitespace':  ignore_whitespace,
+                                                      'ignore_punctuation': ignore_punctuation},
+                                    is_elementwise = True)
+
+def acronym(expression:       IntoExprColumn,
+            uppercase:        bool = True,
+            skip_small_words: bool = True,
+            max_length:       int | None = None,
+            ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'acronym',
+                                    args           = [expression],
+                                    kwargs         = {'uppercase':        uppercase,
+                                                      'skip_small_words': skip_small_words,
+                                                      'max_length':       max_length},
+                                    is_elementwise = True)
+
+def mask_vowels(expression:       IntoExprColumn,
+                mask_char:        str = '*',
+                treat_y_as_vowel: bool = False,
+                ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'mask_vowels',
+                                    args           = [expression],
+                                    kwargs         = {'mask_char':        mask_char,
+                                                      'treat_y_as_vowel': treat_y_as_vowel},
+                                    is_elementwise = True)
+
+def mask_consonants(expression:       IntoExprColumn,
+                    mask_char:        str = '*',
+                    treat_y_as_vowel: bool = False,
+                    ) ->              Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'mask_consonants',
+                                    args           = [expression],
+                                    kwargs         = {'mask_char':        mask_char,
+                                                      'treat_y_as_vowel': treat_y_as_vowel},
+                                    is_elementwise = True)
+
+def interleave(expression: IntoExprColumn,
+               other:      IntoExprColumn,
+               pad:        bool = True,
+               ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'interleave',
+                                    args           = [expression, other],
+                                    kwargs         = {'pad': pad},
+                                    is_elementwise = True)
+
+def vigenere_cipher(expression: IntoExprColumn,
+                    key:        str,
+                    decrypt:    bool = False,
+                    ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'vigenere_cipher',
+                                    args           = [expression],
+                                    kwargs         = {'key':     key,
+                                                      'decrypt': decrypt},
+                                    is_elementwise = True)
+
+def split_sentences(expression:    IntoExprColumn,
+                    abbreviations: list[str] | None = None,
+                    ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'split_sentences',
+                                    args           = [expression],
+                                    kwargs         = {'abbreviations': abbreviations or []},
+                                    is_elementwise = True)
+
+def count_syllables(expression:    IntoExprColumn,
+                    per_word_list: bool = False,
+                    ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'count_syllables',
+                                    args           = [expression],
+                                    kwargs         = {'per_word_list': per_word_list},
+                                    is_elementwise = True)
+
+def flesch_reading_ease(expression:    IntoExprColumn,
+                        abbreviations: list[str] | None = None,
+                        ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'flesch_reading_ease',
+                                    args           = [expression],
+                                    kwargs         = {'abbreviations': abbreviations or []},
+                                    is_elementwise = True)
+
+def flesch_kincaid_grade(expression:    IntoExprColumn,
+                         abbreviations: list[str] | None = None,
+                         ) ->           Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'flesch_kincaid_grade',
+                                    args           = [expression],
+                                    kwargs         = {'abbreviations': abbreviations or []},
+                                    is_elementwise = True)
+
+def diff_strings(expression: IntoExprColumn,
+                 other:      IntoExprColumn,
+                 mode:       str = 'char',
+                 ) ->        Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'diff_strings',
+                                    args           = [expression, other],
+                                    kwargs         = {'mode': mode},
+                                    is_elementwise = True)
+
+def normalize_key(expression:         IntoExprColumn,
+                  lowercase:          bool = True,
+                  strip_accents:      bool = True,
+                  squeeze_whitespace: bool = True,
+                  remove_punctuation: bool = False,
+                  sort_tokens:        bool = False,
+                  ) ->                Expr:
+    """"""
+    return register_plugin_function(plugin_path    = LIB,
+                                    function_name  = 'normalize_key',
+                                    args           = [expression],
+                                    kwargs         = {'lowercase':          lowercase,
+                                                      'strip_accents':      strip_accents,
+                                                      'squeeze_whitespace': squeeze_whitespace,
+                                                      'remove_punctuation': remove_punctuation,
+                                                      'sort_tokens':        sort_tokens},
                                     is_elementwise = True)
\ No newline at end of file